@@ -0,0 +1,207 @@
+use super::emoji::{Jackpot, LotteryConfig};
+use super::error::Error;
+use super::i18n::Messages;
+use super::kv::{readable_time, KVClient, Winner, WINNERS_KEY};
+use super::utils;
+use super::BotConfig;
+
+use wasm_bindgen::JsValue;
+
+// Stored in the data namespace, so it's gone with the guesses when the next season commences
+const STATS_KEY: &str = "lottery:stats";
+// Stored in the config namespace, which survives across seasons
+const SEASONS_KEY: &str = "lottery:seasons";
+const HALL_OF_FAME_KEY: &str = "lottery:hall_of_fame";
+
+fn season_key(season: u64) -> String {
+    format!("lottery:season:{}", season)
+}
+
+#[derive(Deserialize, Serialize, Default, Debug)]
+pub struct SeasonStats {
+    pub total_guesses: u64,
+    pub participants: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SeasonRecord {
+    pub season: u64,
+    pub jackpots: Vec<Jackpot>,
+    pub salt: String,
+    pub commitment: String,
+    pub started_at: i64,
+    pub ended_at: i64,
+    pub winners: Vec<Winner>,
+    pub total_guesses: u64,
+    pub participants: usize,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct HallOfFameEntry {
+    pub season: u64,
+    pub winner: Winner,
+}
+
+// Counts a guess towards the season's stats, call it once a guess has been accepted
+pub async fn count_guess(data_client: &KVClient, submitter: &str) -> Result<(), Error> {
+    let mut stats: SeasonStats = data_client.read(STATS_KEY).await?.unwrap_or_default();
+    stats.total_guesses += 1;
+    if !stats.participants.iter().any(|p| p == submitter) {
+        stats.participants.push(submitter.to_string());
+    }
+    data_client.write::<&SeasonStats>(STATS_KEY, &stats).await
+}
+
+// Copies what's worth keeping about a season out of the data namespace before it's deleted
+// when the next season commences. Archiving a season again replaces its record.
+pub async fn archive_season(bot_config: &BotConfig, config: &LotteryConfig) -> Result<(), Error> {
+    let data_client = KVClient::new(bot_config.kv.clone(), config.data_kv_namespace.clone());
+    let winners: Vec<Winner> = data_client.read(WINNERS_KEY).await?.unwrap_or_default();
+    let stats: SeasonStats = data_client.read(STATS_KEY).await?.unwrap_or_default();
+    let record = SeasonRecord {
+        season: config.season,
+        jackpots: config.all_jackpots(),
+        salt: config.salt.clone(),
+        commitment: config.commitment.clone(),
+        started_at: config.started_at,
+        ended_at: utils::now(),
+        winners: winners.clone(),
+        total_guesses: stats.total_guesses,
+        participants: stats.participants.len(),
+    };
+
+    let config_client = KVClient::new(bot_config.kv.clone(), config.config_kv_namespace.clone());
+    config_client
+        .write::<&SeasonRecord>(&config.scoped_key(&season_key(config.season)), &record)
+        .await?;
+
+    let seasons_key = config.scoped_key(SEASONS_KEY);
+    let mut seasons: Vec<u64> = config_client.read(&seasons_key).await?.unwrap_or_default();
+    if !seasons.contains(&config.season) {
+        seasons.push(config.season);
+        config_client
+            .write::<&Vec<u64>>(&seasons_key, &seasons)
+            .await?;
+    }
+
+    let hall_of_fame_key = config.scoped_key(HALL_OF_FAME_KEY);
+    let mut hall_of_fame: Vec<HallOfFameEntry> = config_client
+        .read(&hall_of_fame_key)
+        .await?
+        .unwrap_or_default();
+    hall_of_fame.retain(|e| e.season != config.season);
+    hall_of_fame.extend(winners.into_iter().map(|winner| HallOfFameEntry {
+        season: config.season,
+        winner,
+    }));
+    config_client
+        .write::<&Vec<HallOfFameEntry>>(&hall_of_fame_key, &hall_of_fame)
+        .await
+}
+
+// /seasons
+pub async fn list_seasons(bot_config: &BotConfig) -> Result<JsValue, JsValue> {
+    let client = KVClient::new(
+        bot_config.kv.clone(),
+        bot_config.emoji.config_kv_namespace.clone(),
+    );
+    let seasons: Vec<u64> = client
+        .read(&bot_config.emoji.scoped_key(SEASONS_KEY))
+        .await
+        .map_err(|e| format!("Failed to read archived seasons, err: {:?}", e))?
+        .unwrap_or_default();
+    let messages = bot_config.emoji.messages();
+    if seasons.is_empty() {
+        return Ok(JsValue::from_str(&messages.get("no_seasons", &[])));
+    }
+    let seasons: Vec<String> = seasons.iter().map(|s| s.to_string()).collect();
+    Ok(JsValue::from_str(
+        &messages.get("past_seasons", &[("seasons", &seasons.join(", "))]),
+    ))
+}
+
+// /seasons/:season
+pub async fn show_season(bot_config: &BotConfig, season: u64) -> Result<JsValue, JsValue> {
+    let client = KVClient::new(
+        bot_config.kv.clone(),
+        bot_config.emoji.config_kv_namespace.clone(),
+    );
+    let record: Option<SeasonRecord> = client
+        .read(&bot_config.emoji.scoped_key(&season_key(season)))
+        .await
+        .map_err(|e| format!("Failed to read season {}, err: {:?}", season, e))?;
+    let messages = bot_config.emoji.messages();
+    match record {
+        Some(record) => Ok(JsValue::from_str(&record.localize(&messages))),
+        None => Ok(JsValue::from_str(
+            &messages.get("season_not_archived", &[("season", &season.to_string())]),
+        )),
+    }
+}
+
+// /winners
+pub async fn hall_of_fame(bot_config: &BotConfig) -> Result<JsValue, JsValue> {
+    let client = KVClient::new(
+        bot_config.kv.clone(),
+        bot_config.emoji.config_kv_namespace.clone(),
+    );
+    let hall_of_fame: Vec<HallOfFameEntry> = client
+        .read(&bot_config.emoji.scoped_key(HALL_OF_FAME_KEY))
+        .await
+        .map_err(|e| format!("Failed to read hall of fame, err: {:?}", e))?
+        .unwrap_or_default();
+    let messages = bot_config.emoji.messages();
+    if hall_of_fame.is_empty() {
+        return Ok(JsValue::from_str(&messages.get("no_hall_of_fame", &[])));
+    }
+    let mut lines = vec![messages.get("hall_of_fame", &[])];
+    lines.extend(hall_of_fame.iter().map(|e| {
+        messages.get(
+            "hall_of_fame_entry",
+            &[
+                ("season", &e.season.to_string()),
+                ("winner", &e.winner.localize(&messages)),
+            ],
+        )
+    }));
+    Ok(JsValue::from_str(&lines.join("\n")))
+}
+
+impl SeasonRecord {
+    pub fn localize(&self, messages: &Messages) -> String {
+        let jackpots: Vec<String> = self
+            .jackpots
+            .iter()
+            .map(|j| format!("{} ({})", j.emoji, j.tier))
+            .collect();
+        let mut lines = vec![messages.get(
+            "season_ran",
+            &[
+                ("season", &self.season.to_string()),
+                ("started_at", &readable_time(self.started_at).to_string()),
+                ("ended_at", &readable_time(self.ended_at).to_string()),
+            ],
+        )];
+        // Raffles have no jackpot to reveal
+        if !jackpots.is_empty() {
+            lines.push(messages.get("season_jackpots", &[("jackpots", &jackpots.join(", "))]));
+        }
+        if !self.commitment.is_empty() {
+            lines.push(messages.get(
+                "season_fairness",
+                &[("salt", &self.salt), ("commitment", &self.commitment)],
+            ));
+        }
+        lines.push(messages.count(
+            "season_guesses",
+            self.participants as u64,
+            &[("guesses", &self.total_guesses.to_string())],
+        ));
+        if self.winners.is_empty() {
+            lines.push(messages.get("season_no_winner", &[]));
+        } else {
+            lines.extend(self.winners.iter().map(|w| w.localize(messages)));
+        }
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,63 @@
+use super::emoji_catalog::EMOJIS;
+use super::error::Error;
+use super::i18n::Messages;
+use super::kv::KVClient;
+use super::BotConfig;
+
+use std::collections::HashSet;
+use wasm_bindgen::JsValue;
+
+// Stored in the data namespace, every wrong guess of the season rules one emoji out
+const ELIMINATED_KEY: &str = "lottery:eliminated";
+
+pub async fn eliminate(data_client: &KVClient, emoji: &[String]) -> Result<(), Error> {
+    let mut eliminated: Vec<String> = data_client.read(ELIMINATED_KEY).await?.unwrap_or_default();
+    let before = eliminated.len();
+    for e in emoji.iter() {
+        if !eliminated.contains(e) {
+            eliminated.push(e.clone());
+        }
+    }
+    if eliminated.len() == before {
+        return Ok(());
+    }
+    data_client
+        .write::<&Vec<String>>(ELIMINATED_KEY, &eliminated)
+        .await
+}
+
+// /board
+pub async fn show_board(bot_config: &BotConfig) -> Result<JsValue, JsValue> {
+    let messages = bot_config.emoji.messages();
+    if bot_config.emoji.hard_mode {
+        return Ok(JsValue::from_str(&messages.get("board_hidden", &[])));
+    }
+    let client = KVClient::new(
+        bot_config.kv.clone(),
+        bot_config.emoji.data_kv_namespace.clone(),
+    );
+    let eliminated: Vec<String> = client
+        .read(ELIMINATED_KEY)
+        .await
+        .map_err(|e| format!("Failed to read elimination board, err: {:?}", e))?
+        .unwrap_or_default();
+    if eliminated.is_empty() {
+        return Ok(JsValue::from_str(&messages.get("board_empty", &[])));
+    }
+    Ok(JsValue::from_str(&render(&eliminated, &messages)))
+}
+
+// Lists eliminated emoji by group, in the catalog's order
+fn render(eliminated: &[String], messages: &Messages) -> String {
+    let eliminated: HashSet<&str> = eliminated.iter().map(|e| e.as_str()).collect();
+    let mut board = messages.count("board", eliminated.len() as u64, &[]);
+    let mut current_group = "";
+    for info in EMOJIS.iter().filter(|e| eliminated.contains(e.emoji)) {
+        if info.group != current_group {
+            current_group = info.group;
+            board.push_str(&format!("\n{}: ", current_group));
+        }
+        board.push_str(info.emoji);
+    }
+    board
+}
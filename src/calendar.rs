@@ -1,6 +1,9 @@
+use super::archive::archive_season;
 use super::discord::new_webhook_client;
 use super::emoji::LotteryConfig;
+use super::game;
 use super::kv::KVClient;
+use super::raffle;
 use super::slack::{new_slack_client, PostMessageResp};
 use super::BotConfig;
 
@@ -18,11 +21,7 @@ pub enum NotifyTo {
     Slack,
 }
 
-pub async fn calendar_start(
-    req: Request,
-    bot_config: BotConfig,
-    to: NotifyTo,
-) -> Result<JsValue, JsValue> {
+pub async fn calendar_start(req: Request, bot_config: BotConfig) -> Result<JsValue, JsValue> {
     let body = JsFuture::from(req.json()?).await?;
     let event: CalendarStartEvent = body.into_serde().map_err(|e| {
         format!(
@@ -30,8 +29,17 @@ pub async fn calendar_start(
             e
         )
     })?;
-    let client = KVClient::new(bot_config.kv, bot_config.emoji.config_kv_namespace.clone());
+    let client = KVClient::new(
+        bot_config.kv.clone(),
+        bot_config.emoji.config_kv_namespace.clone(),
+    );
     let key = bot_config.emoji.kv_key();
+    check_announceable(&bot_config)?;
+
+    // Draw first, a config that can't start a season shouldn't lose the current one's data
+    let mut new_config = game::for_season(&bot_config.emoji)
+        .start_season(&bot_config.emoji)
+        .map_err(|e| format!("Failed to start a new season, err: {:?}", e))?;
 
     client
         .delete_namespace(bot_config.emoji.data_kv_namespace.clone())
@@ -39,44 +47,33 @@ pub async fn calendar_start(
         .map_err(|e| format!("Failed to delete namespace, err: {:?}", e))?;
 
     let resp = client
-        .create_namespace("lottery-bot-data-test".to_string())
+        .create_namespace(bot_config.emoji.scoped_key("lottery-bot-data-test"))
         .await
         .map_err(|e| format!("Failed to create namespace, err: {:?}", e))?;
-
-    let new_config = bot_config.emoji.commence(resp.id);
+    new_config.data_kv_namespace = resp.id;
     // Update emoji lottery config to start a new season
     client
         .write::<&LotteryConfig>(&key, &new_config)
         .await
         .map_err(|e| format!("Failed to write new emoji lottery config, err: {:?}", e))?;
 
-    let msg = format!(
-        "{} season {} commence",
-        event.calendar_name, new_config.season,
+    let messages = new_config.messages();
+    let msg = messages.get(
+        "season_start",
+        &[
+            ("calendar", &event.calendar_name),
+            ("season", &new_config.season.to_string()),
+            (
+                "status",
+                &game::for_season(&new_config).render_status(&new_config, &messages),
+            ),
+        ],
     );
 
-    match &to {
-        NotifyTo::Slack => {
-            let slack_client = new_slack_client(bot_config.slack);
-            let resp = slack_client.post_message(msg).await?;
-            match resp {
-                PostMessageResp::Ok(_) => Ok(JsValue::TRUE),
-                PostMessageResp::Err(e) => Err(JsValue::from_str(&e.error)),
-            }
-        }
-        NotifyTo::Discord => {
-            let webhook_client = new_webhook_client(bot_config.discord.webhook_url);
-            webhook_client.execute(msg).await?;
-            Ok(JsValue::TRUE)
-        }
-    }
+    announce(&bot_config, msg).await
 }
 
-pub async fn calendar_end(
-    req: Request,
-    bot_config: BotConfig,
-    to: NotifyTo,
-) -> Result<JsValue, JsValue> {
+pub async fn calendar_end(req: Request, bot_config: BotConfig) -> Result<JsValue, JsValue> {
     let body = JsFuture::from(req.json()?).await?;
     let event: CalendarStartEvent = body.into_serde().map_err(|e| {
         format!(
@@ -85,21 +82,106 @@ pub async fn calendar_end(
         )
     })?;
 
-    let client = KVClient::new(bot_config.kv, bot_config.emoji.config_kv_namespace.clone());
+    let client = KVClient::new(
+        bot_config.kv.clone(),
+        bot_config.emoji.config_kv_namespace.clone(),
+    );
     let key = bot_config.emoji.kv_key();
-    let new_config = bot_config.emoji.end();
+    check_announceable(&bot_config)?;
+    let game = game::for_season(&bot_config.emoji);
+    let mut new_config = game.end_season(&bot_config.emoji);
+    let drawn = if game.draws_winners() {
+        raffle::draw(&bot_config, &new_config, game)
+            .await
+            .map_err(|e| format!("Failed to draw winners, err: {:?}", e))?
+    } else {
+        Vec::new()
+    };
+    if !drawn.is_empty() {
+        new_config = new_config.won(&drawn[0].tier);
+    }
     client
         .write::<&LotteryConfig>(&key, &new_config)
         .await
         .map_err(|e| format!("Failed to write new emoji lottery config, err: {:?}", e))?;
-    let msg = format!(
-        "{} season {} ends",
-        event.calendar_name, bot_config.emoji.season,
+    archive_season(&bot_config, &new_config)
+        .await
+        .map_err(|e| format!("Failed to archive season, err: {:?}", e))?;
+    let messages = new_config.messages();
+    let mut msg = messages.get(
+        "season_end",
+        &[
+            ("calendar", &event.calendar_name),
+            ("season", &bot_config.emoji.season.to_string()),
+            ("status", &game.render_status(&new_config, &messages)),
+        ],
     );
+    if !drawn.is_empty() {
+        let names: Vec<&str> = drawn.iter().map(|w| w.submitter.as_str()).collect();
+        msg.push_str(&messages.get("raffle_winners", &[("winners", &names.join(", "))]));
+    }
+
+    announce(&bot_config, msg).await
+}
+
+// Seasons shouldn't start or end without anyone being told
+fn check_announceable(bot_config: &BotConfig) -> Result<(), JsValue> {
+    if bot_config.notify_targets().is_empty() {
+        return Err(JsValue::from_str(
+            "Neither Discord nor Slack is set up for this lottery",
+        ));
+    }
+    Ok(())
+}
 
-    match &to {
+// Sends msg to every platform the lottery is on. A platform failing doesn't keep the
+// message from the others, the first error is returned once all were tried.
+pub async fn announce(bot_config: &BotConfig, msg: String) -> Result<JsValue, JsValue> {
+    check_announceable(bot_config)?;
+    let mut result = Ok(JsValue::TRUE);
+    for to in bot_config.notify_targets().iter() {
+        if let Err(e) = notify(bot_config, to, msg.clone()).await {
+            if result.is_ok() {
+                result = Err(e);
+            }
+        }
+    }
+    result
+}
+
+// /status
+pub fn status(bot_config: &BotConfig) -> Result<JsValue, JsValue> {
+    let config = &bot_config.emoji;
+    let messages = config.messages();
+    let key = if config.active {
+        "status_active"
+    } else {
+        "status_over"
+    };
+    Ok(JsValue::from_str(&messages.get(
+        key,
+        &[
+            ("season", &config.season.to_string()),
+            (
+                "status",
+                &game::for_season(config).render_status(config, &messages),
+            ),
+        ],
+    )))
+}
+
+pub async fn notify(
+    bot_config: &BotConfig,
+    to: &NotifyTo,
+    msg: String,
+) -> Result<JsValue, JsValue> {
+    match to {
         NotifyTo::Slack => {
-            let slack_client = new_slack_client(bot_config.slack);
+            let slack = bot_config
+                .slack
+                .clone()
+                .ok_or("Slack isn't set up for this lottery")?;
+            let slack_client = new_slack_client(slack);
             let resp = slack_client.post_message(msg).await?;
             match resp {
                 PostMessageResp::Ok(_) => Ok(JsValue::TRUE),
@@ -107,7 +189,12 @@ pub async fn calendar_end(
             }
         }
         NotifyTo::Discord => {
-            let webhook_client = new_webhook_client(bot_config.discord.webhook_url);
+            let discord = bot_config
+                .discord
+                .as_ref()
+                .ok_or("Discord isn't set up for this lottery")?;
+            let webhook_client =
+                new_webhook_client(discord.webhook_url.clone(), discord.trace.clone());
             webhook_client.execute(msg).await?;
             Ok(JsValue::TRUE)
         }
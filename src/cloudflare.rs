@@ -0,0 +1,161 @@
+use super::error;
+use super::http::{send, Method, Request};
+use super::trace::Trace;
+
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+const API_BASE_URL: &str = "https://api.cloudflare.com/client/v4";
+
+/*
+Either
+{
+    "account_id": "...",
+    "token": "..."
+}
+or, with the global API key
+{
+    "account_id": "...",
+    "email": "maintainer@example.com",
+    "key": "...",
+    "base_url": "http://localhost:8787/client/v4"
+}
+*/
+#[derive(Deserialize, Clone)]
+pub struct CloudflareConfig {
+    pub account_id: String,
+    #[serde(flatten)]
+    auth: Auth,
+    // Overrides the API location, e.g. to point at a local stand-in
+    #[serde(default)]
+    base_url: Option<String>,
+    #[serde(skip)]
+    pub trace: Trace,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Auth {
+    // https://developers.cloudflare.com/api/tokens/create
+    ApiToken { token: String },
+    GlobalKey { email: String, key: String },
+}
+
+pub struct CloudflareClient {
+    base_url: String,
+    account_id: String,
+    auth: Auth,
+    trace: Trace,
+}
+
+impl CloudflareClient {
+    pub fn new(config: CloudflareConfig) -> CloudflareClient {
+        CloudflareClient {
+            base_url: config
+                .base_url
+                .unwrap_or_else(|| API_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            account_id: config.account_id,
+            auth: config.auth,
+            trace: config.trace,
+        }
+    }
+
+    // Builds an authenticated request for a path relative to the account,
+    // e.g. /storage/kv/namespaces
+    pub fn account_request(&self, method: Method, path: &str) -> Request {
+        let url = format!("{}/accounts/{}{}", self.base_url, self.account_id, path);
+        let req = Request::new(method, url).trace(&self.trace);
+        match &self.auth {
+            Auth::ApiToken { token } => req.header("Authorization", &format!("Bearer {}", token)),
+            Auth::GlobalKey { email, key } => {
+                req.header("X-Auth-Email", email).header("X-Auth-Key", key)
+            }
+        }
+    }
+
+    pub async fn expect_result<T>(&self, req: Request) -> Result<T, error::Error>
+    where
+        T: DeserializeOwned + Clone,
+    {
+        let resp: ExpectResultResponse<T> = send(req).await?.json().await?;
+        resp.result()
+    }
+
+    pub async fn no_result(&self, req: Request) -> Result<(), error::Error> {
+        let resp: NoResultResponse = send(req).await?.json().await?;
+        resp.result()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ExpectResultResponse<T>
+where
+    T: Clone,
+{
+    pub result: Option<T>,
+    pub success: bool,
+    pub errors: Vec<Error>,
+    pub messages: Vec<String>,
+}
+
+impl<T> ExpectResultResponse<T>
+where
+    T: Clone,
+{
+    pub fn result(&self) -> Result<T, error::Error> {
+        if let Some(e) = failure(self.success, &self.errors) {
+            Err(error::Error::from(e))
+        } else {
+            match &self.result {
+                Some(r) => Ok(r.clone()),
+                None => Err(error::Error::KvNoResult),
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NoResultResponse {
+    pub success: bool,
+    pub errors: Vec<Error>,
+    pub messages: Vec<String>,
+}
+
+impl NoResultResponse {
+    pub fn result(&self) -> Result<(), error::Error> {
+        match failure(self.success, &self.errors) {
+            Some(e) => Err(error::Error::from(e)),
+            None => Ok(()),
+        }
+    }
+}
+
+// The first error of a failed call. The envelope can say it failed without any errors.
+fn failure(success: bool, errors: &[Error]) -> Option<Error> {
+    match errors.first() {
+        Some(e) => Some(e.clone()),
+        None if !success => Some(Error {
+            code: 0,
+            message: "request failed without an error".to_string(),
+        }),
+        None => None,
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct Error {
+    pub code: u16,
+    pub message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cloudflare api error, code: {}, message: {}",
+            self.code, self.message
+        )
+    }
+}
@@ -0,0 +1,296 @@
+use chrono::prelude::*;
+use chrono::Duration;
+use chrono_tz::Tz;
+
+// Longest wait a policy can be configured with, a year
+const MAX_MINUTES: i64 = 366 * 24 * 60;
+
+/*
+Sample cooldown policies, pick one
+{ "type": "interval", "minutes": 90 }
+{ "type": "rolling_window", "guesses": 3, "window_minutes": 1440 }
+{ "type": "daily_quota", "guesses": 5, "timezone": "America/New_York" }
+{ "type": "token_bucket", "capacity": 3, "refill_minutes": 240 }
+*/
+#[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CooldownPolicy {
+    // A fixed wait after every guess
+    Interval {
+        minutes: i64,
+    },
+    // At most that many guesses in any window of that length
+    RollingWindow {
+        guesses: u32,
+        window_minutes: i64,
+    },
+    // At most that many guesses per calendar day, days start at midnight in the given IANA
+    // timezone, following its daylight saving time
+    DailyQuota {
+        guesses: u32,
+        #[serde(default = "default_timezone")]
+        timezone: String,
+    },
+    // Holds up to capacity guesses, one comes back every refill_minutes, so players can
+    // save guesses up for a burst
+    TokenBucket {
+        capacity: u32,
+        refill_minutes: i64,
+    },
+}
+
+impl CooldownPolicy {
+    // Returns when the next guess is allowed given the unix timestamps of earlier guesses
+    // in ascending order, or None if a guess is allowed at now. Fails if the policy is
+    // misconfigured.
+    pub fn next_allowed(&self, guesses: &[i64], now: i64) -> Result<Option<i64>, String> {
+        self.validate()?;
+        let next = match self {
+            CooldownPolicy::Interval { minutes } => match guesses.last() {
+                Some(last) => last + minutes * 60,
+                None => return Ok(None),
+            },
+            CooldownPolicy::RollingWindow {
+                guesses: limit,
+                window_minutes,
+            } => {
+                let window = window_minutes * 60;
+                let recent: Vec<i64> = guesses
+                    .iter()
+                    .cloned()
+                    .filter(|ts| *ts > now - window)
+                    .collect();
+                if recent.len() < *limit as usize {
+                    return Ok(None);
+                }
+                // The guess that has to leave the window to make room for another one
+                recent[recent.len() - *limit as usize] + window
+            }
+            CooldownPolicy::DailyQuota {
+                guesses: limit,
+                timezone,
+            } => {
+                let tz = parse_timezone(timezone)?;
+                let today = local_date(now, &tz);
+                let used = guesses
+                    .iter()
+                    .filter(|ts| local_date(**ts, &tz) == today)
+                    .count();
+                if used < *limit as usize {
+                    return Ok(None);
+                }
+                start_of_day(&tz, today + Duration::days(1))
+            }
+            CooldownPolicy::TokenBucket {
+                capacity,
+                refill_minutes,
+            } => {
+                let capacity = *capacity as f64;
+                let refill = (refill_minutes * 60) as f64;
+                // Replay earlier guesses to find how many tokens are left
+                let mut tokens = capacity;
+                let mut last: Option<i64> = None;
+                for ts in guesses.iter() {
+                    if let Some(last) = last {
+                        tokens = capacity.min(tokens + (ts - last) as f64 / refill);
+                    }
+                    tokens -= 1.0;
+                    last = Some(*ts);
+                }
+                if let Some(last) = last {
+                    tokens = capacity.min(tokens + (now - last) as f64 / refill);
+                }
+                if tokens >= 1.0 {
+                    return Ok(None);
+                }
+                now + ((1.0 - tokens) * refill).ceil() as i64
+            }
+        };
+        if next > now {
+            Ok(Some(next))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let valid = match self {
+            CooldownPolicy::Interval { minutes } => (0..=MAX_MINUTES).contains(minutes),
+            CooldownPolicy::RollingWindow {
+                guesses,
+                window_minutes,
+            } => *guesses > 0 && (1..=MAX_MINUTES).contains(window_minutes),
+            CooldownPolicy::DailyQuota { guesses, timezone } => {
+                *guesses > 0 && parse_timezone(timezone).is_ok()
+            }
+            CooldownPolicy::TokenBucket {
+                capacity,
+                refill_minutes,
+            } => *capacity > 0 && (1..=MAX_MINUTES).contains(refill_minutes),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(format!("Invalid cooldown policy {:?}", self))
+        }
+    }
+}
+
+pub fn default_timezone() -> String {
+    "UTC".to_string()
+}
+
+// Timezones are IANA names, e.g. Europe/Paris
+fn parse_timezone(timezone: &str) -> Result<Tz, String> {
+    timezone
+        .parse()
+        .map_err(|_| format!("Unknown timezone {:?}", timezone))
+}
+
+fn local_date(ts: i64, tz: &Tz) -> NaiveDate {
+    tz.timestamp(ts, 0).naive_local().date()
+}
+
+// Days usually start at midnight, but some timezones skip it when daylight saving time
+// starts, e.g. America/Havana, then the day starts at the first hour that exists
+fn start_of_day(tz: &Tz, date: NaiveDate) -> i64 {
+    (0..24)
+        .filter_map(|hour| date.and_hms_opt(hour, 0, 0))
+        .find_map(|start| tz.from_local_datetime(&start).earliest())
+        .unwrap_or_else(|| tz.from_utc_datetime(&date.and_hms(0, 0, 0)))
+        .timestamp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    // 2021-03-01T00:00:00Z
+    const MIDNIGHT: i64 = 1614556800;
+
+    #[test]
+    fn interval() {
+        let policy = CooldownPolicy::Interval { minutes: 90 };
+        let now = MIDNIGHT + HOUR;
+        assert_eq!(policy.next_allowed(&[], now), Ok(None));
+        assert_eq!(
+            policy.next_allowed(&[MIDNIGHT], now),
+            Ok(Some(MIDNIGHT + 90 * MINUTE))
+        );
+        assert_eq!(policy.next_allowed(&[MIDNIGHT - HOUR], now), Ok(None));
+    }
+
+    #[test]
+    fn rolling_window() {
+        let policy = CooldownPolicy::RollingWindow {
+            guesses: 2,
+            window_minutes: 60,
+        };
+        let now = MIDNIGHT + HOUR;
+        assert_eq!(policy.next_allowed(&[now - 10 * MINUTE], now), Ok(None));
+        // The older of the two guesses in the window has to leave it
+        assert_eq!(
+            policy.next_allowed(&[now - 50 * MINUTE, now - 10 * MINUTE], now),
+            Ok(Some(now + 10 * MINUTE))
+        );
+        // Guesses older than the window don't count
+        assert_eq!(
+            policy.next_allowed(&[now - 2 * HOUR, now - 10 * MINUTE], now),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn daily_quota() {
+        let policy = CooldownPolicy::DailyQuota {
+            guesses: 2,
+            timezone: "America/New_York".to_string(),
+        };
+        // 2021-03-01T00:00:00-05:00
+        let local_midnight = MIDNIGHT + 5 * HOUR;
+        let now = local_midnight + 10 * HOUR;
+        assert_eq!(policy.next_allowed(&[now - HOUR], now), Ok(None));
+        assert_eq!(
+            policy.next_allowed(&[now - 2 * HOUR, now - HOUR], now),
+            Ok(Some(local_midnight + 24 * HOUR))
+        );
+        // Guesses of the previous local day don't count, though it's the same UTC day
+        assert_eq!(
+            policy.next_allowed(&[local_midnight - HOUR, now - HOUR], now),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn daily_quota_dst() {
+        let policy = CooldownPolicy::DailyQuota {
+            guesses: 1,
+            timezone: "America/New_York".to_string(),
+        };
+        // 2021-03-14T12:00:00-04:00, clocks went forward at 2:00 that night
+        let now = 1615737600;
+        // Local midnight of the 15th is 04:00Z after the switch, 05:00Z the day before
+        assert_eq!(policy.next_allowed(&[now], now), Ok(Some(1615780800)));
+        // 2021-03-14T00:30:00-05:00 is the same local day
+        assert_eq!(
+            policy.next_allowed(&[1615699800], now),
+            Ok(Some(1615780800))
+        );
+        // 2021-03-13T23:30:00-05:00 isn't
+        assert_eq!(policy.next_allowed(&[1615696200], now), Ok(None));
+    }
+
+    #[test]
+    fn token_bucket() {
+        let policy = CooldownPolicy::TokenBucket {
+            capacity: 2,
+            refill_minutes: 60,
+        };
+        let now = MIDNIGHT + 10 * HOUR;
+        assert_eq!(policy.next_allowed(&[], now), Ok(None));
+        assert_eq!(policy.next_allowed(&[now - MINUTE], now), Ok(None));
+        // Both tokens spent, half of one came back since
+        assert_eq!(
+            policy.next_allowed(&[now - 30 * MINUTE, now - 30 * MINUTE], now),
+            Ok(Some(now + 30 * MINUTE))
+        );
+        // Saved up tokens allow a burst, but never more than the capacity
+        assert_eq!(
+            policy.next_allowed(&[now - 10 * HOUR, now, now], now),
+            Ok(Some(now + HOUR))
+        );
+    }
+
+    #[test]
+    fn invalid_policies() {
+        let now = MIDNIGHT;
+        let policies = [
+            CooldownPolicy::Interval { minutes: -1 },
+            CooldownPolicy::RollingWindow {
+                guesses: 0,
+                window_minutes: 60,
+            },
+            CooldownPolicy::DailyQuota {
+                guesses: 0,
+                timezone: default_timezone(),
+            },
+            CooldownPolicy::DailyQuota {
+                guesses: 1,
+                timezone: "Mars/Olympus_Mons".to_string(),
+            },
+            CooldownPolicy::DailyQuota {
+                guesses: 1,
+                timezone: "-05:00".to_string(),
+            },
+            CooldownPolicy::TokenBucket {
+                capacity: 1,
+                refill_minutes: 0,
+            },
+        ];
+        for policy in policies.iter() {
+            assert!(policy.next_allowed(&[now], now).is_err(), "{:?}", policy);
+        }
+    }
+}
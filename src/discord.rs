@@ -1,10 +1,18 @@
+use super::archive;
+use super::board;
+use super::calendar::announce;
+use super::emoji::{Jackpot, LotteryConfig};
 use super::error::Error;
-use super::http::{send, Method, Request};
-use super::kv::{Guess, KVClient};
+use super::game::{self, Verdict};
+use super::history;
+use super::http::{send, Body, Method, Request};
+use super::i18n::Messages;
+use super::kv::{readable_time, Guess, KVClient, Winner, WINNERS_KEY};
+use super::raffle;
+use super::trace::Trace;
+use super::utils;
 use super::BotConfig;
 
-use chrono::prelude::*;
-use std::collections::HashMap;
 use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::JsFuture;
 use web_sys;
@@ -15,12 +23,19 @@ pub struct DiscordConfig {
     pub gateway_token: String,
     pub webhook_url: String,
     pub maintainer: String,
+    #[serde(skip)]
+    pub trace: Trace,
 }
 
+const PLATFORM: &str = "discord";
+
 #[derive(Deserialize, Debug)]
 struct Submission {
     submission: String,
     ts: i64,
+    // Discord interactions come with the user's locale, e.g. fr or en-US
+    #[serde(default)]
+    locale: Option<String>,
 }
 
 pub async fn submit(
@@ -28,67 +43,229 @@ pub async fn submit(
     submitter: String,
     config: BotConfig,
 ) -> Result<JsValue, JsValue> {
-    if !config.emoji.active {
-        return Ok(JsValue::from_str(
-            "No active lottery yet, wait for the next announcement!",
-        ));
-    }
     let body = JsFuture::from(req.json()?).await?;
     let submission: Submission = body
         .into_serde()
         .map_err(|e| format!("Failed to deserialize into Submission, err: {:?}", e))?;
+    let messages =
+        Messages::negotiate(&[submission.locale.as_deref(), config.emoji.locale.as_deref()]);
+    if !config.emoji.active {
+        return Ok(JsValue::from_str(&messages.get("no_active_season", &[])));
+    }
 
-    if submission.submission == config.emoji.jackpot {
-        return Ok(JsValue::from_str("Bingo!"));
+    // The worker's clock is what counts, the client's is only kept for auditing
+    let now = utils::now();
+    // ts comes from the client, it can be anything
+    let skew = submission.ts.abs_diff(now);
+    if skew > config.emoji.max_clock_skew_secs.max(0) as u64 {
+        return Ok(JsValue::from_str(&messages.count("clock_skew", skew, &[])));
     }
-    let client = KVClient::new(config.kv, config.emoji.data_kv_namespace);
-    let last_try = client
-        .read(&submitter)
+    let client = KVClient::new(config.kv.clone(), config.emoji.data_kv_namespace.clone());
+    let guesses = history::read(&client, &submitter)
         .await
         .map_err(|e| format!("Can't retrieve last try, err: {:?}", e))?;
+    let verdict = game::for_season(&config.emoji).judge(
+        &config.emoji,
+        &submitter,
+        &submission.submission,
+        &guesses,
+        &messages,
+    );
+    let (guess, jackpot, feedback, eliminated) = match verdict {
+        Verdict::Rejected(reason) => return Ok(JsValue::from_str(&reason)),
+        Verdict::Enter { entries } => {
+            let entry = Guess {
+                value: submission.submission,
+                created_at: now,
+                client_ts: Some(submission.ts),
+            };
+            return raffle::enter(&client, submitter, entries, entry, PLATFORM, &messages).await;
+        }
+        Verdict::Win {
+            value,
+            jackpot,
+            feedback,
+        } => (value, Some(jackpot), feedback, Vec::new()),
+        Verdict::Miss {
+            value,
+            feedback,
+            eliminated,
+        } => (value, None, feedback, eliminated),
+    };
+
+    // Winning guesses are subject to the cooldown too
+    let cooldown = config.emoji.cooldown();
+    let next_allowed = cooldown
+        .next_allowed(&guesses.timestamps(), now)
+        .map_err(|e| format!("Failed to apply cooldown, err: {:?}", e))?;
+    if let Some(next_allowed) = next_allowed {
+        return Ok(JsValue::from_str(&messages.get(
+            "cooldown",
+            &[("time", &readable_time(next_allowed).to_string())],
+        )));
+    }
 
     let current_guess = Guess {
-        value: submission.submission,
-        created_at: submission.ts,
+        value: guess,
+        created_at: now,
+        client_ts: Some(submission.ts),
     };
+    if let Some(jackpot) = jackpot {
+        return record_win(
+            submitter,
+            jackpot,
+            current_guess,
+            feedback,
+            &messages,
+            config,
+        )
+        .await;
+    }
+    let guesses = history::append_miss(&client, &submitter, current_guess)
+        .await
+        .map_err(|e| format!("Failed to submit, err: {:?}", e))?;
+    archive::count_guess(&client, &submitter)
+        .await
+        .map_err(|e| format!("Failed to count guess, err: {:?}", e))?;
+    board::eliminate(&client, &eliminated)
+        .await
+        .map_err(|e| format!("Failed to update elimination board, err: {:?}", e))?;
 
-    let retry_in_hrs = config.emoji.retry_in_hrs;
-    match last_try {
-        Some(l) => {
-            let last_time = NaiveDateTime::from_timestamp(l.created_at, 0);
-            let current_time = NaiveDateTime::from_timestamp(current_guess.created_at, 0);
-            let next_retryable_time = last_time + chrono::Duration::hours(retry_in_hrs);
-            if current_time < next_retryable_time {
-                return Ok(JsValue::from_str(&format!(
-                    "please submit after {:?}",
-                    next_retryable_time
-                )));
-            }
-        }
-        None => {}
+    let next_allowed = cooldown
+        .next_allowed(&guesses.timestamps(), now)
+        .map_err(|e| format!("Failed to apply cooldown, err: {:?}", e))?;
+    let mut msg = match next_allowed {
+        Some(next_allowed) => messages.get(
+            "miss_retry_after",
+            &[("time", &readable_time(next_allowed).to_string())],
+        ),
+        None => messages.get("miss", &[]),
     };
+    if let Some(feedback) = feedback {
+        msg.push_str(&format!(". {}", feedback));
+    }
+    Ok(JsValue::from_str(&msg))
+}
 
-    let resp = client
-        .write::<Guess>(&submitter, current_guess)
+async fn record_win(
+    submitter: String,
+    jackpot: Jackpot,
+    guess: Guess,
+    feedback: Option<String>,
+    messages: &Messages,
+    config: BotConfig,
+) -> Result<JsValue, JsValue> {
+    let data_client = KVClient::new(config.kv.clone(), config.emoji.data_kv_namespace.clone());
+    let mut winners: Vec<Winner> = data_client
+        .read(WINNERS_KEY)
+        .await
+        .map_err(|e| format!("Failed to read winners, err: {:?}", e))?
+        .unwrap_or_default();
+    if winners
+        .iter()
+        .any(|w| w.submitter == submitter && w.emoji == jackpot.emoji)
+    {
+        return Ok(JsValue::from_str(
+            &messages.get("already_won", &[("tier", &jackpot.tier)]),
+        ));
+    }
+    let tier = config.emoji.tier(&jackpot.tier).unwrap_or_default();
+    let claims = winners.iter().filter(|w| w.emoji == jackpot.emoji).count();
+    if tier.claim_limit > 0 && claims >= tier.claim_limit as usize {
+        return Ok(JsValue::from_str(&messages.count(
+            "jackpot_claimed",
+            claims as u64,
+            &[("tier", &tier.name)],
+        )));
+    }
+    let won_at = guess.created_at;
+    let guesses = history::append(&data_client, &submitter, guess)
         .await
         .map_err(|e| format!("Failed to submit, err: {:?}", e))?;
+    winners.push(Winner {
+        submitter: submitter.clone(),
+        emoji: jackpot.emoji.clone(),
+        tier: tier.name.clone(),
+        guesses: guesses.count,
+        won_at,
+        platform: PLATFORM.to_string(),
+    });
+    data_client
+        .write::<&Vec<Winner>>(WINNERS_KEY, &winners)
+        .await
+        .map_err(|e| format!("Failed to record winner, err: {:?}", e))?;
+    archive::count_guess(&data_client, &submitter)
+        .await
+        .map_err(|e| format!("Failed to count guess, err: {:?}", e))?;
+
+    let new_config = config.emoji.won(&tier.name);
+    let config_client = KVClient::new(config.kv.clone(), config.emoji.config_kv_namespace.clone());
+    config_client
+        .write::<&LotteryConfig>(&config.emoji.kv_key(), &new_config)
+        .await
+        .map_err(|e| format!("Failed to write new emoji lottery config, err: {:?}", e))?;
+
+    // Announcements are in the lottery's locale, and only reveal the jackpot once nobody
+    // else can claim it
+    let season = new_config.season.to_string();
+    let args = [
+        ("submitter", submitter.as_str()),
+        ("tier", tier.name.as_str()),
+        ("jackpot", jackpot.emoji.as_str()),
+        ("season", season.as_str()),
+    ];
+    let msg = if new_config.active {
+        new_config
+            .messages()
+            .get("win_announcement_continue", &args)
+    } else {
+        new_config.messages().get("win_announcement", &args)
+    };
+    // The win is already recorded, a failed announcement shouldn't tell the winner otherwise
+    if let Err(e) = announce(&config, msg).await {
+        web_sys::console::error_1(&JsValue::from_str(&format!(
+            "Failed to announce the win of {}, err: {:?}",
+            submitter, e
+        )));
+    }
 
-    Ok(JsValue::from_str(&format!(
-        "Not quite what I had in mind, try again in {:} hrs",
-        retry_in_hrs,
-    )))
+    let mut reply = messages.get("win", &[("tier", &tier.name)]);
+    if let Some(feedback) = feedback {
+        reply.push_str(&format!("\n{}", feedback));
+    }
+    Ok(JsValue::from_str(&reply))
 }
 
-pub async fn checkLastSubmission(submitter: String, config: BotConfig) -> Result<JsValue, JsValue> {
-    let client = KVClient::new(config.kv, config.emoji.data_kv_namespace);
-    let resp = client
-        .read(&submitter)
+pub async fn checkLastSubmission(
+    submitter: String,
+    locale: Option<String>,
+    config: BotConfig,
+) -> Result<JsValue, JsValue> {
+    let client = KVClient::new(config.kv, config.emoji.data_kv_namespace.clone());
+    let guesses = history::read(&client, &submitter)
         .await
         .map_err(|e| format!("Failed to check last submission, err: {:?}", e))?;
-    match resp {
-        Some(s) => Ok(JsValue::from_str(&s.to_string())),
-        None => Ok(JsValue::from_str("You haven't submit anything yet!")),
+    let messages = Messages::negotiate(&[locale.as_deref(), config.emoji.locale.as_deref()]);
+    match guesses.last() {
+        Some(s) => Ok(JsValue::from_str(&s.localize(&messages))),
+        None => Ok(JsValue::from_str(&messages.get("no_guesses", &[]))),
+    }
+}
+
+pub async fn my_guesses(
+    submitter: String,
+    locale: Option<String>,
+    config: BotConfig,
+) -> Result<JsValue, JsValue> {
+    let client = KVClient::new(config.kv, config.emoji.data_kv_namespace.clone());
+    let guesses = history::read(&client, &submitter)
+        .await
+        .map_err(|e| format!("Failed to read guess history, err: {:?}", e))?;
+    let messages = Messages::negotiate(&[locale.as_deref(), config.emoji.locale.as_deref()]);
+    if guesses.count == 0 {
+        return Ok(JsValue::from_str(&messages.get("no_guesses", &[])));
     }
+    Ok(JsValue::from_str(&guesses.localize(&messages)))
 }
 
 #[derive(Serialize, Debug)]
@@ -98,24 +275,20 @@ struct WebhookBody {
 
 pub struct WebhookClient {
     url: String,
+    trace: Trace,
 }
 
-pub fn new_webhook_client(url: String) -> WebhookClient {
-    return WebhookClient { url };
+pub fn new_webhook_client(url: String, trace: Trace) -> WebhookClient {
+    return WebhookClient { url, trace };
 }
 
 impl WebhookClient {
     pub async fn execute(&self, message: String) -> Result<(), Error> {
-        let mut headers = HashMap::new();
-        headers.insert("Content-type".to_string(), "application/json".to_string());
-        let req = Request {
-            url: self.url.clone(),
-            method: Method::POST,
-            headers: headers,
-            body: WebhookBody { content: message },
-        };
+        let req = Request::new(Method::POST, self.url.clone())
+            .trace(&self.trace)
+            .body(Body::json(&WebhookBody { content: message })?);
         // webhook doesn't return a response https://discordapp.com/developers/docs/resources/webhook#execute-webhook
-        send(req).await?;
+        send(req).await?.error_for_status().await?;
         Ok(())
     }
 }
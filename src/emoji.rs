@@ -1,93 +1,753 @@
-use rand::rngs::SmallRng;
-use rand::Rng;
-use rand::SeedableRng;
+use super::cooldown::CooldownPolicy;
+use super::emoji_catalog::{self, EmojiInfo, EMOJIS};
+use super::error::Error;
+use super::fairness;
+use super::game::{self, Game, GameKind, Verdict};
+use super::history::GuessHistory;
+use super::i18n::Messages;
+use super::normalize::{self, NormalizeConfig};
+use super::puzzle::PuzzleConfig;
+use super::raffle::RaffleConfig;
+use super::utils;
+
+use rand::rngs::OsRng;
+use rand::RngCore;
 use std::char;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+// Catalog lookups by emoji, lowercase name and shortcode. The first entry wins, like a scan
+// of the catalog would.
+struct CatalogIndex {
+    by_emoji: HashMap<&'static str, &'static EmojiInfo>,
+    by_name: HashMap<String, &'static EmojiInfo>,
+    by_shortcode: HashMap<&'static str, &'static EmojiInfo>,
+}
+
+impl CatalogIndex {
+    fn build() -> CatalogIndex {
+        let mut index = CatalogIndex {
+            by_emoji: HashMap::new(),
+            by_name: HashMap::new(),
+            by_shortcode: HashMap::new(),
+        };
+        for info in EMOJIS.iter() {
+            index.by_emoji.entry(info.emoji).or_insert(info);
+            index
+                .by_name
+                .entry(info.name.to_lowercase())
+                .or_insert(info);
+            for shortcode in info.shortcodes.iter() {
+                index.by_shortcode.entry(shortcode).or_insert(info);
+            }
+        }
+        index
+    }
+}
 
-#[derive(Deserialize, Serialize)]
+thread_local! {
+    // Built on first use, guesses are looked up several times per request
+    static INDEX: CatalogIndex = CatalogIndex::build();
+}
+
+#[derive(Deserialize, Serialize, Clone)]
 pub struct LotteryConfig {
     pub has_winner: bool,
     pub active: bool,
     pub jackpot: String,
     pub season: u64,
+    // Which game the season runs
+    #[serde(default)]
+    pub game: GameKind,
+    // Only used by puzzle seasons
+    #[serde(default)]
+    pub puzzle: PuzzleConfig,
+    // Only used by raffle seasons
+    #[serde(default)]
+    pub raffle: RaffleConfig,
+    // Only used when cooldown isn't set
     pub retry_in_hrs: i64,
+    #[serde(default)]
+    pub cooldown: Option<CooldownPolicy>,
+    // Submissions whose client timestamp is further than this from the worker's clock are
+    // rejected
+    #[serde(default = "default_max_clock_skew_secs")]
+    pub max_clock_skew_secs: i64,
+    // Jackpots are drawn from emoji introduced up to this Emoji version, so they render on
+    // platforms that lag behind the latest release
+    #[serde(default = "default_emoji_version")]
+    pub emoji_version: f32,
+    // How guesses and the jackpot are normalized before they are compared
+    #[serde(default)]
+    pub normalize: NormalizeConfig,
+    // Restricts the season to a theme, the whole catalog is used if it's empty
+    #[serde(default)]
+    pub pool: Pool,
+    // Tell players how close a wrong guess was, leave it off for pure luck seasons
+    #[serde(default)]
+    pub hints: bool,
+    #[serde(default)]
+    pub winner_policy: WinnerPolicy,
+    // Repeating an earlier wrong guess is rejected without starting the cooldown, unless
+    // this is set
+    #[serde(default)]
+    pub allow_repeat_guesses: bool,
+    // Hides the elimination board of wrong guesses, see board::show_board
+    #[serde(default)]
+    pub hard_mode: bool,
+    // Prize tiers to draw jackpots for, a season has a single jackpot if it's empty
+    #[serde(default)]
+    pub tiers: Vec<Tier>,
+    // Every jackpot of the season, the first one of the first tier is also in jackpot
+    #[serde(default)]
+    pub jackpots: Vec<Jackpot>,
     pub config_kv_namespace: String,
     pub data_kv_namespace: String,
+    // Secret until the season ends, see fairness::commitment
+    #[serde(default)]
+    pub salt: String,
+    #[serde(default)]
+    pub commitment: String,
+    // Unix timestamp of when the season commenced
+    #[serde(default)]
+    pub started_at: i64,
+    // The community the lottery runs for, None for the deployment's own lottery
+    #[serde(default)]
+    pub tenant: Option<String>,
+    // Locale of announcements and the fallback for replies, e.g. fr. English if unset
+    #[serde(default)]
+    pub locale: Option<String>,
 }
 
 impl LotteryConfig {
+    // Messages in the lottery's locale, for announcements and replies to requests that
+    // don't carry the user's
+    pub fn messages(&self) -> Messages {
+        Messages::negotiate(&[self.locale.as_deref()])
+    }
+
     pub fn kv_key(&self) -> String {
-        return "lotteryConfig".to_string();
+        return self.scoped_key(LOTTERY_CONFIG_KEY);
     }
 
-    pub fn commence(&self, new_data_namespace: String) -> Self {
-        let jackpot = generate_random_emoji();
-        Self {
+    // Tenants share the config namespace, so their keys are prefixed with the tenant
+    pub fn scoped_key(&self, key: &str) -> String {
+        scoped_key(self.tenant.as_ref().map(|t| t.as_str()), key)
+    }
+
+    pub fn cooldown(&self) -> CooldownPolicy {
+        match &self.cooldown {
+            Some(cooldown) => cooldown.clone(),
+            None => game::for_season(self).default_cooldown(self),
+        }
+    }
+
+    pub fn normalize(&self, emoji: &str) -> String {
+        normalize::normalize(emoji, &self.normalize)
+    }
+
+    // Resolves a guess given as an emoji, a shortcode (:tada:, or Slack's
+    // :wave::skin-tone-3:) or a CLDR short name (octopus) to its normalized emoji.
+    // Returns None if the guess isn't an emoji from the catalog.
+    pub fn resolve(&self, guess: &str) -> Option<String> {
+        let guess = guess.trim();
+        let emoji = from_shortcode(guess)
+            .or_else(|| from_name(guess))
+            .unwrap_or_else(|| guess.to_string());
+        let normalized = self.normalize(&emoji);
+        lookup(&normalized).map(|_| normalized)
+    }
+
+    // Whether a resolved guess could be one of the season's jackpots
+    pub fn in_pool(&self, emoji: &str) -> bool {
+        match lookup(emoji) {
+            Some(info) => self.drawable(info, &self.pool_emoji()),
+            None => false,
+        }
+    }
+
+    // The pool's explicit emoji, normalized once rather than for every catalog entry
+    fn pool_emoji(&self) -> HashSet<String> {
+        self.pool.emoji.iter().map(|e| self.normalize(e)).collect()
+    }
+
+    // Both sides are normalized, so the pool matches however its emoji are spelled
+    fn pool_contains(&self, info: &EmojiInfo, pool_emoji: &HashSet<String>) -> bool {
+        let pool = &self.pool;
+        pool.is_empty()
+            || pool.groups.iter().any(|g| g == info.group)
+            || pool.subgroups.iter().any(|s| s == info.subgroup)
+            || pool_emoji.contains(&self.normalize(info.emoji))
+    }
+
+    // Emoji newer than the season's Emoji version are never drawn
+    fn drawable(&self, info: &EmojiInfo, pool_emoji: &HashSet<String>) -> bool {
+        info.version <= self.emoji_version && self.pool_contains(info, pool_emoji)
+    }
+
+    // Draws distinct emoji from the season's pool, see tools/emoji-catalog. Fails if there
+    // are none to draw or the pool is too small, before anything is drawn.
+    pub fn draw_emoji(&self, n: usize) -> Result<Vec<&'static EmojiInfo>, Error> {
+        let pool_emoji = self.pool_emoji();
+        let mut candidates: Vec<&EmojiInfo> = EMOJIS
+            .iter()
+            .filter(|e| !has_skin_tone(e) && self.drawable(e, &pool_emoji))
+            .collect();
+        self.check_draw(n, candidates.len())
+            .map_err(|e| Error::Jv(JsValue::from_str(&e)))?;
+        let mut drawn = Vec::new();
+        for _ in 0..n {
+            let i = random_index(candidates.len())?;
+            drawn.push(candidates.swap_remove(i));
+        }
+        Ok(drawn)
+    }
+
+    fn check_draw(&self, n: usize, candidates: usize) -> Result<(), String> {
+        if n == 0 || n > candidates {
+            return Err(format!(
+                "Can't draw {} emoji from a pool of {} ({})",
+                n, candidates, self.pool
+            ));
+        }
+        Ok(())
+    }
+
+    // Draws distinct jackpots for every tier
+    fn draw_jackpots(&self) -> Result<Vec<Jackpot>, Error> {
+        let tiers: Vec<String> = self
+            .tiers()
+            .iter()
+            .flat_map(|t| (0..t.count).map(move |_| t.name.clone()))
+            .collect();
+        let drawn = self.draw_emoji(tiers.len())?;
+        Ok(drawn
+            .into_iter()
+            .zip(tiers)
+            .map(|(info, tier)| Jackpot {
+                emoji: info.emoji.to_string(),
+                tier,
+            })
+            .collect())
+    }
+
+    pub fn tiers(&self) -> Vec<Tier> {
+        if self.tiers.is_empty() {
+            vec![Tier::default()]
+        } else {
+            self.tiers.clone()
+        }
+    }
+
+    pub fn tier(&self, name: &str) -> Option<Tier> {
+        self.tiers().into_iter().find(|t| t.name == name)
+    }
+
+    // Configs written before tiers existed only have jackpot
+    pub fn all_jackpots(&self) -> Vec<Jackpot> {
+        if self.jackpots.is_empty() {
+            vec![Jackpot {
+                emoji: self.jackpot.clone(),
+                tier: Tier::default().name,
+            }]
+        } else {
+            self.jackpots.clone()
+        }
+    }
+
+    // What the commitment is computed over and revealed at the end of the season
+    pub fn revealed_jackpots(&self) -> String {
+        self.all_jackpots()
+            .iter()
+            .map(|j| j.emoji.as_str())
+            .collect::<Vec<&str>>()
+            .join(",")
+    }
+
+    pub fn find_jackpot(&self, guess: &str) -> Option<Jackpot> {
+        let guess = self.normalize(guess);
+        self.all_jackpots()
+            .into_iter()
+            .find(|j| self.normalize(&j.emoji) == guess)
+    }
+
+    // Compares a wrong guess with the closest jackpot using the catalog's groups and
+    // subgroups. Returns None if hints are disabled for the season.
+    pub fn hint(&self, guess: &str) -> Option<Hint> {
+        if !self.hints {
+            return None;
+        }
+        let guess = lookup(guess)?;
+        self.all_jackpots()
+            .iter()
+            .filter_map(|j| lookup(&self.normalize(&j.emoji)))
+            .map(|jackpot| {
+                if guess.group == jackpot.group && guess.subgroup == jackpot.subgroup {
+                    Hint::SameSubgroup(guess.subgroup)
+                } else if guess.group == jackpot.group {
+                    Hint::SameGroup(guess.group)
+                } else {
+                    Hint::Cold
+                }
+            })
+            .max_by_key(|hint| hint.heat())
+    }
+
+    pub fn commence(&self) -> Result<Self, Error> {
+        self.commence_with(self.draw_jackpots()?)
+    }
+
+    // Starts a new season with jackpots drawn by the game, there are none in a raffle. The
+    // data namespace is left to the caller, so nothing is changed in KV if the draw fails.
+    pub fn commence_with(&self, jackpots: Vec<Jackpot>) -> Result<Self, Error> {
+        // There's nothing to commit to without a jackpot, e.g. in a raffle
+        let salt = if jackpots.is_empty() {
+            String::new()
+        } else {
+            fairness::new_salt()?
+        };
+        let mut new_config = Self {
             has_winner: false,
             active: true,
-            jackpot: jackpot.to_string(),
+            jackpot: jackpots
+                .first()
+                .map(|j| j.emoji.clone())
+                .unwrap_or_default(),
             season: self.season + 1,
-            retry_in_hrs: self.retry_in_hrs,
-            config_kv_namespace: self.config_kv_namespace.clone(),
-            data_kv_namespace: new_data_namespace,
+            jackpots,
+            salt,
+            commitment: String::new(),
+            started_at: utils::now(),
+            ..self.clone()
+        };
+        if !new_config.jackpots.is_empty() {
+            new_config.commitment =
+                fairness::commitment(&new_config.revealed_jackpots(), &new_config.salt);
+        }
+        Ok(new_config)
+    }
+
+    // Marks the season as won, ending it if that's the policy and the top tier was hit
+    pub fn won(&self, tier: &str) -> Self {
+        let top_tier = self.tiers()[0].name == tier;
+        Self {
+            has_winner: true,
+            active: self.active && !(top_tier && self.winner_policy == WinnerPolicy::EndSeason),
+            ..self.clone()
         }
     }
 
     pub fn end(&self) -> Self {
         Self {
-            has_winner: false,
             active: false,
-            jackpot: self.jackpot.clone(),
-            season: self.season,
-            retry_in_hrs: self.retry_in_hrs,
-            config_kv_namespace: self.config_kv_namespace.clone(),
-            data_kv_namespace: self.data_kv_namespace.clone(),
+            ..self.clone()
+        }
+    }
+}
+
+// The original game, players guess which emoji was drawn as the jackpot
+pub struct EmojiLottery;
+
+impl Game for EmojiLottery {
+    fn start_season(&self, config: &LotteryConfig) -> Result<LotteryConfig, Error> {
+        config.commence()
+    }
+
+    fn judge(
+        &self,
+        config: &LotteryConfig,
+        _submitter: &str,
+        submission: &str,
+        history: &GuessHistory,
+        messages: &Messages,
+    ) -> Verdict {
+        let guess = match config.resolve(submission) {
+            Some(guess) => guess,
+            None => {
+                return Verdict::Rejected(messages.get("unknown_emoji", &[("emoji", submission)]))
+            }
+        };
+        if !config.in_pool(&guess) {
+            return Verdict::Rejected(messages.get(
+                "not_in_pool",
+                &[("emoji", &guess), ("pool", &config.pool.to_string())],
+            ));
+        }
+        if !config.allow_repeat_guesses && history.missed(&guess) {
+            return Verdict::Rejected(messages.get("repeat_guess", &[("emoji", &guess)]));
+        }
+        match config.find_jackpot(&guess) {
+            Some(jackpot) => Verdict::Win {
+                value: guess,
+                jackpot,
+                feedback: None,
+            },
+            None => Verdict::Miss {
+                feedback: config.hint(&guess).map(|hint| hint.localize(messages)),
+                eliminated: vec![guess.clone()],
+                value: guess,
+            },
+        }
+    }
+
+    fn default_cooldown(&self, config: &LotteryConfig) -> CooldownPolicy {
+        CooldownPolicy::Interval {
+            minutes: config.retry_in_hrs * 60,
+        }
+    }
+
+    fn end_season(&self, config: &LotteryConfig) -> LotteryConfig {
+        config.end()
+    }
+
+    fn render_status(&self, config: &LotteryConfig, messages: &Messages) -> String {
+        if config.active {
+            let mut status = messages.get("lottery_active", &[("commitment", &config.commitment)]);
+            if !config.pool.is_empty() {
+                status
+                    .push_str(&messages.get("season_theme", &[("pool", &config.pool.to_string())]));
+            }
+            status
+        } else {
+            let mut status = messages.get(
+                "lottery_over",
+                &[
+                    ("jackpots", &config.revealed_jackpots()),
+                    ("salt", &config.salt),
+                    ("commitment", &config.commitment),
+                ],
+            );
+            if !config.has_winner {
+                status.push_str(&messages.get("nobody_found", &[]));
+            }
+            status
         }
     }
 }
 
-// Generate a random emoji based on v13 of emoji list https://unicode.org/emoji/charts/full-emoji-list.html
-// The unicode range is U+1F3F4 to U+1F600
-fn generate_random_emoji() -> char {
-    use js_sys::Date;
+/*
+Sample Pool for a food themed season, an emoji belongs to the pool if it matches any of
+the groups, subgroups or explicit emoji
+{
+    "groups": ["Food & Drink"],
+    "subgroups": ["animal-marine"],
+    "emoji": ["🦀", "🐟"]
+}
+*/
+#[derive(Deserialize, Serialize, Clone, Default, Debug)]
+pub struct Pool {
+    #[serde(default)]
+    pub groups: Vec<String>,
+    #[serde(default)]
+    pub subgroups: Vec<String>,
+    #[serde(default)]
+    pub emoji: Vec<String>,
+}
 
-    let ticks = Date::now();
-    //convert the number to byte array
-    let tick_bytes = transmute(ticks as u128);
-    let mut rng = SmallRng::from_seed(tick_bytes);
-    // get_range method will hang
-    let x: u32 = rng.gen();
-    let emoji = char::from_u32(emoji_range(x)).unwrap_or('💡');
-    emoji
+impl Pool {
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty() && self.subgroups.is_empty() && self.emoji.is_empty()
+    }
 }
 
-fn emoji_range(x: u32) -> u32 {
-    0x1F3F4 + x % (0x1F600 - 0x1F3F4)
+impl fmt::Display for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "any emoji");
+        }
+        let parts: Vec<String> = self
+            .groups
+            .iter()
+            .chain(self.subgroups.iter())
+            .chain(self.emoji.iter())
+            .cloned()
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
 }
 
-fn transmute(x: u128) -> [u8; 16] {
-    let b1: u8 = ((x >> 120) & 0xffffffff) as u8;
-    let b2: u8 = ((x >> 112) & 0xffffffff) as u8;
-    let b3: u8 = ((x >> 104) & 0xffffffff) as u8;
-    let b4: u8 = ((x >> 96) & 0xffffffff) as u8;
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum WinnerPolicy {
+    // Keep the season open after the first winner, later winners are recorded too
+    Continue,
+    // The first winner of the top tier ends the season
+    EndSeason,
+}
 
-    let b5: u8 = ((x >> 88) & 0xffffffff) as u8;
-    let b6: u8 = ((x >> 80) & 0xffffffff) as u8;
-    let b7: u8 = ((x >> 72) & 0xffffffff) as u8;
-    let b8: u8 = ((x >> 64) & 0xffffffff) as u8;
+impl Default for WinnerPolicy {
+    fn default() -> Self {
+        WinnerPolicy::Continue
+    }
+}
 
-    let b9: u8 = ((x >> 56) & 0xffffffff) as u8;
-    let b10: u8 = ((x >> 48) & 0xffffffff) as u8;
-    let b11: u8 = ((x >> 40) & 0xffffffff) as u8;
-    let b12: u8 = ((x >> 32) & 0xffffffff) as u8;
+/*
+Sample tiers, one grand prize and three minor prizes that can be claimed twice each.
+A claim_limit of 0 means any number of players can claim the jackpot.
+[
+    { "name": "grand", "count": 1, "claim_limit": 1 },
+    { "name": "minor", "count": 3, "claim_limit": 2 }
+]
+*/
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct Tier {
+    pub name: String,
+    #[serde(default = "one")]
+    pub count: u32,
+    #[serde(default)]
+    pub claim_limit: u32,
+}
+
+impl Default for Tier {
+    fn default() -> Self {
+        Tier {
+            name: "jackpot".to_string(),
+            count: 1,
+            claim_limit: 0,
+        }
+    }
+}
+
+fn one() -> u32 {
+    1
+}
 
-    let b13: u8 = ((x >> 24) & 0xffffffff) as u8;
-    let b14: u8 = ((x >> 16) & 0xffffffff) as u8;
-    let b15: u8 = ((x >> 8) & 0xffffffff) as u8;
-    let b16: u8 = (x & 0xffffffff) as u8;
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct Jackpot {
+    pub emoji: String,
+    pub tier: String,
+}
+
+pub enum Hint {
+    SameSubgroup(&'static str),
+    SameGroup(&'static str),
+    Cold,
+}
+
+impl Hint {
+    fn heat(&self) -> u8 {
+        match self {
+            Hint::SameSubgroup(_) => 2,
+            Hint::SameGroup(_) => 1,
+            Hint::Cold => 0,
+        }
+    }
+
+    pub fn localize(&self, messages: &Messages) -> String {
+        match self {
+            Hint::SameSubgroup(subgroup) => messages.get("hint_hot", &[("subgroup", subgroup)]),
+            Hint::SameGroup(group) => messages.get("hint_warm", &[("group", group)]),
+            Hint::Cold => messages.get("hint_cold", &[]),
+        }
+    }
+}
+
+pub const LOTTERY_CONFIG_KEY: &str = "lotteryConfig";
+
+// Tenant keys live under t/<tenant>/, tenant ids can't contain a slash so they never clash
+// with the deployment's keys or another tenant's, see tenant::is_valid
+pub fn scoped_key(tenant: Option<&str>, key: &str) -> String {
+    match tenant {
+        Some(tenant) => format!("t/{}/{}", tenant, key),
+        None => key.to_string(),
+    }
+}
+
+fn default_emoji_version() -> f32 {
+    emoji_catalog::VERSION
+}
+
+fn default_max_clock_skew_secs() -> i64 {
+    300
+}
 
-    //Most of the entropy is in the last few bytes and generators are allowed
-    //to assume evenly spread entropy in the seed, so spread the bytes around
-    [
-        b16, b1, b14, b3, b12, b5, b10, b7, b8, b9, b6, b11, b4, b13, b2, b15,
-    ]
+// Returns the catalog entry of a fully-qualified emoji
+// Spellings with other variation selectors than the catalog's are still valid emoji, e.g. ❤
+// when variation selectors aren't ignored, they just don't equal the catalog's
+pub fn lookup(emoji: &str) -> Option<&'static EmojiInfo> {
+    INDEX.with(|index| {
+        index
+            .by_emoji
+            .get(emoji)
+            .or_else(|| normalize::canonical(emoji).and_then(|e| index.by_emoji.get(e)))
+            .copied()
+    })
+}
+
+// :tada: or :wave::skin-tone-3:
+fn from_shortcode(guess: &str) -> Option<String> {
+    if guess.len() < 3 || !guess.starts_with(':') || !guess.ends_with(':') {
+        return None;
+    }
+    let mut parts = guess[1..guess.len() - 1].split("::");
+    let base = by_shortcode(parts.next()?)?;
+    let skin_tone = parts
+        .next()
+        .and_then(|tone| tone.strip_prefix("skin-tone-"))
+        .and_then(|n| n.parse::<u32>().ok())
+        .filter(|n| (2..=6).contains(n))
+        .and_then(|n| char::from_u32(0x1F3FB + n - 2));
+    match skin_tone {
+        // The modifier follows the first emoji of the sequence, which drops its
+        // variation selector, e.g. ✌️ becomes ✌🏽
+        Some(tone) => {
+            let mut chars = base.emoji.chars().filter(|c| *c != '\u{FE0F}');
+            let first = chars.next()?;
+            let toned: String = std::iter::once(first)
+                .chain(std::iter::once(tone))
+                .chain(chars)
+                .collect();
+            Some(lookup(&toned).unwrap_or(base).emoji.to_string())
+        }
+        None => Some(base.emoji.to_string()),
+    }
+}
+
+// octopus, thumbs_up or a shortcode typed without colons
+fn from_name(guess: &str) -> Option<String> {
+    // Names are indexed in lowercase, some have capitals, e.g. OK hand or flag: Japan
+    let name = guess.to_lowercase().replace('_', " ");
+    INDEX
+        .with(|index| index.by_name.get(&name).copied())
+        .or_else(|| by_shortcode(guess))
+        .map(|e| e.emoji.to_string())
+}
+
+fn by_shortcode(shortcode: &str) -> Option<&'static EmojiInfo> {
+    let shortcode = shortcode.to_lowercase();
+    INDEX.with(|index| index.by_shortcode.get(shortcode.as_str()).copied())
+}
+
+// Skin tone variants are left out of jackpot draws, the base emoji stands for all of them
+fn has_skin_tone(info: &EmojiInfo) -> bool {
+    info.emoji.chars().any(normalize::is_skin_tone)
+}
+
+// Returns a uniformly distributed index in [0, len), see random_below
+pub fn random_index(len: usize) -> Result<usize, Error> {
+    Ok(random_below(len as u64)? as usize)
+}
+
+// Returns a uniformly distributed number in [0, bound) using the OS CSPRNG, which is
+// crypto.getRandomValues in workers. Draws that would bias the result towards small
+// numbers are rejected instead of being reduced with a plain modulo.
+pub fn random_below(bound: u64) -> Result<u64, Error> {
+    if bound == 0 {
+        return Err(Error::Jv(JsValue::from_str(
+            "Can't pick from an empty pool",
+        )));
+    }
+    let zone = u64::MAX - u64::MAX % bound;
+    loop {
+        let mut bytes = [0u8; 8];
+        OsRng.try_fill_bytes(&mut bytes).map_err(|e| {
+            Error::Jv(JsValue::from_str(&format!(
+                "Failed to get randomness, err: {}",
+                e
+            )))
+        })?;
+        let x = u64::from_le_bytes(bytes);
+        if x < zone {
+            return Ok(x % bound);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A lottery season whose jackpot is 🐙, extra is spliced into the JSON config
+    fn config(extra: &str) -> LotteryConfig {
+        serde_json::from_str(&format!(
+            r#"{{
+                "has_winner": false,
+                "active": true,
+                "jackpot": "🐙",
+                "season": 1,
+                "retry_in_hrs": 1,
+                "config_kv_namespace": "config",
+                "data_kv_namespace": "data"
+                {}
+            }}"#,
+            extra
+        ))
+        .unwrap()
+    }
+
+    fn judge(config: &LotteryConfig, submission: &str) -> Verdict {
+        EmojiLottery.judge(
+            config,
+            "submitter",
+            submission,
+            &GuessHistory::default(),
+            &Messages::default(),
+        )
+    }
+
+    #[test]
+    fn resolve() {
+        let config = config("");
+        assert_eq!(config.resolve(":tada:"), Some("\u{1F389}".to_string()));
+        // Aliases only gemoji knows
+        assert_eq!(config.resolve(":satisfied:"), Some("\u{1F606}".to_string()));
+        assert_eq!(config.resolve("+1"), Some("\u{1F44D}".to_string()));
+        // CLDR names, whatever the case
+        assert_eq!(config.resolve("OCTOPUS"), Some("\u{1F419}".to_string()));
+        assert_eq!(config.resolve("ok hand"), Some("\u{1F44C}".to_string()));
+        assert_eq!(
+            config.resolve("Flag: Japan"),
+            Some("\u{1F1EF}\u{1F1F5}".to_string())
+        );
+        assert_eq!(config.resolve("not an emoji"), None);
+    }
+
+    #[test]
+    fn unknown_emoji() {
+        match judge(&config(""), "squid-ish") {
+            Verdict::Rejected(reason) => assert_eq!(
+                reason,
+                "I don't know the emoji squid-ish, send an emoji, its :shortcode: or its name"
+            ),
+            _ => panic!("squid-ish isn't an emoji"),
+        }
+    }
+
+    #[test]
+    fn in_pool() {
+        // Written without the variation selector the catalog has
+        let hearts = config(r#", "pool": {"emoji": ["❤"]}"#);
+        assert!(hearts.in_pool(&hearts.resolve("red heart").unwrap()));
+        assert!(!hearts.in_pool("\u{1F419}"));
+
+        let emoji_1 = config(r#", "emoji_version": 1.0"#);
+        assert!(emoji_1.in_pool("\u{1F419}"));
+        // smiling face with tear is from Emoji 13.0, it's never drawn
+        assert!(!emoji_1.in_pool("\u{1F972}"));
+    }
+
+    #[test]
+    fn draw_jackpots() {
+        let config = config(
+            r#", "pool": {"emoji": ["🐙", "🦑"]}, "tiers": [{"name": "grand", "count": 2}]"#,
+        );
+        assert!(config.check_draw(0, 2).is_err());
+        assert!(config.check_draw(3, 2).is_err());
+        let mut drawn: Vec<String> = config
+            .draw_jackpots()
+            .unwrap()
+            .into_iter()
+            .map(|j| j.emoji)
+            .collect();
+        drawn.sort();
+        assert_eq!(drawn, vec!["🐙", "🦑"]);
+    }
+
+    #[test]
+    fn other_variation_selectors_are_valid_guesses() {
+        let config = config(r#", "normalize": { "ignore_variation_selectors": false }"#);
+        assert_eq!(config.resolve("\u{2764}"), Some("\u{2764}".to_string()));
+        assert!(matches!(judge(&config, "\u{2764}"), Verdict::Miss { .. }));
+    }
 }
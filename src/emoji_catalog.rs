@@ -0,0 +1,3521 @@
+// Code generated by tools/emoji-catalog from emoji-test.txt. DO NOT EDIT.
+
+pub struct EmojiInfo {
+    pub emoji: &'static str,
+    // CLDR short name
+    pub name: &'static str,
+    pub group: &'static str,
+    pub subgroup: &'static str,
+    // Emoji version the sequence was introduced in
+    pub version: f32,
+    // Without the surrounding colons, e.g. tada for :tada:
+    pub shortcodes: &'static [&'static str],
+}
+
+pub const VERSION: f32 = 13.1;
+
+#[rustfmt::skip]
+pub static EMOJIS: &[EmojiInfo] = &[
+    EmojiInfo { emoji: "\u{1f600}", name: "grinning face", group: "Smileys & Emotion", subgroup: "face-smiling", version: 1.0, shortcodes: &["grinning"] },
+    EmojiInfo { emoji: "\u{1f603}", name: "grinning face with big eyes", group: "Smileys & Emotion", subgroup: "face-smiling", version: 0.6, shortcodes: &["smiley"] },
+    EmojiInfo { emoji: "\u{1f604}", name: "grinning face with smiling eyes", group: "Smileys & Emotion", subgroup: "face-smiling", version: 0.6, shortcodes: &["smile"] },
+    EmojiInfo { emoji: "\u{1f601}", name: "beaming face with smiling eyes", group: "Smileys & Emotion", subgroup: "face-smiling", version: 0.6, shortcodes: &["grin"] },
+    EmojiInfo { emoji: "\u{1f606}", name: "grinning squinting face", group: "Smileys & Emotion", subgroup: "face-smiling", version: 0.6, shortcodes: &["laughing", "satisfied"] },
+    EmojiInfo { emoji: "\u{1f605}", name: "grinning face with sweat", group: "Smileys & Emotion", subgroup: "face-smiling", version: 0.6, shortcodes: &["sweat_smile"] },
+    EmojiInfo { emoji: "\u{1f923}", name: "rolling on the floor laughing", group: "Smileys & Emotion", subgroup: "face-smiling", version: 3.0, shortcodes: &["rofl"] },
+    EmojiInfo { emoji: "\u{1f602}", name: "face with tears of joy", group: "Smileys & Emotion", subgroup: "face-smiling", version: 0.6, shortcodes: &["joy"] },
+    EmojiInfo { emoji: "\u{1f642}", name: "slightly smiling face", group: "Smileys & Emotion", subgroup: "face-smiling", version: 1.0, shortcodes: &["slightly_smiling_face"] },
+    EmojiInfo { emoji: "\u{1f643}", name: "upside-down face", group: "Smileys & Emotion", subgroup: "face-smiling", version: 1.0, shortcodes: &["upside_down_face"] },
+    EmojiInfo { emoji: "\u{1f609}", name: "winking face", group: "Smileys & Emotion", subgroup: "face-smiling", version: 0.6, shortcodes: &["wink"] },
+    EmojiInfo { emoji: "\u{1f60a}", name: "smiling face with smiling eyes", group: "Smileys & Emotion", subgroup: "face-smiling", version: 0.6, shortcodes: &["blush"] },
+    EmojiInfo { emoji: "\u{1f607}", name: "smiling face with halo", group: "Smileys & Emotion", subgroup: "face-smiling", version: 1.0, shortcodes: &["innocent"] },
+    EmojiInfo { emoji: "\u{1f970}", name: "smiling face with hearts", group: "Smileys & Emotion", subgroup: "face-affection", version: 11.0, shortcodes: &["smiling_face_with_three_hearts"] },
+    EmojiInfo { emoji: "\u{1f60d}", name: "smiling face with heart-eyes", group: "Smileys & Emotion", subgroup: "face-affection", version: 0.6, shortcodes: &["heart_eyes"] },
+    EmojiInfo { emoji: "\u{1f929}", name: "star-struck", group: "Smileys & Emotion", subgroup: "face-affection", version: 5.0, shortcodes: &["star_struck"] },
+    EmojiInfo { emoji: "\u{1f618}", name: "face blowing a kiss", group: "Smileys & Emotion", subgroup: "face-affection", version: 0.6, shortcodes: &["kissing_heart"] },
+    EmojiInfo { emoji: "\u{1f617}", name: "kissing face", group: "Smileys & Emotion", subgroup: "face-affection", version: 1.0, shortcodes: &["kissing"] },
+    EmojiInfo { emoji: "\u{263a}\u{fe0f}", name: "smiling face", group: "Smileys & Emotion", subgroup: "face-affection", version: 0.6, shortcodes: &["relaxed"] },
+    EmojiInfo { emoji: "\u{1f61a}", name: "kissing face with closed eyes", group: "Smileys & Emotion", subgroup: "face-affection", version: 0.6, shortcodes: &["kissing_closed_eyes"] },
+    EmojiInfo { emoji: "\u{1f619}", name: "kissing face with smiling eyes", group: "Smileys & Emotion", subgroup: "face-affection", version: 1.0, shortcodes: &["kissing_smiling_eyes"] },
+    EmojiInfo { emoji: "\u{1f972}", name: "smiling face with tear", group: "Smileys & Emotion", subgroup: "face-affection", version: 13.0, shortcodes: &["smiling_face_with_tear"] },
+    EmojiInfo { emoji: "\u{1f60b}", name: "face savoring food", group: "Smileys & Emotion", subgroup: "face-tongue", version: 0.6, shortcodes: &["yum"] },
+    EmojiInfo { emoji: "\u{1f61b}", name: "face with tongue", group: "Smileys & Emotion", subgroup: "face-tongue", version: 1.0, shortcodes: &["stuck_out_tongue"] },
+    EmojiInfo { emoji: "\u{1f61c}", name: "winking face with tongue", group: "Smileys & Emotion", subgroup: "face-tongue", version: 0.6, shortcodes: &["stuck_out_tongue_winking_eye"] },
+    EmojiInfo { emoji: "\u{1f92a}", name: "zany face", group: "Smileys & Emotion", subgroup: "face-tongue", version: 5.0, shortcodes: &["zany_face"] },
+    EmojiInfo { emoji: "\u{1f61d}", name: "squinting face with tongue", group: "Smileys & Emotion", subgroup: "face-tongue", version: 0.6, shortcodes: &["stuck_out_tongue_closed_eyes"] },
+    EmojiInfo { emoji: "\u{1f911}", name: "money-mouth face", group: "Smileys & Emotion", subgroup: "face-tongue", version: 1.0, shortcodes: &["money_mouth_face"] },
+    EmojiInfo { emoji: "\u{1f917}", name: "hugging face", group: "Smileys & Emotion", subgroup: "face-hand", version: 1.0, shortcodes: &["hugs"] },
+    EmojiInfo { emoji: "\u{1f92d}", name: "face with hand over mouth", group: "Smileys & Emotion", subgroup: "face-hand", version: 5.0, shortcodes: &["hand_over_mouth"] },
+    EmojiInfo { emoji: "\u{1f92b}", name: "shushing face", group: "Smileys & Emotion", subgroup: "face-hand", version: 5.0, shortcodes: &["shushing_face"] },
+    EmojiInfo { emoji: "\u{1f914}", name: "thinking face", group: "Smileys & Emotion", subgroup: "face-hand", version: 1.0, shortcodes: &["thinking"] },
+    EmojiInfo { emoji: "\u{1f910}", name: "zipper-mouth face", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 1.0, shortcodes: &["zipper_mouth_face"] },
+    EmojiInfo { emoji: "\u{1f928}", name: "face with raised eyebrow", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 5.0, shortcodes: &["raised_eyebrow"] },
+    EmojiInfo { emoji: "\u{1f610}", name: "neutral face", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 0.7, shortcodes: &["neutral_face"] },
+    EmojiInfo { emoji: "\u{1f611}", name: "expressionless face", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 1.0, shortcodes: &["expressionless"] },
+    EmojiInfo { emoji: "\u{1f636}", name: "face without mouth", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 1.0, shortcodes: &["no_mouth"] },
+    EmojiInfo { emoji: "\u{1f636}\u{200d}\u{1f32b}\u{fe0f}", name: "face in clouds", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 13.1, shortcodes: &["face_in_clouds"] },
+    EmojiInfo { emoji: "\u{1f60f}", name: "smirking face", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 0.6, shortcodes: &["smirk"] },
+    EmojiInfo { emoji: "\u{1f612}", name: "unamused face", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 0.6, shortcodes: &["unamused"] },
+    EmojiInfo { emoji: "\u{1f644}", name: "face with rolling eyes", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 1.0, shortcodes: &["roll_eyes"] },
+    EmojiInfo { emoji: "\u{1f62c}", name: "grimacing face", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 1.0, shortcodes: &["grimacing"] },
+    EmojiInfo { emoji: "\u{1f62e}\u{200d}\u{1f4a8}", name: "face exhaling", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 13.1, shortcodes: &["face_exhaling"] },
+    EmojiInfo { emoji: "\u{1f925}", name: "lying face", group: "Smileys & Emotion", subgroup: "face-neutral-skeptical", version: 3.0, shortcodes: &["lying_face"] },
+    EmojiInfo { emoji: "\u{1f60c}", name: "relieved face", group: "Smileys & Emotion", subgroup: "face-sleepy", version: 0.6, shortcodes: &["relieved"] },
+    EmojiInfo { emoji: "\u{1f614}", name: "pensive face", group: "Smileys & Emotion", subgroup: "face-sleepy", version: 0.6, shortcodes: &["pensive"] },
+    EmojiInfo { emoji: "\u{1f62a}", name: "sleepy face", group: "Smileys & Emotion", subgroup: "face-sleepy", version: 0.6, shortcodes: &["sleepy"] },
+    EmojiInfo { emoji: "\u{1f924}", name: "drooling face", group: "Smileys & Emotion", subgroup: "face-sleepy", version: 3.0, shortcodes: &["drooling_face"] },
+    EmojiInfo { emoji: "\u{1f634}", name: "sleeping face", group: "Smileys & Emotion", subgroup: "face-sleepy", version: 1.0, shortcodes: &["sleeping"] },
+    EmojiInfo { emoji: "\u{1f637}", name: "face with medical mask", group: "Smileys & Emotion", subgroup: "face-unwell", version: 0.6, shortcodes: &["mask"] },
+    EmojiInfo { emoji: "\u{1f912}", name: "face with thermometer", group: "Smileys & Emotion", subgroup: "face-unwell", version: 1.0, shortcodes: &["face_with_thermometer"] },
+    EmojiInfo { emoji: "\u{1f915}", name: "face with head-bandage", group: "Smileys & Emotion", subgroup: "face-unwell", version: 1.0, shortcodes: &["face_with_head_bandage"] },
+    EmojiInfo { emoji: "\u{1f922}", name: "nauseated face", group: "Smileys & Emotion", subgroup: "face-unwell", version: 3.0, shortcodes: &["nauseated_face"] },
+    EmojiInfo { emoji: "\u{1f92e}", name: "face vomiting", group: "Smileys & Emotion", subgroup: "face-unwell", version: 5.0, shortcodes: &["vomiting_face"] },
+    EmojiInfo { emoji: "\u{1f927}", name: "sneezing face", group: "Smileys & Emotion", subgroup: "face-unwell", version: 3.0, shortcodes: &["sneezing_face"] },
+    EmojiInfo { emoji: "\u{1f975}", name: "hot face", group: "Smileys & Emotion", subgroup: "face-unwell", version: 11.0, shortcodes: &["hot_face"] },
+    EmojiInfo { emoji: "\u{1f976}", name: "cold face", group: "Smileys & Emotion", subgroup: "face-unwell", version: 11.0, shortcodes: &["cold_face"] },
+    EmojiInfo { emoji: "\u{1f974}", name: "woozy face", group: "Smileys & Emotion", subgroup: "face-unwell", version: 11.0, shortcodes: &["woozy_face"] },
+    EmojiInfo { emoji: "\u{1f635}", name: "knocked-out face", group: "Smileys & Emotion", subgroup: "face-unwell", version: 0.6, shortcodes: &["dizzy_face"] },
+    EmojiInfo { emoji: "\u{1f635}\u{200d}\u{1f4ab}", name: "face with spiral eyes", group: "Smileys & Emotion", subgroup: "face-unwell", version: 13.1, shortcodes: &["face_with_spiral_eyes"] },
+    EmojiInfo { emoji: "\u{1f92f}", name: "exploding head", group: "Smileys & Emotion", subgroup: "face-unwell", version: 5.0, shortcodes: &["exploding_head"] },
+    EmojiInfo { emoji: "\u{1f920}", name: "cowboy hat face", group: "Smileys & Emotion", subgroup: "face-hat", version: 3.0, shortcodes: &["cowboy_hat_face"] },
+    EmojiInfo { emoji: "\u{1f973}", name: "partying face", group: "Smileys & Emotion", subgroup: "face-hat", version: 11.0, shortcodes: &["partying_face"] },
+    EmojiInfo { emoji: "\u{1f978}", name: "disguised face", group: "Smileys & Emotion", subgroup: "face-hat", version: 13.0, shortcodes: &["disguised_face"] },
+    EmojiInfo { emoji: "\u{1f60e}", name: "smiling face with sunglasses", group: "Smileys & Emotion", subgroup: "face-glasses", version: 1.0, shortcodes: &["sunglasses"] },
+    EmojiInfo { emoji: "\u{1f913}", name: "nerd face", group: "Smileys & Emotion", subgroup: "face-glasses", version: 1.0, shortcodes: &["nerd_face"] },
+    EmojiInfo { emoji: "\u{1f9d0}", name: "face with monocle", group: "Smileys & Emotion", subgroup: "face-glasses", version: 5.0, shortcodes: &["monocle_face"] },
+    EmojiInfo { emoji: "\u{1f615}", name: "confused face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 1.0, shortcodes: &["confused"] },
+    EmojiInfo { emoji: "\u{1f61f}", name: "worried face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 1.0, shortcodes: &["worried"] },
+    EmojiInfo { emoji: "\u{1f641}", name: "slightly frowning face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 1.0, shortcodes: &["slightly_frowning_face"] },
+    EmojiInfo { emoji: "\u{2639}\u{fe0f}", name: "frowning face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.7, shortcodes: &["frowning_face"] },
+    EmojiInfo { emoji: "\u{1f62e}", name: "face with open mouth", group: "Smileys & Emotion", subgroup: "face-concerned", version: 1.0, shortcodes: &["open_mouth"] },
+    EmojiInfo { emoji: "\u{1f62f}", name: "hushed face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 1.0, shortcodes: &["hushed"] },
+    EmojiInfo { emoji: "\u{1f632}", name: "astonished face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["astonished"] },
+    EmojiInfo { emoji: "\u{1f633}", name: "flushed face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["flushed"] },
+    EmojiInfo { emoji: "\u{1f97a}", name: "pleading face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 11.0, shortcodes: &["pleading_face"] },
+    EmojiInfo { emoji: "\u{1f626}", name: "frowning face with open mouth", group: "Smileys & Emotion", subgroup: "face-concerned", version: 1.0, shortcodes: &["frowning"] },
+    EmojiInfo { emoji: "\u{1f627}", name: "anguished face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 1.0, shortcodes: &["anguished"] },
+    EmojiInfo { emoji: "\u{1f628}", name: "fearful face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["fearful"] },
+    EmojiInfo { emoji: "\u{1f630}", name: "anxious face with sweat", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["cold_sweat"] },
+    EmojiInfo { emoji: "\u{1f625}", name: "sad but relieved face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["disappointed_relieved"] },
+    EmojiInfo { emoji: "\u{1f622}", name: "crying face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["cry"] },
+    EmojiInfo { emoji: "\u{1f62d}", name: "loudly crying face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["sob"] },
+    EmojiInfo { emoji: "\u{1f631}", name: "face screaming in fear", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["scream"] },
+    EmojiInfo { emoji: "\u{1f616}", name: "confounded face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["confounded"] },
+    EmojiInfo { emoji: "\u{1f623}", name: "persevering face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["persevere"] },
+    EmojiInfo { emoji: "\u{1f61e}", name: "disappointed face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["disappointed"] },
+    EmojiInfo { emoji: "\u{1f613}", name: "downcast face with sweat", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["sweat"] },
+    EmojiInfo { emoji: "\u{1f629}", name: "weary face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["weary"] },
+    EmojiInfo { emoji: "\u{1f62b}", name: "tired face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 0.6, shortcodes: &["tired_face"] },
+    EmojiInfo { emoji: "\u{1f971}", name: "yawning face", group: "Smileys & Emotion", subgroup: "face-concerned", version: 12.0, shortcodes: &["yawning_face"] },
+    EmojiInfo { emoji: "\u{1f624}", name: "face with steam from nose", group: "Smileys & Emotion", subgroup: "face-negative", version: 0.6, shortcodes: &["triumph"] },
+    EmojiInfo { emoji: "\u{1f621}", name: "pouting face", group: "Smileys & Emotion", subgroup: "face-negative", version: 0.6, shortcodes: &["rage", "pout"] },
+    EmojiInfo { emoji: "\u{1f620}", name: "angry face", group: "Smileys & Emotion", subgroup: "face-negative", version: 0.6, shortcodes: &["angry"] },
+    EmojiInfo { emoji: "\u{1f92c}", name: "face with symbols on mouth", group: "Smileys & Emotion", subgroup: "face-negative", version: 5.0, shortcodes: &["cursing_face"] },
+    EmojiInfo { emoji: "\u{1f608}", name: "smiling face with horns", group: "Smileys & Emotion", subgroup: "face-negative", version: 1.0, shortcodes: &["smiling_imp"] },
+    EmojiInfo { emoji: "\u{1f47f}", name: "angry face with horns", group: "Smileys & Emotion", subgroup: "face-negative", version: 0.6, shortcodes: &["imp"] },
+    EmojiInfo { emoji: "\u{1f480}", name: "skull", group: "Smileys & Emotion", subgroup: "face-negative", version: 0.6, shortcodes: &["skull"] },
+    EmojiInfo { emoji: "\u{2620}\u{fe0f}", name: "skull and crossbones", group: "Smileys & Emotion", subgroup: "face-negative", version: 1.0, shortcodes: &["skull_and_crossbones"] },
+    EmojiInfo { emoji: "\u{1f4a9}", name: "pile of poo", group: "Smileys & Emotion", subgroup: "face-costume", version: 0.6, shortcodes: &["hankey", "poop", "shit"] },
+    EmojiInfo { emoji: "\u{1f921}", name: "clown face", group: "Smileys & Emotion", subgroup: "face-costume", version: 3.0, shortcodes: &["clown_face"] },
+    EmojiInfo { emoji: "\u{1f479}", name: "ogre", group: "Smileys & Emotion", subgroup: "face-costume", version: 0.6, shortcodes: &["japanese_ogre"] },
+    EmojiInfo { emoji: "\u{1f47a}", name: "goblin", group: "Smileys & Emotion", subgroup: "face-costume", version: 0.6, shortcodes: &["japanese_goblin"] },
+    EmojiInfo { emoji: "\u{1f47b}", name: "ghost", group: "Smileys & Emotion", subgroup: "face-costume", version: 0.6, shortcodes: &["ghost"] },
+    EmojiInfo { emoji: "\u{1f47d}", name: "alien", group: "Smileys & Emotion", subgroup: "face-costume", version: 0.6, shortcodes: &["alien"] },
+    EmojiInfo { emoji: "\u{1f47e}", name: "alien monster", group: "Smileys & Emotion", subgroup: "face-costume", version: 0.6, shortcodes: &["space_invader"] },
+    EmojiInfo { emoji: "\u{1f916}", name: "robot", group: "Smileys & Emotion", subgroup: "face-costume", version: 1.0, shortcodes: &["robot"] },
+    EmojiInfo { emoji: "\u{1f63a}", name: "grinning cat", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["smiley_cat"] },
+    EmojiInfo { emoji: "\u{1f638}", name: "grinning cat with smiling eyes", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["smile_cat"] },
+    EmojiInfo { emoji: "\u{1f639}", name: "cat with tears of joy", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["joy_cat"] },
+    EmojiInfo { emoji: "\u{1f63b}", name: "smiling cat with heart-eyes", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["heart_eyes_cat"] },
+    EmojiInfo { emoji: "\u{1f63c}", name: "cat with wry smile", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["smirk_cat"] },
+    EmojiInfo { emoji: "\u{1f63d}", name: "kissing cat", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["kissing_cat"] },
+    EmojiInfo { emoji: "\u{1f640}", name: "weary cat", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["scream_cat"] },
+    EmojiInfo { emoji: "\u{1f63f}", name: "crying cat", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["crying_cat_face"] },
+    EmojiInfo { emoji: "\u{1f63e}", name: "pouting cat", group: "Smileys & Emotion", subgroup: "cat-face", version: 0.6, shortcodes: &["pouting_cat"] },
+    EmojiInfo { emoji: "\u{1f648}", name: "see-no-evil monkey", group: "Smileys & Emotion", subgroup: "monkey-face", version: 0.6, shortcodes: &["see_no_evil"] },
+    EmojiInfo { emoji: "\u{1f649}", name: "hear-no-evil monkey", group: "Smileys & Emotion", subgroup: "monkey-face", version: 0.6, shortcodes: &["hear_no_evil"] },
+    EmojiInfo { emoji: "\u{1f64a}", name: "speak-no-evil monkey", group: "Smileys & Emotion", subgroup: "monkey-face", version: 0.6, shortcodes: &["speak_no_evil"] },
+    EmojiInfo { emoji: "\u{1f48b}", name: "kiss mark", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["kiss"] },
+    EmojiInfo { emoji: "\u{1f48c}", name: "love letter", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["love_letter"] },
+    EmojiInfo { emoji: "\u{1f498}", name: "heart with arrow", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["cupid"] },
+    EmojiInfo { emoji: "\u{1f49d}", name: "heart with ribbon", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["gift_heart"] },
+    EmojiInfo { emoji: "\u{1f496}", name: "sparkling heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["sparkling_heart"] },
+    EmojiInfo { emoji: "\u{1f497}", name: "growing heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["heartpulse"] },
+    EmojiInfo { emoji: "\u{1f493}", name: "beating heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["heartbeat"] },
+    EmojiInfo { emoji: "\u{1f49e}", name: "revolving hearts", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["revolving_hearts"] },
+    EmojiInfo { emoji: "\u{1f495}", name: "two hearts", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["two_hearts"] },
+    EmojiInfo { emoji: "\u{1f49f}", name: "heart decoration", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["heart_decoration"] },
+    EmojiInfo { emoji: "\u{2763}\u{fe0f}", name: "heart exclamation", group: "Smileys & Emotion", subgroup: "emotion", version: 1.0, shortcodes: &["heavy_heart_exclamation"] },
+    EmojiInfo { emoji: "\u{1f494}", name: "broken heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["broken_heart"] },
+    EmojiInfo { emoji: "\u{2764}\u{fe0f}\u{200d}\u{1f525}", name: "heart on fire", group: "Smileys & Emotion", subgroup: "emotion", version: 13.1, shortcodes: &["heart_on_fire"] },
+    EmojiInfo { emoji: "\u{2764}\u{fe0f}\u{200d}\u{1fa79}", name: "mending heart", group: "Smileys & Emotion", subgroup: "emotion", version: 13.1, shortcodes: &["mending_heart"] },
+    EmojiInfo { emoji: "\u{2764}\u{fe0f}", name: "red heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["heart"] },
+    EmojiInfo { emoji: "\u{1f9e1}", name: "orange heart", group: "Smileys & Emotion", subgroup: "emotion", version: 5.0, shortcodes: &["orange_heart"] },
+    EmojiInfo { emoji: "\u{1f49b}", name: "yellow heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["yellow_heart"] },
+    EmojiInfo { emoji: "\u{1f49a}", name: "green heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["green_heart"] },
+    EmojiInfo { emoji: "\u{1f499}", name: "blue heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["blue_heart"] },
+    EmojiInfo { emoji: "\u{1f49c}", name: "purple heart", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["purple_heart"] },
+    EmojiInfo { emoji: "\u{1f90e}", name: "brown heart", group: "Smileys & Emotion", subgroup: "emotion", version: 12.0, shortcodes: &["brown_heart"] },
+    EmojiInfo { emoji: "\u{1f5a4}", name: "black heart", group: "Smileys & Emotion", subgroup: "emotion", version: 3.0, shortcodes: &["black_heart"] },
+    EmojiInfo { emoji: "\u{1f90d}", name: "white heart", group: "Smileys & Emotion", subgroup: "emotion", version: 12.0, shortcodes: &["white_heart"] },
+    EmojiInfo { emoji: "\u{1f4af}", name: "hundred points", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["100"] },
+    EmojiInfo { emoji: "\u{1f4a2}", name: "anger symbol", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["anger"] },
+    EmojiInfo { emoji: "\u{1f4a5}", name: "collision", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["boom", "collision"] },
+    EmojiInfo { emoji: "\u{1f4ab}", name: "dizzy", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["dizzy"] },
+    EmojiInfo { emoji: "\u{1f4a6}", name: "sweat droplets", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["sweat_drops"] },
+    EmojiInfo { emoji: "\u{1f4a8}", name: "dashing away", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["dash"] },
+    EmojiInfo { emoji: "\u{1f573}\u{fe0f}", name: "hole", group: "Smileys & Emotion", subgroup: "emotion", version: 0.7, shortcodes: &["hole"] },
+    EmojiInfo { emoji: "\u{1f4a3}", name: "bomb", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["bomb"] },
+    EmojiInfo { emoji: "\u{1f4ac}", name: "speech balloon", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["speech_balloon"] },
+    EmojiInfo { emoji: "\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}", name: "eye in speech bubble", group: "Smileys & Emotion", subgroup: "emotion", version: 2.0, shortcodes: &["eye_speech_bubble"] },
+    EmojiInfo { emoji: "\u{1f5e8}\u{fe0f}", name: "left speech bubble", group: "Smileys & Emotion", subgroup: "emotion", version: 2.0, shortcodes: &["left_speech_bubble"] },
+    EmojiInfo { emoji: "\u{1f5ef}\u{fe0f}", name: "right anger bubble", group: "Smileys & Emotion", subgroup: "emotion", version: 0.7, shortcodes: &["right_anger_bubble"] },
+    EmojiInfo { emoji: "\u{1f4ad}", name: "thought balloon", group: "Smileys & Emotion", subgroup: "emotion", version: 1.0, shortcodes: &["thought_balloon"] },
+    EmojiInfo { emoji: "\u{1f4a4}", name: "zzz", group: "Smileys & Emotion", subgroup: "emotion", version: 0.6, shortcodes: &["zzz"] },
+    EmojiInfo { emoji: "\u{1f44b}", name: "waving hand", group: "People & Body", subgroup: "hand-fingers-open", version: 0.6, shortcodes: &["wave"] },
+    EmojiInfo { emoji: "\u{1f44b}\u{1f3fb}", name: "waving hand: light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44b}\u{1f3fc}", name: "waving hand: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44b}\u{1f3fd}", name: "waving hand: medium skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44b}\u{1f3fe}", name: "waving hand: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44b}\u{1f3ff}", name: "waving hand: dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91a}", name: "raised back of hand", group: "People & Body", subgroup: "hand-fingers-open", version: 3.0, shortcodes: &["raised_back_of_hand"] },
+    EmojiInfo { emoji: "\u{1f91a}\u{1f3fb}", name: "raised back of hand: light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91a}\u{1f3fc}", name: "raised back of hand: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91a}\u{1f3fd}", name: "raised back of hand: medium skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91a}\u{1f3fe}", name: "raised back of hand: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91a}\u{1f3ff}", name: "raised back of hand: dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f590}\u{fe0f}", name: "hand with fingers splayed", group: "People & Body", subgroup: "hand-fingers-open", version: 0.7, shortcodes: &["raised_hand_with_fingers_splayed"] },
+    EmojiInfo { emoji: "\u{1f590}\u{1f3fb}", name: "hand with fingers splayed: light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f590}\u{1f3fc}", name: "hand with fingers splayed: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f590}\u{1f3fd}", name: "hand with fingers splayed: medium skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f590}\u{1f3fe}", name: "hand with fingers splayed: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f590}\u{1f3ff}", name: "hand with fingers splayed: dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270b}", name: "raised hand", group: "People & Body", subgroup: "hand-fingers-open", version: 0.6, shortcodes: &["hand", "raised_hand"] },
+    EmojiInfo { emoji: "\u{270b}\u{1f3fb}", name: "raised hand: light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270b}\u{1f3fc}", name: "raised hand: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270b}\u{1f3fd}", name: "raised hand: medium skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270b}\u{1f3fe}", name: "raised hand: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270b}\u{1f3ff}", name: "raised hand: dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f596}", name: "vulcan salute", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &["vulcan_salute"] },
+    EmojiInfo { emoji: "\u{1f596}\u{1f3fb}", name: "vulcan salute: light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f596}\u{1f3fc}", name: "vulcan salute: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f596}\u{1f3fd}", name: "vulcan salute: medium skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f596}\u{1f3fe}", name: "vulcan salute: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f596}\u{1f3ff}", name: "vulcan salute: dark skin tone", group: "People & Body", subgroup: "hand-fingers-open", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44c}", name: "OK hand", group: "People & Body", subgroup: "hand-fingers-partial", version: 0.6, shortcodes: &["ok_hand"] },
+    EmojiInfo { emoji: "\u{1f44c}\u{1f3fb}", name: "OK hand: light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44c}\u{1f3fc}", name: "OK hand: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44c}\u{1f3fd}", name: "OK hand: medium skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44c}\u{1f3fe}", name: "OK hand: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44c}\u{1f3ff}", name: "OK hand: dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90c}", name: "pinched fingers", group: "People & Body", subgroup: "hand-fingers-partial", version: 13.0, shortcodes: &["pinched_fingers"] },
+    EmojiInfo { emoji: "\u{1f90c}\u{1f3fb}", name: "pinched fingers: light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90c}\u{1f3fc}", name: "pinched fingers: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90c}\u{1f3fd}", name: "pinched fingers: medium skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90c}\u{1f3fe}", name: "pinched fingers: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90c}\u{1f3ff}", name: "pinched fingers: dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90f}", name: "pinching hand", group: "People & Body", subgroup: "hand-fingers-partial", version: 12.0, shortcodes: &["pinching_hand"] },
+    EmojiInfo { emoji: "\u{1f90f}\u{1f3fb}", name: "pinching hand: light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90f}\u{1f3fc}", name: "pinching hand: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90f}\u{1f3fd}", name: "pinching hand: medium skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90f}\u{1f3fe}", name: "pinching hand: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f90f}\u{1f3ff}", name: "pinching hand: dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270c}\u{fe0f}", name: "victory hand", group: "People & Body", subgroup: "hand-fingers-partial", version: 0.6, shortcodes: &["v"] },
+    EmojiInfo { emoji: "\u{270c}\u{1f3fb}", name: "victory hand: light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270c}\u{1f3fc}", name: "victory hand: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270c}\u{1f3fd}", name: "victory hand: medium skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270c}\u{1f3fe}", name: "victory hand: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270c}\u{1f3ff}", name: "victory hand: dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91e}", name: "crossed fingers", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &["crossed_fingers"] },
+    EmojiInfo { emoji: "\u{1f91e}\u{1f3fb}", name: "crossed fingers: light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91e}\u{1f3fc}", name: "crossed fingers: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91e}\u{1f3fd}", name: "crossed fingers: medium skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91e}\u{1f3fe}", name: "crossed fingers: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91e}\u{1f3ff}", name: "crossed fingers: dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91f}", name: "love-you gesture", group: "People & Body", subgroup: "hand-fingers-partial", version: 5.0, shortcodes: &["love_you_gesture"] },
+    EmojiInfo { emoji: "\u{1f91f}\u{1f3fb}", name: "love-you gesture: light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91f}\u{1f3fc}", name: "love-you gesture: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91f}\u{1f3fd}", name: "love-you gesture: medium skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91f}\u{1f3fe}", name: "love-you gesture: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91f}\u{1f3ff}", name: "love-you gesture: dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f918}", name: "sign of the horns", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &["metal"] },
+    EmojiInfo { emoji: "\u{1f918}\u{1f3fb}", name: "sign of the horns: light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f918}\u{1f3fc}", name: "sign of the horns: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f918}\u{1f3fd}", name: "sign of the horns: medium skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f918}\u{1f3fe}", name: "sign of the horns: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f918}\u{1f3ff}", name: "sign of the horns: dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f919}", name: "call me hand", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &["call_me_hand"] },
+    EmojiInfo { emoji: "\u{1f919}\u{1f3fb}", name: "call me hand: light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f919}\u{1f3fc}", name: "call me hand: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f919}\u{1f3fd}", name: "call me hand: medium skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f919}\u{1f3fe}", name: "call me hand: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f919}\u{1f3ff}", name: "call me hand: dark skin tone", group: "People & Body", subgroup: "hand-fingers-partial", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f448}", name: "backhand index pointing left", group: "People & Body", subgroup: "hand-single-finger", version: 0.6, shortcodes: &["point_left"] },
+    EmojiInfo { emoji: "\u{1f448}\u{1f3fb}", name: "backhand index pointing left: light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f448}\u{1f3fc}", name: "backhand index pointing left: medium-light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f448}\u{1f3fd}", name: "backhand index pointing left: medium skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f448}\u{1f3fe}", name: "backhand index pointing left: medium-dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f448}\u{1f3ff}", name: "backhand index pointing left: dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f449}", name: "backhand index pointing right", group: "People & Body", subgroup: "hand-single-finger", version: 0.6, shortcodes: &["point_right"] },
+    EmojiInfo { emoji: "\u{1f449}\u{1f3fb}", name: "backhand index pointing right: light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f449}\u{1f3fc}", name: "backhand index pointing right: medium-light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f449}\u{1f3fd}", name: "backhand index pointing right: medium skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f449}\u{1f3fe}", name: "backhand index pointing right: medium-dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f449}\u{1f3ff}", name: "backhand index pointing right: dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f446}", name: "backhand index pointing up", group: "People & Body", subgroup: "hand-single-finger", version: 0.6, shortcodes: &["point_up_2"] },
+    EmojiInfo { emoji: "\u{1f446}\u{1f3fb}", name: "backhand index pointing up: light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f446}\u{1f3fc}", name: "backhand index pointing up: medium-light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f446}\u{1f3fd}", name: "backhand index pointing up: medium skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f446}\u{1f3fe}", name: "backhand index pointing up: medium-dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f446}\u{1f3ff}", name: "backhand index pointing up: dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f595}", name: "middle finger", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &["middle_finger", "fu"] },
+    EmojiInfo { emoji: "\u{1f595}\u{1f3fb}", name: "middle finger: light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f595}\u{1f3fc}", name: "middle finger: medium-light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f595}\u{1f3fd}", name: "middle finger: medium skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f595}\u{1f3fe}", name: "middle finger: medium-dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f595}\u{1f3ff}", name: "middle finger: dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f447}", name: "backhand index pointing down", group: "People & Body", subgroup: "hand-single-finger", version: 0.6, shortcodes: &["point_down"] },
+    EmojiInfo { emoji: "\u{1f447}\u{1f3fb}", name: "backhand index pointing down: light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f447}\u{1f3fc}", name: "backhand index pointing down: medium-light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f447}\u{1f3fd}", name: "backhand index pointing down: medium skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f447}\u{1f3fe}", name: "backhand index pointing down: medium-dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f447}\u{1f3ff}", name: "backhand index pointing down: dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{261d}\u{fe0f}", name: "index pointing up", group: "People & Body", subgroup: "hand-single-finger", version: 0.6, shortcodes: &["point_up"] },
+    EmojiInfo { emoji: "\u{261d}\u{1f3fb}", name: "index pointing up: light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{261d}\u{1f3fc}", name: "index pointing up: medium-light skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{261d}\u{1f3fd}", name: "index pointing up: medium skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{261d}\u{1f3fe}", name: "index pointing up: medium-dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{261d}\u{1f3ff}", name: "index pointing up: dark skin tone", group: "People & Body", subgroup: "hand-single-finger", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44d}", name: "thumbs up", group: "People & Body", subgroup: "hand-fingers-closed", version: 0.6, shortcodes: &["+1", "thumbsup"] },
+    EmojiInfo { emoji: "\u{1f44d}\u{1f3fb}", name: "thumbs up: light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44d}\u{1f3fc}", name: "thumbs up: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44d}\u{1f3fd}", name: "thumbs up: medium skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44d}\u{1f3fe}", name: "thumbs up: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44d}\u{1f3ff}", name: "thumbs up: dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44e}", name: "thumbs down", group: "People & Body", subgroup: "hand-fingers-closed", version: 0.6, shortcodes: &["-1", "thumbsdown"] },
+    EmojiInfo { emoji: "\u{1f44e}\u{1f3fb}", name: "thumbs down: light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44e}\u{1f3fc}", name: "thumbs down: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44e}\u{1f3fd}", name: "thumbs down: medium skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44e}\u{1f3fe}", name: "thumbs down: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44e}\u{1f3ff}", name: "thumbs down: dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270a}", name: "raised fist", group: "People & Body", subgroup: "hand-fingers-closed", version: 0.6, shortcodes: &["fist_raised", "fist"] },
+    EmojiInfo { emoji: "\u{270a}\u{1f3fb}", name: "raised fist: light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270a}\u{1f3fc}", name: "raised fist: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270a}\u{1f3fd}", name: "raised fist: medium skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270a}\u{1f3fe}", name: "raised fist: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270a}\u{1f3ff}", name: "raised fist: dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44a}", name: "oncoming fist", group: "People & Body", subgroup: "hand-fingers-closed", version: 0.6, shortcodes: &["fist_oncoming", "facepunch", "punch"] },
+    EmojiInfo { emoji: "\u{1f44a}\u{1f3fb}", name: "oncoming fist: light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44a}\u{1f3fc}", name: "oncoming fist: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44a}\u{1f3fd}", name: "oncoming fist: medium skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44a}\u{1f3fe}", name: "oncoming fist: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44a}\u{1f3ff}", name: "oncoming fist: dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91b}", name: "left-facing fist", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &["fist_left"] },
+    EmojiInfo { emoji: "\u{1f91b}\u{1f3fb}", name: "left-facing fist: light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91b}\u{1f3fc}", name: "left-facing fist: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91b}\u{1f3fd}", name: "left-facing fist: medium skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91b}\u{1f3fe}", name: "left-facing fist: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91b}\u{1f3ff}", name: "left-facing fist: dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91c}", name: "right-facing fist", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &["fist_right"] },
+    EmojiInfo { emoji: "\u{1f91c}\u{1f3fb}", name: "right-facing fist: light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91c}\u{1f3fc}", name: "right-facing fist: medium-light skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91c}\u{1f3fd}", name: "right-facing fist: medium skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91c}\u{1f3fe}", name: "right-facing fist: medium-dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91c}\u{1f3ff}", name: "right-facing fist: dark skin tone", group: "People & Body", subgroup: "hand-fingers-closed", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44f}", name: "clapping hands", group: "People & Body", subgroup: "hands", version: 0.6, shortcodes: &["clap"] },
+    EmojiInfo { emoji: "\u{1f44f}\u{1f3fb}", name: "clapping hands: light skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44f}\u{1f3fc}", name: "clapping hands: medium-light skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44f}\u{1f3fd}", name: "clapping hands: medium skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44f}\u{1f3fe}", name: "clapping hands: medium-dark skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f44f}\u{1f3ff}", name: "clapping hands: dark skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64c}", name: "raising hands", group: "People & Body", subgroup: "hands", version: 0.6, shortcodes: &["raised_hands"] },
+    EmojiInfo { emoji: "\u{1f64c}\u{1f3fb}", name: "raising hands: light skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64c}\u{1f3fc}", name: "raising hands: medium-light skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64c}\u{1f3fd}", name: "raising hands: medium skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64c}\u{1f3fe}", name: "raising hands: medium-dark skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64c}\u{1f3ff}", name: "raising hands: dark skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f450}", name: "open hands", group: "People & Body", subgroup: "hands", version: 0.6, shortcodes: &["open_hands"] },
+    EmojiInfo { emoji: "\u{1f450}\u{1f3fb}", name: "open hands: light skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f450}\u{1f3fc}", name: "open hands: medium-light skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f450}\u{1f3fd}", name: "open hands: medium skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f450}\u{1f3fe}", name: "open hands: medium-dark skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f450}\u{1f3ff}", name: "open hands: dark skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f932}", name: "palms up together", group: "People & Body", subgroup: "hands", version: 5.0, shortcodes: &["palms_up_together"] },
+    EmojiInfo { emoji: "\u{1f932}\u{1f3fb}", name: "palms up together: light skin tone", group: "People & Body", subgroup: "hands", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f932}\u{1f3fc}", name: "palms up together: medium-light skin tone", group: "People & Body", subgroup: "hands", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f932}\u{1f3fd}", name: "palms up together: medium skin tone", group: "People & Body", subgroup: "hands", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f932}\u{1f3fe}", name: "palms up together: medium-dark skin tone", group: "People & Body", subgroup: "hands", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f932}\u{1f3ff}", name: "palms up together: dark skin tone", group: "People & Body", subgroup: "hands", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f91d}", name: "handshake", group: "People & Body", subgroup: "hands", version: 3.0, shortcodes: &["handshake"] },
+    EmojiInfo { emoji: "\u{1f64f}", name: "folded hands", group: "People & Body", subgroup: "hands", version: 0.6, shortcodes: &["pray"] },
+    EmojiInfo { emoji: "\u{1f64f}\u{1f3fb}", name: "folded hands: light skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64f}\u{1f3fc}", name: "folded hands: medium-light skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64f}\u{1f3fd}", name: "folded hands: medium skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64f}\u{1f3fe}", name: "folded hands: medium-dark skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64f}\u{1f3ff}", name: "folded hands: dark skin tone", group: "People & Body", subgroup: "hands", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270d}\u{fe0f}", name: "writing hand", group: "People & Body", subgroup: "hand-prop", version: 0.7, shortcodes: &["writing_hand"] },
+    EmojiInfo { emoji: "\u{270d}\u{1f3fb}", name: "writing hand: light skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270d}\u{1f3fc}", name: "writing hand: medium-light skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270d}\u{1f3fd}", name: "writing hand: medium skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270d}\u{1f3fe}", name: "writing hand: medium-dark skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{270d}\u{1f3ff}", name: "writing hand: dark skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f485}", name: "nail polish", group: "People & Body", subgroup: "hand-prop", version: 0.6, shortcodes: &["nail_care"] },
+    EmojiInfo { emoji: "\u{1f485}\u{1f3fb}", name: "nail polish: light skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f485}\u{1f3fc}", name: "nail polish: medium-light skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f485}\u{1f3fd}", name: "nail polish: medium skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f485}\u{1f3fe}", name: "nail polish: medium-dark skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f485}\u{1f3ff}", name: "nail polish: dark skin tone", group: "People & Body", subgroup: "hand-prop", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f933}", name: "selfie", group: "People & Body", subgroup: "hand-prop", version: 3.0, shortcodes: &["selfie"] },
+    EmojiInfo { emoji: "\u{1f933}\u{1f3fb}", name: "selfie: light skin tone", group: "People & Body", subgroup: "hand-prop", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f933}\u{1f3fc}", name: "selfie: medium-light skin tone", group: "People & Body", subgroup: "hand-prop", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f933}\u{1f3fd}", name: "selfie: medium skin tone", group: "People & Body", subgroup: "hand-prop", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f933}\u{1f3fe}", name: "selfie: medium-dark skin tone", group: "People & Body", subgroup: "hand-prop", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f933}\u{1f3ff}", name: "selfie: dark skin tone", group: "People & Body", subgroup: "hand-prop", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f4aa}", name: "flexed biceps", group: "People & Body", subgroup: "body-parts", version: 0.6, shortcodes: &["muscle"] },
+    EmojiInfo { emoji: "\u{1f4aa}\u{1f3fb}", name: "flexed biceps: light skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f4aa}\u{1f3fc}", name: "flexed biceps: medium-light skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f4aa}\u{1f3fd}", name: "flexed biceps: medium skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f4aa}\u{1f3fe}", name: "flexed biceps: medium-dark skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f4aa}\u{1f3ff}", name: "flexed biceps: dark skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9be}", name: "mechanical arm", group: "People & Body", subgroup: "body-parts", version: 12.0, shortcodes: &["mechanical_arm"] },
+    EmojiInfo { emoji: "\u{1f9bf}", name: "mechanical leg", group: "People & Body", subgroup: "body-parts", version: 12.0, shortcodes: &["mechanical_leg"] },
+    EmojiInfo { emoji: "\u{1f9b5}", name: "leg", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &["leg"] },
+    EmojiInfo { emoji: "\u{1f9b5}\u{1f3fb}", name: "leg: light skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b5}\u{1f3fc}", name: "leg: medium-light skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b5}\u{1f3fd}", name: "leg: medium skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b5}\u{1f3fe}", name: "leg: medium-dark skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b5}\u{1f3ff}", name: "leg: dark skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b6}", name: "foot", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &["foot"] },
+    EmojiInfo { emoji: "\u{1f9b6}\u{1f3fb}", name: "foot: light skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b6}\u{1f3fc}", name: "foot: medium-light skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b6}\u{1f3fd}", name: "foot: medium skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b6}\u{1f3fe}", name: "foot: medium-dark skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b6}\u{1f3ff}", name: "foot: dark skin tone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f442}", name: "ear", group: "People & Body", subgroup: "body-parts", version: 0.6, shortcodes: &["ear"] },
+    EmojiInfo { emoji: "\u{1f442}\u{1f3fb}", name: "ear: light skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f442}\u{1f3fc}", name: "ear: medium-light skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f442}\u{1f3fd}", name: "ear: medium skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f442}\u{1f3fe}", name: "ear: medium-dark skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f442}\u{1f3ff}", name: "ear: dark skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9bb}", name: "ear with hearing aid", group: "People & Body", subgroup: "body-parts", version: 12.0, shortcodes: &["ear_with_hearing_aid"] },
+    EmojiInfo { emoji: "\u{1f9bb}\u{1f3fb}", name: "ear with hearing aid: light skin tone", group: "People & Body", subgroup: "body-parts", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9bb}\u{1f3fc}", name: "ear with hearing aid: medium-light skin tone", group: "People & Body", subgroup: "body-parts", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9bb}\u{1f3fd}", name: "ear with hearing aid: medium skin tone", group: "People & Body", subgroup: "body-parts", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9bb}\u{1f3fe}", name: "ear with hearing aid: medium-dark skin tone", group: "People & Body", subgroup: "body-parts", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9bb}\u{1f3ff}", name: "ear with hearing aid: dark skin tone", group: "People & Body", subgroup: "body-parts", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f443}", name: "nose", group: "People & Body", subgroup: "body-parts", version: 0.6, shortcodes: &["nose"] },
+    EmojiInfo { emoji: "\u{1f443}\u{1f3fb}", name: "nose: light skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f443}\u{1f3fc}", name: "nose: medium-light skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f443}\u{1f3fd}", name: "nose: medium skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f443}\u{1f3fe}", name: "nose: medium-dark skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f443}\u{1f3ff}", name: "nose: dark skin tone", group: "People & Body", subgroup: "body-parts", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9e0}", name: "brain", group: "People & Body", subgroup: "body-parts", version: 5.0, shortcodes: &["brain"] },
+    EmojiInfo { emoji: "\u{1fac0}", name: "anatomical heart", group: "People & Body", subgroup: "body-parts", version: 13.0, shortcodes: &["anatomical_heart"] },
+    EmojiInfo { emoji: "\u{1fac1}", name: "lungs", group: "People & Body", subgroup: "body-parts", version: 13.0, shortcodes: &["lungs"] },
+    EmojiInfo { emoji: "\u{1f9b7}", name: "tooth", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &["tooth"] },
+    EmojiInfo { emoji: "\u{1f9b4}", name: "bone", group: "People & Body", subgroup: "body-parts", version: 11.0, shortcodes: &["bone"] },
+    EmojiInfo { emoji: "\u{1f440}", name: "eyes", group: "People & Body", subgroup: "body-parts", version: 0.6, shortcodes: &["eyes"] },
+    EmojiInfo { emoji: "\u{1f441}\u{fe0f}", name: "eye", group: "People & Body", subgroup: "body-parts", version: 0.7, shortcodes: &["eye"] },
+    EmojiInfo { emoji: "\u{1f445}", name: "tongue", group: "People & Body", subgroup: "body-parts", version: 0.6, shortcodes: &["tongue"] },
+    EmojiInfo { emoji: "\u{1f444}", name: "mouth", group: "People & Body", subgroup: "body-parts", version: 0.6, shortcodes: &["lips"] },
+    EmojiInfo { emoji: "\u{1f476}", name: "baby", group: "People & Body", subgroup: "person", version: 0.6, shortcodes: &["baby"] },
+    EmojiInfo { emoji: "\u{1f476}\u{1f3fb}", name: "baby: light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f476}\u{1f3fc}", name: "baby: medium-light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f476}\u{1f3fd}", name: "baby: medium skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f476}\u{1f3fe}", name: "baby: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f476}\u{1f3ff}", name: "baby: dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d2}", name: "child", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &["child"] },
+    EmojiInfo { emoji: "\u{1f9d2}\u{1f3fb}", name: "child: light skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d2}\u{1f3fc}", name: "child: medium-light skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d2}\u{1f3fd}", name: "child: medium skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d2}\u{1f3fe}", name: "child: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d2}\u{1f3ff}", name: "child: dark skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f466}", name: "boy", group: "People & Body", subgroup: "person", version: 0.6, shortcodes: &["boy"] },
+    EmojiInfo { emoji: "\u{1f466}\u{1f3fb}", name: "boy: light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f466}\u{1f3fc}", name: "boy: medium-light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f466}\u{1f3fd}", name: "boy: medium skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f466}\u{1f3fe}", name: "boy: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f466}\u{1f3ff}", name: "boy: dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f467}", name: "girl", group: "People & Body", subgroup: "person", version: 0.6, shortcodes: &["girl"] },
+    EmojiInfo { emoji: "\u{1f467}\u{1f3fb}", name: "girl: light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f467}\u{1f3fc}", name: "girl: medium-light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f467}\u{1f3fd}", name: "girl: medium skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f467}\u{1f3fe}", name: "girl: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f467}\u{1f3ff}", name: "girl: dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}", name: "person", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &["adult"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}", name: "person: light skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}", name: "person: medium-light skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}", name: "person: medium skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}", name: "person: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}", name: "person: dark skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}", name: "person: blond hair", group: "People & Body", subgroup: "person", version: 0.6, shortcodes: &["blond_haired_person"] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fb}", name: "person: light skin tone, blond hair", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fc}", name: "person: medium-light skin tone, blond hair", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fd}", name: "person: medium skin tone, blond hair", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fe}", name: "person: medium-dark skin tone, blond hair", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3ff}", name: "person: dark skin tone, blond hair", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}", name: "man", group: "People & Body", subgroup: "person", version: 0.6, shortcodes: &["man"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}", name: "man: light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}", name: "man: medium-light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}", name: "man: medium skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}", name: "man: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}", name: "man: dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}", name: "person: beard", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &["bearded_person"] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fb}", name: "person: light skin tone, beard", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fc}", name: "person: medium-light skin tone, beard", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fd}", name: "person: medium skin tone, beard", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fe}", name: "person: medium-dark skin tone, beard", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3ff}", name: "person: dark skin tone, beard", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{200d}\u{2642}\u{fe0f}", name: "man: beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &["man_beard"] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man: light skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man: medium-light skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man: medium skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man: medium-dark skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man: dark skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{200d}\u{2640}\u{fe0f}", name: "woman: beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &["woman_beard"] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman: light skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman: medium-light skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman: medium skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman: medium-dark skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman: dark skin tone, beard", group: "People & Body", subgroup: "person", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f9b0}", name: "man: red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &["red_haired_man"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b0}", name: "man: light skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b0}", name: "man: medium-light skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b0}", name: "man: medium skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b0}", name: "man: medium-dark skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b0}", name: "man: dark skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f9b1}", name: "man: curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &["curly_haired_man"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b1}", name: "man: light skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b1}", name: "man: medium-light skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b1}", name: "man: medium skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b1}", name: "man: medium-dark skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b1}", name: "man: dark skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f9b3}", name: "man: white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &["white_haired_man"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b3}", name: "man: light skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b3}", name: "man: medium-light skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b3}", name: "man: medium skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b3}", name: "man: medium-dark skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b3}", name: "man: dark skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f9b2}", name: "man: bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &["bald_man"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b2}", name: "man: light skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b2}", name: "man: medium-light skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b2}", name: "man: medium skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b2}", name: "man: medium-dark skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b2}", name: "man: dark skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}", name: "woman", group: "People & Body", subgroup: "person", version: 0.6, shortcodes: &["woman"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}", name: "woman: light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}", name: "woman: medium-light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}", name: "woman: medium skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}", name: "woman: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}", name: "woman: dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f9b0}", name: "woman: red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &["red_haired_woman"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b0}", name: "woman: light skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b0}", name: "woman: medium-light skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b0}", name: "woman: medium skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b0}", name: "woman: medium-dark skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b0}", name: "woman: dark skin tone, red hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f9b0}", name: "person: red hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &["person_red_hair"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b0}", name: "person: light skin tone, red hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b0}", name: "person: medium-light skin tone, red hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b0}", name: "person: medium skin tone, red hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b0}", name: "person: medium-dark skin tone, red hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b0}", name: "person: dark skin tone, red hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f9b1}", name: "woman: curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &["curly_haired_woman"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b1}", name: "woman: light skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b1}", name: "woman: medium-light skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b1}", name: "woman: medium skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b1}", name: "woman: medium-dark skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b1}", name: "woman: dark skin tone, curly hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f9b1}", name: "person: curly hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &["person_curly_hair"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b1}", name: "person: light skin tone, curly hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b1}", name: "person: medium-light skin tone, curly hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b1}", name: "person: medium skin tone, curly hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b1}", name: "person: medium-dark skin tone, curly hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b1}", name: "person: dark skin tone, curly hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f9b3}", name: "woman: white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &["white_haired_woman"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b3}", name: "woman: light skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b3}", name: "woman: medium-light skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b3}", name: "woman: medium skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b3}", name: "woman: medium-dark skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b3}", name: "woman: dark skin tone, white hair", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f9b3}", name: "person: white hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &["person_white_hair"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b3}", name: "person: light skin tone, white hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b3}", name: "person: medium-light skin tone, white hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b3}", name: "person: medium skin tone, white hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b3}", name: "person: medium-dark skin tone, white hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b3}", name: "person: dark skin tone, white hair", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f9b2}", name: "woman: bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &["bald_woman"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b2}", name: "woman: light skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b2}", name: "woman: medium-light skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b2}", name: "woman: medium skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b2}", name: "woman: medium-dark skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b2}", name: "woman: dark skin tone, bald", group: "People & Body", subgroup: "person", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f9b2}", name: "person: bald", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &["person_bald"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b2}", name: "person: light skin tone, bald", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b2}", name: "person: medium-light skin tone, bald", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b2}", name: "person: medium skin tone, bald", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b2}", name: "person: medium-dark skin tone, bald", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b2}", name: "person: dark skin tone, bald", group: "People & Body", subgroup: "person", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{200d}\u{2640}\u{fe0f}", name: "woman: blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &["blond_haired_woman", "blonde_woman"] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman: light skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman: medium-light skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman: medium skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman: medium-dark skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman: dark skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{200d}\u{2642}\u{fe0f}", name: "man: blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &["blond_haired_man"] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man: light skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man: medium-light skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man: medium skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man: medium-dark skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f471}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man: dark skin tone, blond hair", group: "People & Body", subgroup: "person", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d3}", name: "older person", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &["older_adult"] },
+    EmojiInfo { emoji: "\u{1f9d3}\u{1f3fb}", name: "older person: light skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d3}\u{1f3fc}", name: "older person: medium-light skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d3}\u{1f3fd}", name: "older person: medium skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d3}\u{1f3fe}", name: "older person: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d3}\u{1f3ff}", name: "older person: dark skin tone", group: "People & Body", subgroup: "person", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f474}", name: "old man", group: "People & Body", subgroup: "person", version: 0.6, shortcodes: &["older_man"] },
+    EmojiInfo { emoji: "\u{1f474}\u{1f3fb}", name: "old man: light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f474}\u{1f3fc}", name: "old man: medium-light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f474}\u{1f3fd}", name: "old man: medium skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f474}\u{1f3fe}", name: "old man: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f474}\u{1f3ff}", name: "old man: dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f475}", name: "old woman", group: "People & Body", subgroup: "person", version: 0.6, shortcodes: &["older_woman"] },
+    EmojiInfo { emoji: "\u{1f475}\u{1f3fb}", name: "old woman: light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f475}\u{1f3fc}", name: "old woman: medium-light skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f475}\u{1f3fd}", name: "old woman: medium skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f475}\u{1f3fe}", name: "old woman: medium-dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f475}\u{1f3ff}", name: "old woman: dark skin tone", group: "People & Body", subgroup: "person", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}", name: "person frowning", group: "People & Body", subgroup: "person-gesture", version: 0.6, shortcodes: &["frowning_person"] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fb}", name: "person frowning: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fc}", name: "person frowning: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fd}", name: "person frowning: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fe}", name: "person frowning: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3ff}", name: "person frowning: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{200d}\u{2642}\u{fe0f}", name: "man frowning", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["frowning_man"] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man frowning: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man frowning: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man frowning: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man frowning: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man frowning: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{200d}\u{2640}\u{fe0f}", name: "woman frowning", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["frowning_woman"] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman frowning: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman frowning: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman frowning: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman frowning: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman frowning: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}", name: "person pouting", group: "People & Body", subgroup: "person-gesture", version: 0.6, shortcodes: &["pouting_face"] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fb}", name: "person pouting: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fc}", name: "person pouting: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fd}", name: "person pouting: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fe}", name: "person pouting: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3ff}", name: "person pouting: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{200d}\u{2642}\u{fe0f}", name: "man pouting", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["pouting_man"] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man pouting: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man pouting: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man pouting: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man pouting: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man pouting: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{200d}\u{2640}\u{fe0f}", name: "woman pouting", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["pouting_woman"] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman pouting: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman pouting: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman pouting: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman pouting: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman pouting: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}", name: "person gesturing NO", group: "People & Body", subgroup: "person-gesture", version: 0.6, shortcodes: &["no_good"] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fb}", name: "person gesturing NO: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fc}", name: "person gesturing NO: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fd}", name: "person gesturing NO: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fe}", name: "person gesturing NO: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3ff}", name: "person gesturing NO: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing NO", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["no_good_man", "ng_man"] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing NO: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing NO: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing NO: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing NO: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing NO: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing NO", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["no_good_woman", "ng_woman"] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing NO: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing NO: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing NO: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing NO: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f645}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing NO: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}", name: "person gesturing OK", group: "People & Body", subgroup: "person-gesture", version: 0.6, shortcodes: &["ok_person"] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fb}", name: "person gesturing OK: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fc}", name: "person gesturing OK: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fd}", name: "person gesturing OK: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fe}", name: "person gesturing OK: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3ff}", name: "person gesturing OK: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing OK", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["ok_man"] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing OK: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing OK: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing OK: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing OK: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man gesturing OK: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing OK", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["ok_woman"] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing OK: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing OK: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing OK: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing OK: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f646}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman gesturing OK: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}", name: "person tipping hand", group: "People & Body", subgroup: "person-gesture", version: 0.6, shortcodes: &["tipping_hand_person", "information_desk_person"] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fb}", name: "person tipping hand: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fc}", name: "person tipping hand: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fd}", name: "person tipping hand: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fe}", name: "person tipping hand: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3ff}", name: "person tipping hand: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{200d}\u{2642}\u{fe0f}", name: "man tipping hand", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["tipping_hand_man", "sassy_man"] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man tipping hand: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man tipping hand: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man tipping hand: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man tipping hand: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man tipping hand: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{200d}\u{2640}\u{fe0f}", name: "woman tipping hand", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["tipping_hand_woman", "sassy_woman"] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman tipping hand: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman tipping hand: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman tipping hand: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman tipping hand: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f481}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman tipping hand: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}", name: "person raising hand", group: "People & Body", subgroup: "person-gesture", version: 0.6, shortcodes: &["raising_hand"] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fb}", name: "person raising hand: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fc}", name: "person raising hand: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fd}", name: "person raising hand: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fe}", name: "person raising hand: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3ff}", name: "person raising hand: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{200d}\u{2642}\u{fe0f}", name: "man raising hand", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["raising_hand_man"] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man raising hand: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man raising hand: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man raising hand: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man raising hand: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man raising hand: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{200d}\u{2640}\u{fe0f}", name: "woman raising hand", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["raising_hand_woman"] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman raising hand: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman raising hand: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman raising hand: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman raising hand: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f64b}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman raising hand: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}", name: "deaf person", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &["deaf_person"] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fb}", name: "deaf person: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fc}", name: "deaf person: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fd}", name: "deaf person: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fe}", name: "deaf person: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3ff}", name: "deaf person: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{200d}\u{2642}\u{fe0f}", name: "deaf man", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &["deaf_man"] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "deaf man: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "deaf man: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "deaf man: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "deaf man: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "deaf man: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{200d}\u{2640}\u{fe0f}", name: "deaf woman", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &["deaf_woman"] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "deaf woman: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "deaf woman: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "deaf woman: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "deaf woman: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "deaf woman: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}", name: "person bowing", group: "People & Body", subgroup: "person-gesture", version: 0.6, shortcodes: &["bow"] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fb}", name: "person bowing: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fc}", name: "person bowing: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fd}", name: "person bowing: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fe}", name: "person bowing: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3ff}", name: "person bowing: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{200d}\u{2642}\u{fe0f}", name: "man bowing", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["bowing_man"] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man bowing: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man bowing: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man bowing: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man bowing: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man bowing: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{200d}\u{2640}\u{fe0f}", name: "woman bowing", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["bowing_woman"] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman bowing: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman bowing: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman bowing: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman bowing: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f647}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman bowing: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}", name: "person facepalming", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &["facepalm"] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fb}", name: "person facepalming: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fc}", name: "person facepalming: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fd}", name: "person facepalming: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fe}", name: "person facepalming: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3ff}", name: "person facepalming: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{200d}\u{2642}\u{fe0f}", name: "man facepalming", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["man_facepalming"] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man facepalming: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man facepalming: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man facepalming: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man facepalming: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man facepalming: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{200d}\u{2640}\u{fe0f}", name: "woman facepalming", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["woman_facepalming"] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman facepalming: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman facepalming: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman facepalming: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman facepalming: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f926}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman facepalming: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}", name: "person shrugging", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &["shrug"] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fb}", name: "person shrugging: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fc}", name: "person shrugging: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fd}", name: "person shrugging: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fe}", name: "person shrugging: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3ff}", name: "person shrugging: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{200d}\u{2642}\u{fe0f}", name: "man shrugging", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["man_shrugging"] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man shrugging: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man shrugging: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man shrugging: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man shrugging: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man shrugging: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{200d}\u{2640}\u{fe0f}", name: "woman shrugging", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &["woman_shrugging"] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman shrugging: light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman shrugging: medium-light skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman shrugging: medium skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman shrugging: medium-dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f937}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman shrugging: dark skin tone", group: "People & Body", subgroup: "person-gesture", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{2695}\u{fe0f}", name: "health worker", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["health_worker"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", name: "health worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", name: "health worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", name: "health worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", name: "health worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", name: "health worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{2695}\u{fe0f}", name: "man health worker", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_health_worker"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", name: "man health worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", name: "man health worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", name: "man health worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", name: "man health worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", name: "man health worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{2695}\u{fe0f}", name: "woman health worker", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_health_worker"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", name: "woman health worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", name: "woman health worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", name: "woman health worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", name: "woman health worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", name: "woman health worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f393}", name: "student", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["student"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f393}", name: "student: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f393}", name: "student: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f393}", name: "student: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f393}", name: "student: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f393}", name: "student: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f393}", name: "man student", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_student"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f393}", name: "man student: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f393}", name: "man student: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f393}", name: "man student: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f393}", name: "man student: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f393}", name: "man student: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f393}", name: "woman student", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_student"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f393}", name: "woman student: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f393}", name: "woman student: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f393}", name: "woman student: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f393}", name: "woman student: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f393}", name: "woman student: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f3eb}", name: "teacher", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["teacher"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3eb}", name: "teacher: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3eb}", name: "teacher: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3eb}", name: "teacher: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3eb}", name: "teacher: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3eb}", name: "teacher: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f3eb}", name: "man teacher", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_teacher"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f3eb}", name: "man teacher: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f3eb}", name: "man teacher: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f3eb}", name: "man teacher: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f3eb}", name: "man teacher: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f3eb}", name: "man teacher: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f3eb}", name: "woman teacher", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_teacher"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f3eb}", name: "woman teacher: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f3eb}", name: "woman teacher: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f3eb}", name: "woman teacher: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f3eb}", name: "woman teacher: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f3eb}", name: "woman teacher: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{2696}\u{fe0f}", name: "judge", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["judge"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", name: "judge: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", name: "judge: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", name: "judge: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", name: "judge: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", name: "judge: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{2696}\u{fe0f}", name: "man judge", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_judge"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", name: "man judge: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", name: "man judge: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", name: "man judge: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", name: "man judge: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", name: "man judge: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{2696}\u{fe0f}", name: "woman judge", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_judge"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", name: "woman judge: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", name: "woman judge: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", name: "woman judge: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", name: "woman judge: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", name: "woman judge: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f33e}", name: "farmer", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["farmer"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f33e}", name: "farmer: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f33e}", name: "farmer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f33e}", name: "farmer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f33e}", name: "farmer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f33e}", name: "farmer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f33e}", name: "man farmer", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_farmer"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f33e}", name: "man farmer: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f33e}", name: "man farmer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f33e}", name: "man farmer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f33e}", name: "man farmer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f33e}", name: "man farmer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f33e}", name: "woman farmer", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_farmer"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f33e}", name: "woman farmer: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f33e}", name: "woman farmer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f33e}", name: "woman farmer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f33e}", name: "woman farmer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f33e}", name: "woman farmer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f373}", name: "cook", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["cook"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f373}", name: "cook: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f373}", name: "cook: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f373}", name: "cook: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f373}", name: "cook: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f373}", name: "cook: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f373}", name: "man cook", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_cook"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f373}", name: "man cook: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f373}", name: "man cook: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f373}", name: "man cook: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f373}", name: "man cook: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f373}", name: "man cook: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f373}", name: "woman cook", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_cook"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f373}", name: "woman cook: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f373}", name: "woman cook: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f373}", name: "woman cook: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f373}", name: "woman cook: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f373}", name: "woman cook: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f527}", name: "mechanic", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["mechanic"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f527}", name: "mechanic: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f527}", name: "mechanic: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f527}", name: "mechanic: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f527}", name: "mechanic: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f527}", name: "mechanic: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f527}", name: "man mechanic", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_mechanic"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f527}", name: "man mechanic: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f527}", name: "man mechanic: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f527}", name: "man mechanic: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f527}", name: "man mechanic: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f527}", name: "man mechanic: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f527}", name: "woman mechanic", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_mechanic"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f527}", name: "woman mechanic: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f527}", name: "woman mechanic: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f527}", name: "woman mechanic: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f527}", name: "woman mechanic: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f527}", name: "woman mechanic: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f3ed}", name: "factory worker", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["factory_worker"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3ed}", name: "factory worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3ed}", name: "factory worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3ed}", name: "factory worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3ed}", name: "factory worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3ed}", name: "factory worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f3ed}", name: "man factory worker", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_factory_worker"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f3ed}", name: "man factory worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f3ed}", name: "man factory worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f3ed}", name: "man factory worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f3ed}", name: "man factory worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f3ed}", name: "man factory worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f3ed}", name: "woman factory worker", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_factory_worker"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f3ed}", name: "woman factory worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f3ed}", name: "woman factory worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f3ed}", name: "woman factory worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f3ed}", name: "woman factory worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f3ed}", name: "woman factory worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f4bc}", name: "office worker", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["office_worker"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bc}", name: "office worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bc}", name: "office worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bc}", name: "office worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bc}", name: "office worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bc}", name: "office worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f4bc}", name: "man office worker", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_office_worker"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f4bc}", name: "man office worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f4bc}", name: "man office worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f4bc}", name: "man office worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f4bc}", name: "man office worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f4bc}", name: "man office worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f4bc}", name: "woman office worker", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_office_worker"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f4bc}", name: "woman office worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f4bc}", name: "woman office worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bc}", name: "woman office worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f4bc}", name: "woman office worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f4bc}", name: "woman office worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f52c}", name: "scientist", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["scientist"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f52c}", name: "scientist: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f52c}", name: "scientist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f52c}", name: "scientist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f52c}", name: "scientist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f52c}", name: "scientist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f52c}", name: "man scientist", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_scientist"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f52c}", name: "man scientist: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f52c}", name: "man scientist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f52c}", name: "man scientist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f52c}", name: "man scientist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f52c}", name: "man scientist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f52c}", name: "woman scientist", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_scientist"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f52c}", name: "woman scientist: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f52c}", name: "woman scientist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}", name: "woman scientist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f52c}", name: "woman scientist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f52c}", name: "woman scientist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f4bb}", name: "technologist", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["technologist"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bb}", name: "technologist: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bb}", name: "technologist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bb}", name: "technologist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bb}", name: "technologist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bb}", name: "technologist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f4bb}", name: "man technologist", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_technologist"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f4bb}", name: "man technologist: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f4bb}", name: "man technologist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f4bb}", name: "man technologist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f4bb}", name: "man technologist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f4bb}", name: "man technologist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f4bb}", name: "woman technologist", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_technologist"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f4bb}", name: "woman technologist: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f4bb}", name: "woman technologist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}", name: "woman technologist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f4bb}", name: "woman technologist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f4bb}", name: "woman technologist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f3a4}", name: "singer", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["singer"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a4}", name: "singer: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a4}", name: "singer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a4}", name: "singer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a4}", name: "singer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a4}", name: "singer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f3a4}", name: "man singer", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_singer"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f3a4}", name: "man singer: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f3a4}", name: "man singer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f3a4}", name: "man singer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f3a4}", name: "man singer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f3a4}", name: "man singer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f3a4}", name: "woman singer", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_singer"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f3a4}", name: "woman singer: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f3a4}", name: "woman singer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f3a4}", name: "woman singer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f3a4}", name: "woman singer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f3a4}", name: "woman singer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f3a8}", name: "artist", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["artist"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a8}", name: "artist: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a8}", name: "artist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a8}", name: "artist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a8}", name: "artist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a8}", name: "artist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f3a8}", name: "man artist", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_artist"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f3a8}", name: "man artist: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f3a8}", name: "man artist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f3a8}", name: "man artist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f3a8}", name: "man artist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f3a8}", name: "man artist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f3a8}", name: "woman artist", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_artist"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f3a8}", name: "woman artist: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f3a8}", name: "woman artist: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f3a8}", name: "woman artist: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f3a8}", name: "woman artist: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f3a8}", name: "woman artist: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{2708}\u{fe0f}", name: "pilot", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["pilot"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", name: "pilot: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", name: "pilot: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", name: "pilot: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", name: "pilot: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", name: "pilot: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{2708}\u{fe0f}", name: "man pilot", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_pilot"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", name: "man pilot: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", name: "man pilot: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", name: "man pilot: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", name: "man pilot: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", name: "man pilot: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{2708}\u{fe0f}", name: "woman pilot", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_pilot"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", name: "woman pilot: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", name: "woman pilot: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", name: "woman pilot: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", name: "woman pilot: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", name: "woman pilot: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f680}", name: "astronaut", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["astronaut"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f680}", name: "astronaut: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f680}", name: "astronaut: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f680}", name: "astronaut: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f680}", name: "astronaut: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f680}", name: "astronaut: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f680}", name: "man astronaut", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_astronaut"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f680}", name: "man astronaut: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f680}", name: "man astronaut: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f680}", name: "man astronaut: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f680}", name: "man astronaut: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f680}", name: "man astronaut: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f680}", name: "woman astronaut", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_astronaut"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f680}", name: "woman astronaut: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f680}", name: "woman astronaut: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f680}", name: "woman astronaut: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f680}", name: "woman astronaut: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f680}", name: "woman astronaut: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f692}", name: "firefighter", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &["firefighter"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f692}", name: "firefighter: light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f692}", name: "firefighter: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f692}", name: "firefighter: medium skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f692}", name: "firefighter: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f692}", name: "firefighter: dark skin tone", group: "People & Body", subgroup: "person-role", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f692}", name: "man firefighter", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_firefighter"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f692}", name: "man firefighter: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f692}", name: "man firefighter: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f692}", name: "man firefighter: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f692}", name: "man firefighter: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f692}", name: "man firefighter: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f692}", name: "woman firefighter", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_firefighter"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f692}", name: "woman firefighter: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f692}", name: "woman firefighter: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f692}", name: "woman firefighter: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f692}", name: "woman firefighter: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f692}", name: "woman firefighter: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}", name: "police officer", group: "People & Body", subgroup: "person-role", version: 0.6, shortcodes: &["police_officer", "cop"] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fb}", name: "police officer: light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fc}", name: "police officer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fd}", name: "police officer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fe}", name: "police officer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3ff}", name: "police officer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{200d}\u{2642}\u{fe0f}", name: "man police officer", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["policeman"] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man police officer: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man police officer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man police officer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man police officer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man police officer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{200d}\u{2640}\u{fe0f}", name: "woman police officer", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["policewoman"] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman police officer: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman police officer: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman police officer: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman police officer: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman police officer: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{fe0f}", name: "detective", group: "People & Body", subgroup: "person-role", version: 0.7, shortcodes: &["detective"] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fb}", name: "detective: light skin tone", group: "People & Body", subgroup: "person-role", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fc}", name: "detective: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fd}", name: "detective: medium skin tone", group: "People & Body", subgroup: "person-role", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fe}", name: "detective: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3ff}", name: "detective: dark skin tone", group: "People & Body", subgroup: "person-role", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", name: "man detective", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["male_detective"] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man detective: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man detective: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man detective: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man detective: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man detective: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", name: "woman detective", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["female_detective"] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman detective: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman detective: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman detective: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman detective: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f575}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman detective: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}", name: "guard", group: "People & Body", subgroup: "person-role", version: 0.6, shortcodes: &["guard"] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fb}", name: "guard: light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fc}", name: "guard: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fd}", name: "guard: medium skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fe}", name: "guard: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3ff}", name: "guard: dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{200d}\u{2642}\u{fe0f}", name: "man guard", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["guardsman"] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man guard: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man guard: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man guard: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man guard: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man guard: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{200d}\u{2640}\u{fe0f}", name: "woman guard", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["guardswoman"] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman guard: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman guard: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman guard: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman guard: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f482}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman guard: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f977}", name: "ninja", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &["ninja"] },
+    EmojiInfo { emoji: "\u{1f977}\u{1f3fb}", name: "ninja: light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f977}\u{1f3fc}", name: "ninja: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f977}\u{1f3fd}", name: "ninja: medium skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f977}\u{1f3fe}", name: "ninja: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f977}\u{1f3ff}", name: "ninja: dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}", name: "construction worker", group: "People & Body", subgroup: "person-role", version: 0.6, shortcodes: &["construction_worker"] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fb}", name: "construction worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fc}", name: "construction worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fd}", name: "construction worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fe}", name: "construction worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3ff}", name: "construction worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{200d}\u{2642}\u{fe0f}", name: "man construction worker", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["construction_worker_man"] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man construction worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man construction worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man construction worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man construction worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man construction worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{200d}\u{2640}\u{fe0f}", name: "woman construction worker", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["construction_worker_woman"] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman construction worker: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman construction worker: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman construction worker: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman construction worker: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f477}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman construction worker: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f934}", name: "prince", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &["prince"] },
+    EmojiInfo { emoji: "\u{1f934}\u{1f3fb}", name: "prince: light skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f934}\u{1f3fc}", name: "prince: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f934}\u{1f3fd}", name: "prince: medium skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f934}\u{1f3fe}", name: "prince: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f934}\u{1f3ff}", name: "prince: dark skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f478}", name: "princess", group: "People & Body", subgroup: "person-role", version: 0.6, shortcodes: &["princess"] },
+    EmojiInfo { emoji: "\u{1f478}\u{1f3fb}", name: "princess: light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f478}\u{1f3fc}", name: "princess: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f478}\u{1f3fd}", name: "princess: medium skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f478}\u{1f3fe}", name: "princess: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f478}\u{1f3ff}", name: "princess: dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}", name: "person wearing turban", group: "People & Body", subgroup: "person-role", version: 0.6, shortcodes: &["person_with_turban"] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fb}", name: "person wearing turban: light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fc}", name: "person wearing turban: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fd}", name: "person wearing turban: medium skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fe}", name: "person wearing turban: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3ff}", name: "person wearing turban: dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{200d}\u{2642}\u{fe0f}", name: "man wearing turban", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["man_with_turban"] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man wearing turban: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man wearing turban: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man wearing turban: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man wearing turban: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man wearing turban: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{200d}\u{2640}\u{fe0f}", name: "woman wearing turban", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &["woman_with_turban"] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman wearing turban: light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman wearing turban: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman wearing turban: medium skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman wearing turban: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f473}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman wearing turban: dark skin tone", group: "People & Body", subgroup: "person-role", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f472}", name: "person with skullcap", group: "People & Body", subgroup: "person-role", version: 0.6, shortcodes: &["man_with_gua_pi_mao"] },
+    EmojiInfo { emoji: "\u{1f472}\u{1f3fb}", name: "person with skullcap: light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f472}\u{1f3fc}", name: "person with skullcap: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f472}\u{1f3fd}", name: "person with skullcap: medium skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f472}\u{1f3fe}", name: "person with skullcap: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f472}\u{1f3ff}", name: "person with skullcap: dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d5}", name: "woman with headscarf", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &["woman_with_headscarf"] },
+    EmojiInfo { emoji: "\u{1f9d5}\u{1f3fb}", name: "woman with headscarf: light skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d5}\u{1f3fc}", name: "woman with headscarf: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d5}\u{1f3fd}", name: "woman with headscarf: medium skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d5}\u{1f3fe}", name: "woman with headscarf: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d5}\u{1f3ff}", name: "woman with headscarf: dark skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}", name: "person in tuxedo", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &["person_in_tuxedo"] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fb}", name: "person in tuxedo: light skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fc}", name: "person in tuxedo: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fd}", name: "person in tuxedo: medium skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fe}", name: "person in tuxedo: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3ff}", name: "person in tuxedo: dark skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{200d}\u{2642}\u{fe0f}", name: "man in tuxedo", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &["man_in_tuxedo"] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man in tuxedo: light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man in tuxedo: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man in tuxedo: medium skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man in tuxedo: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man in tuxedo: dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{200d}\u{2640}\u{fe0f}", name: "woman in tuxedo", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &["woman_in_tuxedo"] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman in tuxedo: light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman in tuxedo: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman in tuxedo: medium skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman in tuxedo: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f935}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman in tuxedo: dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}", name: "person with veil", group: "People & Body", subgroup: "person-role", version: 0.6, shortcodes: &["person_with_veil"] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fb}", name: "person with veil: light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fc}", name: "person with veil: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fd}", name: "person with veil: medium skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fe}", name: "person with veil: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3ff}", name: "person with veil: dark skin tone", group: "People & Body", subgroup: "person-role", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{200d}\u{2642}\u{fe0f}", name: "man with veil", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &["man_with_veil"] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man with veil: light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man with veil: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man with veil: medium skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man with veil: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man with veil: dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{200d}\u{2640}\u{fe0f}", name: "woman with veil", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &["woman_with_veil", "bride_with_veil"] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman with veil: light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman with veil: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman with veil: medium skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman with veil: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f470}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman with veil: dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f930}", name: "pregnant woman", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &["pregnant_woman"] },
+    EmojiInfo { emoji: "\u{1f930}\u{1f3fb}", name: "pregnant woman: light skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f930}\u{1f3fc}", name: "pregnant woman: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f930}\u{1f3fd}", name: "pregnant woman: medium skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f930}\u{1f3fe}", name: "pregnant woman: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f930}\u{1f3ff}", name: "pregnant woman: dark skin tone", group: "People & Body", subgroup: "person-role", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f931}", name: "breast-feeding", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &["breast_feeding"] },
+    EmojiInfo { emoji: "\u{1f931}\u{1f3fb}", name: "breast-feeding: light skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f931}\u{1f3fc}", name: "breast-feeding: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f931}\u{1f3fd}", name: "breast-feeding: medium skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f931}\u{1f3fe}", name: "breast-feeding: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f931}\u{1f3ff}", name: "breast-feeding: dark skin tone", group: "People & Body", subgroup: "person-role", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f37c}", name: "woman feeding baby", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &["woman_feeding_baby"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f37c}", name: "woman feeding baby: light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f37c}", name: "woman feeding baby: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f37c}", name: "woman feeding baby: medium skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f37c}", name: "woman feeding baby: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f37c}", name: "woman feeding baby: dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f37c}", name: "man feeding baby", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &["man_feeding_baby"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f37c}", name: "man feeding baby: light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f37c}", name: "man feeding baby: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f37c}", name: "man feeding baby: medium skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f37c}", name: "man feeding baby: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f37c}", name: "man feeding baby: dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f37c}", name: "person feeding baby", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &["person_feeding_baby"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f37c}", name: "person feeding baby: light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f37c}", name: "person feeding baby: medium-light skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f37c}", name: "person feeding baby: medium skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f37c}", name: "person feeding baby: medium-dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f37c}", name: "person feeding baby: dark skin tone", group: "People & Body", subgroup: "person-role", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f47c}", name: "baby angel", group: "People & Body", subgroup: "person-fantasy", version: 0.6, shortcodes: &["angel"] },
+    EmojiInfo { emoji: "\u{1f47c}\u{1f3fb}", name: "baby angel: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f47c}\u{1f3fc}", name: "baby angel: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f47c}\u{1f3fd}", name: "baby angel: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f47c}\u{1f3fe}", name: "baby angel: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f47c}\u{1f3ff}", name: "baby angel: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f385}", name: "Santa Claus", group: "People & Body", subgroup: "person-fantasy", version: 0.6, shortcodes: &["santa"] },
+    EmojiInfo { emoji: "\u{1f385}\u{1f3fb}", name: "Santa Claus: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f385}\u{1f3fc}", name: "Santa Claus: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f385}\u{1f3fd}", name: "Santa Claus: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f385}\u{1f3fe}", name: "Santa Claus: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f385}\u{1f3ff}", name: "Santa Claus: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f936}", name: "Mrs. Claus", group: "People & Body", subgroup: "person-fantasy", version: 3.0, shortcodes: &["mrs_claus"] },
+    EmojiInfo { emoji: "\u{1f936}\u{1f3fb}", name: "Mrs. Claus: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f936}\u{1f3fc}", name: "Mrs. Claus: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f936}\u{1f3fd}", name: "Mrs. Claus: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f936}\u{1f3fe}", name: "Mrs. Claus: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f936}\u{1f3ff}", name: "Mrs. Claus: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f384}", name: "mx claus", group: "People & Body", subgroup: "person-fantasy", version: 13.0, shortcodes: &["mx_claus"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f384}", name: "mx claus: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f384}", name: "mx claus: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f384}", name: "mx claus: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f384}", name: "mx claus: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f384}", name: "mx claus: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 13.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}", name: "superhero", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &["superhero"] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fb}", name: "superhero: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fc}", name: "superhero: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fd}", name: "superhero: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fe}", name: "superhero: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3ff}", name: "superhero: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{200d}\u{2642}\u{fe0f}", name: "man superhero", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &["superhero_man"] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man superhero: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man superhero: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man superhero: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man superhero: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man superhero: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{200d}\u{2640}\u{fe0f}", name: "woman superhero", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &["superhero_woman"] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman superhero: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman superhero: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman superhero: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman superhero: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman superhero: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}", name: "supervillain", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &["supervillain"] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fb}", name: "supervillain: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fc}", name: "supervillain: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fd}", name: "supervillain: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fe}", name: "supervillain: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3ff}", name: "supervillain: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{200d}\u{2642}\u{fe0f}", name: "man supervillain", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &["supervillain_man"] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man supervillain: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man supervillain: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man supervillain: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man supervillain: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man supervillain: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{200d}\u{2640}\u{fe0f}", name: "woman supervillain", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &["supervillain_woman"] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman supervillain: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman supervillain: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman supervillain: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman supervillain: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman supervillain: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 11.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}", name: "mage", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["mage"] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fb}", name: "mage: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fc}", name: "mage: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fd}", name: "mage: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fe}", name: "mage: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3ff}", name: "mage: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{200d}\u{2642}\u{fe0f}", name: "man mage", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["mage_man"] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man mage: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man mage: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man mage: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man mage: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man mage: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{200d}\u{2640}\u{fe0f}", name: "woman mage", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["mage_woman"] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman mage: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman mage: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman mage: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman mage: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman mage: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}", name: "fairy", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["fairy"] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fb}", name: "fairy: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fc}", name: "fairy: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fd}", name: "fairy: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fe}", name: "fairy: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3ff}", name: "fairy: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{200d}\u{2642}\u{fe0f}", name: "man fairy", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["fairy_man"] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man fairy: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man fairy: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man fairy: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man fairy: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man fairy: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{200d}\u{2640}\u{fe0f}", name: "woman fairy", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["fairy_woman"] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman fairy: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman fairy: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman fairy: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman fairy: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9da}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman fairy: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}", name: "vampire", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["vampire"] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fb}", name: "vampire: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fc}", name: "vampire: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fd}", name: "vampire: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fe}", name: "vampire: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3ff}", name: "vampire: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{200d}\u{2642}\u{fe0f}", name: "man vampire", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["vampire_man"] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man vampire: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man vampire: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man vampire: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man vampire: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man vampire: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{200d}\u{2640}\u{fe0f}", name: "woman vampire", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["vampire_woman"] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman vampire: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman vampire: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman vampire: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman vampire: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9db}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman vampire: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}", name: "merperson", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["merperson"] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fb}", name: "merperson: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fc}", name: "merperson: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fd}", name: "merperson: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fe}", name: "merperson: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3ff}", name: "merperson: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{200d}\u{2642}\u{fe0f}", name: "merman", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["merman"] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "merman: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "merman: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "merman: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "merman: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "merman: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{200d}\u{2640}\u{fe0f}", name: "mermaid", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["mermaid"] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "mermaid: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "mermaid: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "mermaid: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "mermaid: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "mermaid: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}", name: "elf", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["elf"] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fb}", name: "elf: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fc}", name: "elf: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fd}", name: "elf: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fe}", name: "elf: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3ff}", name: "elf: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{200d}\u{2642}\u{fe0f}", name: "man elf", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["elf_man"] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man elf: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man elf: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man elf: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man elf: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man elf: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{200d}\u{2640}\u{fe0f}", name: "woman elf", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["elf_woman"] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman elf: light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman elf: medium-light skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman elf: medium skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman elf: medium-dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman elf: dark skin tone", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9de}", name: "genie", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["genie"] },
+    EmojiInfo { emoji: "\u{1f9de}\u{200d}\u{2642}\u{fe0f}", name: "man genie", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["genie_man"] },
+    EmojiInfo { emoji: "\u{1f9de}\u{200d}\u{2640}\u{fe0f}", name: "woman genie", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["genie_woman"] },
+    EmojiInfo { emoji: "\u{1f9df}", name: "zombie", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["zombie"] },
+    EmojiInfo { emoji: "\u{1f9df}\u{200d}\u{2642}\u{fe0f}", name: "man zombie", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["zombie_man"] },
+    EmojiInfo { emoji: "\u{1f9df}\u{200d}\u{2640}\u{fe0f}", name: "woman zombie", group: "People & Body", subgroup: "person-fantasy", version: 5.0, shortcodes: &["zombie_woman"] },
+    EmojiInfo { emoji: "\u{1f486}", name: "person getting massage", group: "People & Body", subgroup: "person-activity", version: 0.6, shortcodes: &["massage"] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fb}", name: "person getting massage: light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fc}", name: "person getting massage: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fd}", name: "person getting massage: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fe}", name: "person getting massage: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3ff}", name: "person getting massage: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{200d}\u{2642}\u{fe0f}", name: "man getting massage", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["massage_man"] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man getting massage: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man getting massage: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man getting massage: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man getting massage: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man getting massage: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{200d}\u{2640}\u{fe0f}", name: "woman getting massage", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["massage_woman"] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman getting massage: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman getting massage: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman getting massage: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman getting massage: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f486}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman getting massage: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}", name: "person getting haircut", group: "People & Body", subgroup: "person-activity", version: 0.6, shortcodes: &["haircut"] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fb}", name: "person getting haircut: light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fc}", name: "person getting haircut: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fd}", name: "person getting haircut: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fe}", name: "person getting haircut: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3ff}", name: "person getting haircut: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{200d}\u{2642}\u{fe0f}", name: "man getting haircut", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["haircut_man"] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man getting haircut: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man getting haircut: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man getting haircut: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man getting haircut: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man getting haircut: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{200d}\u{2640}\u{fe0f}", name: "woman getting haircut", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["haircut_woman"] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman getting haircut: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman getting haircut: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman getting haircut: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman getting haircut: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f487}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman getting haircut: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}", name: "person walking", group: "People & Body", subgroup: "person-activity", version: 0.6, shortcodes: &["walking"] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fb}", name: "person walking: light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fc}", name: "person walking: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fd}", name: "person walking: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fe}", name: "person walking: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3ff}", name: "person walking: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{200d}\u{2642}\u{fe0f}", name: "man walking", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["walking_man"] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man walking: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man walking: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man walking: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man walking: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man walking: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{200d}\u{2640}\u{fe0f}", name: "woman walking", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["walking_woman"] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman walking: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman walking: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman walking: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman walking: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman walking: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}", name: "person standing", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["standing_person"] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fb}", name: "person standing: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fc}", name: "person standing: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fd}", name: "person standing: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fe}", name: "person standing: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3ff}", name: "person standing: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{200d}\u{2642}\u{fe0f}", name: "man standing", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["standing_man"] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man standing: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man standing: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man standing: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man standing: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man standing: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{200d}\u{2640}\u{fe0f}", name: "woman standing", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["standing_woman"] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman standing: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman standing: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman standing: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman standing: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman standing: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}", name: "person kneeling", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["kneeling_person"] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fb}", name: "person kneeling: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fc}", name: "person kneeling: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fd}", name: "person kneeling: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fe}", name: "person kneeling: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3ff}", name: "person kneeling: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{200d}\u{2642}\u{fe0f}", name: "man kneeling", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["kneeling_man"] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man kneeling: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man kneeling: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man kneeling: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man kneeling: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man kneeling: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{200d}\u{2640}\u{fe0f}", name: "woman kneeling", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["kneeling_woman"] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman kneeling: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman kneeling: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman kneeling: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman kneeling: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman kneeling: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f9af}", name: "person with white cane", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &["person_with_probing_cane"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}", name: "person with white cane: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}", name: "person with white cane: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}", name: "person with white cane: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}", name: "person with white cane: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}", name: "person with white cane: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f9af}", name: "man with white cane", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["man_with_probing_cane"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}", name: "man with white cane: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}", name: "man with white cane: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}", name: "man with white cane: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}", name: "man with white cane: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}", name: "man with white cane: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f9af}", name: "woman with white cane", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["woman_with_probing_cane"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}", name: "woman with white cane: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}", name: "woman with white cane: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}", name: "woman with white cane: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}", name: "woman with white cane: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}", name: "woman with white cane: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f9bc}", name: "person in motorized wheelchair", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &["person_in_motorized_wheelchair"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}", name: "person in motorized wheelchair: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}", name: "person in motorized wheelchair: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}", name: "person in motorized wheelchair: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}", name: "person in motorized wheelchair: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}", name: "person in motorized wheelchair: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f9bc}", name: "man in motorized wheelchair", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["man_in_motorized_wheelchair"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}", name: "man in motorized wheelchair: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}", name: "man in motorized wheelchair: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}", name: "man in motorized wheelchair: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}", name: "man in motorized wheelchair: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}", name: "man in motorized wheelchair: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f9bc}", name: "woman in motorized wheelchair", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["woman_in_motorized_wheelchair"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}", name: "woman in motorized wheelchair: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}", name: "woman in motorized wheelchair: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}", name: "woman in motorized wheelchair: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}", name: "woman in motorized wheelchair: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}", name: "woman in motorized wheelchair: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f9bd}", name: "person in manual wheelchair", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &["person_in_manual_wheelchair"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}", name: "person in manual wheelchair: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}", name: "person in manual wheelchair: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}", name: "person in manual wheelchair: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}", name: "person in manual wheelchair: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}", name: "person in manual wheelchair: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f9bd}", name: "man in manual wheelchair", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["man_in_manual_wheelchair"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}", name: "man in manual wheelchair: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}", name: "man in manual wheelchair: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}", name: "man in manual wheelchair: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}", name: "man in manual wheelchair: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}", name: "man in manual wheelchair: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f9bd}", name: "woman in manual wheelchair", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &["woman_in_manual_wheelchair"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}", name: "woman in manual wheelchair: light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}", name: "woman in manual wheelchair: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}", name: "woman in manual wheelchair: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}", name: "woman in manual wheelchair: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}", name: "woman in manual wheelchair: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}", name: "person running", group: "People & Body", subgroup: "person-activity", version: 0.6, shortcodes: &["runner", "running"] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fb}", name: "person running: light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fc}", name: "person running: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fd}", name: "person running: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fe}", name: "person running: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3ff}", name: "person running: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{200d}\u{2642}\u{fe0f}", name: "man running", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["running_man"] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man running: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man running: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man running: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man running: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man running: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{200d}\u{2640}\u{fe0f}", name: "woman running", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["running_woman"] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman running: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman running: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman running: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman running: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman running: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f483}", name: "woman dancing", group: "People & Body", subgroup: "person-activity", version: 0.6, shortcodes: &["woman_dancing", "dancer"] },
+    EmojiInfo { emoji: "\u{1f483}\u{1f3fb}", name: "woman dancing: light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f483}\u{1f3fc}", name: "woman dancing: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f483}\u{1f3fd}", name: "woman dancing: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f483}\u{1f3fe}", name: "woman dancing: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f483}\u{1f3ff}", name: "woman dancing: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f57a}", name: "man dancing", group: "People & Body", subgroup: "person-activity", version: 3.0, shortcodes: &["man_dancing"] },
+    EmojiInfo { emoji: "\u{1f57a}\u{1f3fb}", name: "man dancing: light skin tone", group: "People & Body", subgroup: "person-activity", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f57a}\u{1f3fc}", name: "man dancing: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f57a}\u{1f3fd}", name: "man dancing: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f57a}\u{1f3fe}", name: "man dancing: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f57a}\u{1f3ff}", name: "man dancing: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f574}\u{fe0f}", name: "person in suit levitating", group: "People & Body", subgroup: "person-activity", version: 0.7, shortcodes: &["business_suit_levitating"] },
+    EmojiInfo { emoji: "\u{1f574}\u{1f3fb}", name: "person in suit levitating: light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f574}\u{1f3fc}", name: "person in suit levitating: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f574}\u{1f3fd}", name: "person in suit levitating: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f574}\u{1f3fe}", name: "person in suit levitating: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f574}\u{1f3ff}", name: "person in suit levitating: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46f}", name: "people with bunny ears", group: "People & Body", subgroup: "person-activity", version: 0.6, shortcodes: &["dancers"] },
+    EmojiInfo { emoji: "\u{1f46f}\u{200d}\u{2642}\u{fe0f}", name: "men with bunny ears", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["dancing_men"] },
+    EmojiInfo { emoji: "\u{1f46f}\u{200d}\u{2640}\u{fe0f}", name: "women with bunny ears", group: "People & Body", subgroup: "person-activity", version: 4.0, shortcodes: &["dancing_women"] },
+    EmojiInfo { emoji: "\u{1f9d6}", name: "person in steamy room", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &["sauna_person"] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fb}", name: "person in steamy room: light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fc}", name: "person in steamy room: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fd}", name: "person in steamy room: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fe}", name: "person in steamy room: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3ff}", name: "person in steamy room: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{200d}\u{2642}\u{fe0f}", name: "man in steamy room", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &["sauna_man"] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man in steamy room: light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man in steamy room: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man in steamy room: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man in steamy room: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man in steamy room: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{200d}\u{2640}\u{fe0f}", name: "woman in steamy room", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &["sauna_woman"] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman in steamy room: light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman in steamy room: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman in steamy room: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman in steamy room: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman in steamy room: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}", name: "person climbing", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &["climbing"] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fb}", name: "person climbing: light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fc}", name: "person climbing: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fd}", name: "person climbing: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fe}", name: "person climbing: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3ff}", name: "person climbing: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{200d}\u{2642}\u{fe0f}", name: "man climbing", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &["climbing_man"] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man climbing: light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man climbing: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man climbing: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man climbing: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man climbing: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{200d}\u{2640}\u{fe0f}", name: "woman climbing", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &["climbing_woman"] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman climbing: light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman climbing: medium-light skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman climbing: medium skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman climbing: medium-dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman climbing: dark skin tone", group: "People & Body", subgroup: "person-activity", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93a}", name: "person fencing", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &["person_fencing"] },
+    EmojiInfo { emoji: "\u{1f3c7}", name: "horse racing", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &["horse_racing"] },
+    EmojiInfo { emoji: "\u{1f3c7}\u{1f3fb}", name: "horse racing: light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c7}\u{1f3fc}", name: "horse racing: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c7}\u{1f3fd}", name: "horse racing: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c7}\u{1f3fe}", name: "horse racing: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c7}\u{1f3ff}", name: "horse racing: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f7}\u{fe0f}", name: "skier", group: "People & Body", subgroup: "person-sport", version: 0.7, shortcodes: &["skier"] },
+    EmojiInfo { emoji: "\u{1f3c2}", name: "snowboarder", group: "People & Body", subgroup: "person-sport", version: 0.6, shortcodes: &["snowboarder"] },
+    EmojiInfo { emoji: "\u{1f3c2}\u{1f3fb}", name: "snowboarder: light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c2}\u{1f3fc}", name: "snowboarder: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c2}\u{1f3fd}", name: "snowboarder: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c2}\u{1f3fe}", name: "snowboarder: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c2}\u{1f3ff}", name: "snowboarder: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{fe0f}", name: "person golfing", group: "People & Body", subgroup: "person-sport", version: 0.7, shortcodes: &["golfing"] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fb}", name: "person golfing: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fc}", name: "person golfing: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fd}", name: "person golfing: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fe}", name: "person golfing: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3ff}", name: "person golfing: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", name: "man golfing", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["golfing_man"] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man golfing: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man golfing: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man golfing: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man golfing: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man golfing: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", name: "woman golfing", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["golfing_woman"] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman golfing: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman golfing: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman golfing: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman golfing: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman golfing: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}", name: "person surfing", group: "People & Body", subgroup: "person-sport", version: 0.6, shortcodes: &["surfer"] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fb}", name: "person surfing: light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fc}", name: "person surfing: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fd}", name: "person surfing: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fe}", name: "person surfing: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3ff}", name: "person surfing: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{200d}\u{2642}\u{fe0f}", name: "man surfing", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["surfing_man"] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man surfing: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man surfing: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man surfing: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man surfing: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man surfing: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{200d}\u{2640}\u{fe0f}", name: "woman surfing", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["surfing_woman"] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman surfing: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman surfing: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman surfing: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman surfing: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman surfing: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}", name: "person rowing boat", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &["rowboat"] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fb}", name: "person rowing boat: light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fc}", name: "person rowing boat: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fd}", name: "person rowing boat: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fe}", name: "person rowing boat: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3ff}", name: "person rowing boat: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{200d}\u{2642}\u{fe0f}", name: "man rowing boat", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["rowing_man"] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man rowing boat: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man rowing boat: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man rowing boat: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man rowing boat: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man rowing boat: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{200d}\u{2640}\u{fe0f}", name: "woman rowing boat", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["rowing_woman"] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman rowing boat: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman rowing boat: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman rowing boat: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman rowing boat: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman rowing boat: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}", name: "person swimming", group: "People & Body", subgroup: "person-sport", version: 0.6, shortcodes: &["swimmer"] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fb}", name: "person swimming: light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fc}", name: "person swimming: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fd}", name: "person swimming: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fe}", name: "person swimming: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3ff}", name: "person swimming: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{200d}\u{2642}\u{fe0f}", name: "man swimming", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["swimming_man"] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man swimming: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man swimming: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man swimming: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man swimming: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man swimming: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{200d}\u{2640}\u{fe0f}", name: "woman swimming", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["swimming_woman"] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman swimming: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman swimming: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman swimming: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman swimming: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman swimming: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{fe0f}", name: "person bouncing ball", group: "People & Body", subgroup: "person-sport", version: 0.7, shortcodes: &["bouncing_ball_person"] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fb}", name: "person bouncing ball: light skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fc}", name: "person bouncing ball: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fd}", name: "person bouncing ball: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fe}", name: "person bouncing ball: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3ff}", name: "person bouncing ball: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", name: "man bouncing ball", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["bouncing_ball_man", "basketball_man"] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man bouncing ball: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man bouncing ball: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man bouncing ball: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man bouncing ball: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man bouncing ball: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", name: "woman bouncing ball", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["bouncing_ball_woman", "basketball_woman"] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman bouncing ball: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman bouncing ball: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman bouncing ball: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman bouncing ball: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{26f9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman bouncing ball: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{fe0f}", name: "person lifting weights", group: "People & Body", subgroup: "person-sport", version: 0.7, shortcodes: &["weight_lifting"] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fb}", name: "person lifting weights: light skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fc}", name: "person lifting weights: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fd}", name: "person lifting weights: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fe}", name: "person lifting weights: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3ff}", name: "person lifting weights: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 2.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", name: "man lifting weights", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["weight_lifting_man"] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man lifting weights: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man lifting weights: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man lifting weights: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man lifting weights: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man lifting weights: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", name: "woman lifting weights", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["weight_lifting_woman"] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman lifting weights: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman lifting weights: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman lifting weights: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman lifting weights: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman lifting weights: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}", name: "person biking", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &["bicyclist"] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fb}", name: "person biking: light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fc}", name: "person biking: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fd}", name: "person biking: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fe}", name: "person biking: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3ff}", name: "person biking: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{200d}\u{2642}\u{fe0f}", name: "man biking", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["biking_man"] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man biking: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man biking: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man biking: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man biking: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man biking: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{200d}\u{2640}\u{fe0f}", name: "woman biking", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["biking_woman"] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman biking: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman biking: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman biking: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman biking: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman biking: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}", name: "person mountain biking", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &["mountain_bicyclist"] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fb}", name: "person mountain biking: light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fc}", name: "person mountain biking: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fd}", name: "person mountain biking: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fe}", name: "person mountain biking: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3ff}", name: "person mountain biking: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{200d}\u{2642}\u{fe0f}", name: "man mountain biking", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["mountain_biking_man"] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man mountain biking: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man mountain biking: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man mountain biking: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man mountain biking: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man mountain biking: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{200d}\u{2640}\u{fe0f}", name: "woman mountain biking", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["mountain_biking_woman"] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman mountain biking: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman mountain biking: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman mountain biking: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman mountain biking: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman mountain biking: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}", name: "person cartwheeling", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &["cartwheeling"] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fb}", name: "person cartwheeling: light skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fc}", name: "person cartwheeling: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fd}", name: "person cartwheeling: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fe}", name: "person cartwheeling: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3ff}", name: "person cartwheeling: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{200d}\u{2642}\u{fe0f}", name: "man cartwheeling", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["man_cartwheeling"] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man cartwheeling: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man cartwheeling: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man cartwheeling: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man cartwheeling: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man cartwheeling: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{200d}\u{2640}\u{fe0f}", name: "woman cartwheeling", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["woman_cartwheeling"] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman cartwheeling: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman cartwheeling: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman cartwheeling: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman cartwheeling: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f938}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman cartwheeling: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93c}", name: "people wrestling", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &["wrestling"] },
+    EmojiInfo { emoji: "\u{1f93c}\u{200d}\u{2642}\u{fe0f}", name: "men wrestling", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["men_wrestling"] },
+    EmojiInfo { emoji: "\u{1f93c}\u{200d}\u{2640}\u{fe0f}", name: "women wrestling", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["women_wrestling"] },
+    EmojiInfo { emoji: "\u{1f93d}", name: "person playing water polo", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &["water_polo"] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fb}", name: "person playing water polo: light skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fc}", name: "person playing water polo: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fd}", name: "person playing water polo: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fe}", name: "person playing water polo: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3ff}", name: "person playing water polo: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{200d}\u{2642}\u{fe0f}", name: "man playing water polo", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["man_playing_water_polo"] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man playing water polo: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man playing water polo: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man playing water polo: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man playing water polo: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man playing water polo: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{200d}\u{2640}\u{fe0f}", name: "woman playing water polo", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["woman_playing_water_polo"] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman playing water polo: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman playing water polo: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman playing water polo: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman playing water polo: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman playing water polo: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}", name: "person playing handball", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &["handball_person"] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fb}", name: "person playing handball: light skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fc}", name: "person playing handball: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fd}", name: "person playing handball: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fe}", name: "person playing handball: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3ff}", name: "person playing handball: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{200d}\u{2642}\u{fe0f}", name: "man playing handball", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["man_playing_handball"] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man playing handball: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man playing handball: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man playing handball: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man playing handball: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man playing handball: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{200d}\u{2640}\u{fe0f}", name: "woman playing handball", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["woman_playing_handball"] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman playing handball: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman playing handball: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman playing handball: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman playing handball: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f93e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman playing handball: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}", name: "person juggling", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &["juggling_person"] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fb}", name: "person juggling: light skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fc}", name: "person juggling: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fd}", name: "person juggling: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fe}", name: "person juggling: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3ff}", name: "person juggling: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 3.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{200d}\u{2642}\u{fe0f}", name: "man juggling", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["man_juggling"] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man juggling: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man juggling: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man juggling: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man juggling: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man juggling: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{200d}\u{2640}\u{fe0f}", name: "woman juggling", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &["woman_juggling"] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman juggling: light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman juggling: medium-light skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman juggling: medium skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman juggling: medium-dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f939}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman juggling: dark skin tone", group: "People & Body", subgroup: "person-sport", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}", name: "person in lotus position", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &["lotus_position"] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fb}", name: "person in lotus position: light skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fc}", name: "person in lotus position: medium-light skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fd}", name: "person in lotus position: medium skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fe}", name: "person in lotus position: medium-dark skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3ff}", name: "person in lotus position: dark skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{200d}\u{2642}\u{fe0f}", name: "man in lotus position", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &["lotus_position_man"] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", name: "man in lotus position: light skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", name: "man in lotus position: medium-light skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", name: "man in lotus position: medium skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", name: "man in lotus position: medium-dark skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", name: "man in lotus position: dark skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{200d}\u{2640}\u{fe0f}", name: "woman in lotus position", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &["lotus_position_woman"] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", name: "woman in lotus position: light skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", name: "woman in lotus position: medium-light skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", name: "woman in lotus position: medium skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", name: "woman in lotus position: medium-dark skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", name: "woman in lotus position: dark skin tone", group: "People & Body", subgroup: "person-resting", version: 5.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6c0}", name: "person taking bath", group: "People & Body", subgroup: "person-resting", version: 0.6, shortcodes: &["bath"] },
+    EmojiInfo { emoji: "\u{1f6c0}\u{1f3fb}", name: "person taking bath: light skin tone", group: "People & Body", subgroup: "person-resting", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6c0}\u{1f3fc}", name: "person taking bath: medium-light skin tone", group: "People & Body", subgroup: "person-resting", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6c0}\u{1f3fd}", name: "person taking bath: medium skin tone", group: "People & Body", subgroup: "person-resting", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6c0}\u{1f3fe}", name: "person taking bath: medium-dark skin tone", group: "People & Body", subgroup: "person-resting", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6c0}\u{1f3ff}", name: "person taking bath: dark skin tone", group: "People & Body", subgroup: "person-resting", version: 1.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6cc}", name: "person in bed", group: "People & Body", subgroup: "person-resting", version: 1.0, shortcodes: &["sleeping_bed"] },
+    EmojiInfo { emoji: "\u{1f6cc}\u{1f3fb}", name: "person in bed: light skin tone", group: "People & Body", subgroup: "person-resting", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6cc}\u{1f3fc}", name: "person in bed: medium-light skin tone", group: "People & Body", subgroup: "person-resting", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6cc}\u{1f3fd}", name: "person in bed: medium skin tone", group: "People & Body", subgroup: "person-resting", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6cc}\u{1f3fe}", name: "person in bed: medium-dark skin tone", group: "People & Body", subgroup: "person-resting", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f6cc}\u{1f3ff}", name: "person in bed: dark skin tone", group: "People & Body", subgroup: "person-resting", version: 4.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}", name: "people holding hands", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &["people_holding_hands"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", name: "people holding hands: light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", name: "people holding hands: light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", name: "people holding hands: light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", name: "people holding hands: light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", name: "people holding hands: light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", name: "people holding hands: medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", name: "people holding hands: medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", name: "people holding hands: medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", name: "people holding hands: medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", name: "people holding hands: medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", name: "people holding hands: medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", name: "people holding hands: medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", name: "people holding hands: medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", name: "people holding hands: medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", name: "people holding hands: medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", name: "people holding hands: medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", name: "people holding hands: medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", name: "people holding hands: medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", name: "people holding hands: medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", name: "people holding hands: medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", name: "people holding hands: dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", name: "people holding hands: dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", name: "people holding hands: dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", name: "people holding hands: dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", name: "people holding hands: dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46d}", name: "women holding hands", group: "People & Body", subgroup: "family", version: 1.0, shortcodes: &["two_women_holding_hands"] },
+    EmojiInfo { emoji: "\u{1f46d}\u{1f3fb}", name: "women holding hands: light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", name: "women holding hands: light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", name: "women holding hands: light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", name: "women holding hands: light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", name: "women holding hands: light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", name: "women holding hands: medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46d}\u{1f3fc}", name: "women holding hands: medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", name: "women holding hands: medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", name: "women holding hands: medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", name: "women holding hands: medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", name: "women holding hands: medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", name: "women holding hands: medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46d}\u{1f3fd}", name: "women holding hands: medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", name: "women holding hands: medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", name: "women holding hands: medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", name: "women holding hands: medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", name: "women holding hands: medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", name: "women holding hands: medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46d}\u{1f3fe}", name: "women holding hands: medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", name: "women holding hands: medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", name: "women holding hands: dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", name: "women holding hands: dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", name: "women holding hands: dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", name: "women holding hands: dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46d}\u{1f3ff}", name: "women holding hands: dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46b}", name: "woman and man holding hands", group: "People & Body", subgroup: "family", version: 0.6, shortcodes: &["couple"] },
+    EmojiInfo { emoji: "\u{1f46b}\u{1f3fb}", name: "woman and man holding hands: light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", name: "woman and man holding hands: light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", name: "woman and man holding hands: light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", name: "woman and man holding hands: light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", name: "woman and man holding hands: light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", name: "woman and man holding hands: medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46b}\u{1f3fc}", name: "woman and man holding hands: medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", name: "woman and man holding hands: medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", name: "woman and man holding hands: medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", name: "woman and man holding hands: medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", name: "woman and man holding hands: medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", name: "woman and man holding hands: medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46b}\u{1f3fd}", name: "woman and man holding hands: medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", name: "woman and man holding hands: medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", name: "woman and man holding hands: medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", name: "woman and man holding hands: medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", name: "woman and man holding hands: medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", name: "woman and man holding hands: medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46b}\u{1f3fe}", name: "woman and man holding hands: medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", name: "woman and man holding hands: medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", name: "woman and man holding hands: dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", name: "woman and man holding hands: dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", name: "woman and man holding hands: dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", name: "woman and man holding hands: dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46b}\u{1f3ff}", name: "woman and man holding hands: dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46c}", name: "men holding hands", group: "People & Body", subgroup: "family", version: 1.0, shortcodes: &["two_men_holding_hands"] },
+    EmojiInfo { emoji: "\u{1f46c}\u{1f3fb}", name: "men holding hands: light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", name: "men holding hands: light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", name: "men holding hands: light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", name: "men holding hands: light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", name: "men holding hands: light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", name: "men holding hands: medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46c}\u{1f3fc}", name: "men holding hands: medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", name: "men holding hands: medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", name: "men holding hands: medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", name: "men holding hands: medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", name: "men holding hands: medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", name: "men holding hands: medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46c}\u{1f3fd}", name: "men holding hands: medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", name: "men holding hands: medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", name: "men holding hands: medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", name: "men holding hands: medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", name: "men holding hands: medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", name: "men holding hands: medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46c}\u{1f3fe}", name: "men holding hands: medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", name: "men holding hands: medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 12.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", name: "men holding hands: dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", name: "men holding hands: dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", name: "men holding hands: dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", name: "men holding hands: dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46c}\u{1f3ff}", name: "men holding hands: dark skin tone", group: "People & Body", subgroup: "family", version: 12.0, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f48f}", name: "kiss", group: "People & Body", subgroup: "family", version: 0.6, shortcodes: &["couplekiss"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", name: "kiss: person, person, light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", name: "kiss: person, person, light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", name: "kiss: person, person, light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", name: "kiss: person, person, light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", name: "kiss: person, person, medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", name: "kiss: person, person, medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", name: "kiss: person, person, medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", name: "kiss: person, person, medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", name: "kiss: person, person, medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", name: "kiss: person, person, medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", name: "kiss: person, person, medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", name: "kiss: person, person, medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", name: "kiss: person, person, medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", name: "kiss: person, person, medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", name: "kiss: person, person, medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", name: "kiss: person, person, medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", name: "kiss: person, person, dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", name: "kiss: person, person, dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", name: "kiss: person, person, dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", name: "kiss: person, person, dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", name: "kiss: woman, man", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["couplekiss_man_woman"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: woman, man, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: woman, man, light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: woman, man, light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: woman, man, light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: woman, man, light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: woman, man, medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: woman, man, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: woman, man, medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: woman, man, medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: woman, man, medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: woman, man, medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: woman, man, medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: woman, man, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: woman, man, medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: woman, man, medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: woman, man, medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: woman, man, medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: woman, man, medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: woman, man, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: woman, man, medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: woman, man, dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: woman, man, dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: woman, man, dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: woman, man, dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: woman, man, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", name: "kiss: man, man", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["couplekiss_man_man"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: man, man, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: man, man, light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: man, man, light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: man, man, light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: man, man, light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: man, man, medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: man, man, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: man, man, medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: man, man, medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: man, man, medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: man, man, medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: man, man, medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: man, man, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: man, man, medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: man, man, medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: man, man, medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: man, man, medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: man, man, medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: man, man, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: man, man, medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", name: "kiss: man, man, dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", name: "kiss: man, man, dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", name: "kiss: man, man, dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", name: "kiss: man, man, dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", name: "kiss: man, man, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}", name: "kiss: woman, woman", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["couplekiss_woman_woman"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", name: "kiss: woman, woman, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", name: "kiss: woman, woman, light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", name: "kiss: woman, woman, light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", name: "kiss: woman, woman, light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", name: "kiss: woman, woman, light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", name: "kiss: woman, woman, medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", name: "kiss: woman, woman, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", name: "kiss: woman, woman, medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", name: "kiss: woman, woman, medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", name: "kiss: woman, woman, medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", name: "kiss: woman, woman, medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", name: "kiss: woman, woman, medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", name: "kiss: woman, woman, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", name: "kiss: woman, woman, medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", name: "kiss: woman, woman, medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", name: "kiss: woman, woman, medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", name: "kiss: woman, woman, medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", name: "kiss: woman, woman, medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", name: "kiss: woman, woman, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", name: "kiss: woman, woman, medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", name: "kiss: woman, woman, dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", name: "kiss: woman, woman, dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", name: "kiss: woman, woman, dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", name: "kiss: woman, woman, dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", name: "kiss: woman, woman, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f491}", name: "couple with heart", group: "People & Body", subgroup: "family", version: 0.6, shortcodes: &["couple_with_heart"] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", name: "couple with heart: person, person, light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", name: "couple with heart: person, person, light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", name: "couple with heart: person, person, light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", name: "couple with heart: person, person, light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", name: "couple with heart: person, person, medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", name: "couple with heart: person, person, medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", name: "couple with heart: person, person, medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", name: "couple with heart: person, person, medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", name: "couple with heart: person, person, medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", name: "couple with heart: person, person, medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", name: "couple with heart: person, person, medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", name: "couple with heart: person, person, medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", name: "couple with heart: person, person, medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", name: "couple with heart: person, person, medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", name: "couple with heart: person, person, medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", name: "couple with heart: person, person, medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", name: "couple with heart: person, person, dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", name: "couple with heart: person, person, dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", name: "couple with heart: person, person, dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", name: "couple with heart: person, person, dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", name: "couple with heart: woman, man", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["couple_with_heart_woman_man"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: woman, man, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: woman, man, light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: woman, man, light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: woman, man, light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: woman, man, light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: woman, man, medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: woman, man, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: woman, man, medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: woman, man, medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: woman, man, medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: woman, man, medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: woman, man, medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: woman, man, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: woman, man, medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: woman, man, medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: woman, man, medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: woman, man, medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: woman, man, medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: woman, man, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: woman, man, medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: woman, man, dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: woman, man, dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: woman, man, dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: woman, man, dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: woman, man, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", name: "couple with heart: man, man", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["couple_with_heart_man_man"] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: man, man, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: man, man, light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: man, man, light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: man, man, light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: man, man, light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: man, man, medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: man, man, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: man, man, medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: man, man, medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: man, man, medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: man, man, medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: man, man, medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: man, man, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: man, man, medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: man, man, medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: man, man, medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: man, man, medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: man, man, medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: man, man, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: man, man, medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", name: "couple with heart: man, man, dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", name: "couple with heart: man, man, dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", name: "couple with heart: man, man, dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", name: "couple with heart: man, man, dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", name: "couple with heart: man, man, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}", name: "couple with heart: woman, woman", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["couple_with_heart_woman_woman"] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", name: "couple with heart: woman, woman, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", name: "couple with heart: woman, woman, light skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", name: "couple with heart: woman, woman, light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", name: "couple with heart: woman, woman, light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", name: "couple with heart: woman, woman, light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", name: "couple with heart: woman, woman, medium-light skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", name: "couple with heart: woman, woman, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", name: "couple with heart: woman, woman, medium-light skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", name: "couple with heart: woman, woman, medium-light skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", name: "couple with heart: woman, woman, medium-light skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", name: "couple with heart: woman, woman, medium skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", name: "couple with heart: woman, woman, medium skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", name: "couple with heart: woman, woman, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", name: "couple with heart: woman, woman, medium skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", name: "couple with heart: woman, woman, medium skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", name: "couple with heart: woman, woman, medium-dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", name: "couple with heart: woman, woman, medium-dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", name: "couple with heart: woman, woman, medium-dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", name: "couple with heart: woman, woman, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", name: "couple with heart: woman, woman, medium-dark skin tone, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", name: "couple with heart: woman, woman, dark skin tone, light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", name: "couple with heart: woman, woman, dark skin tone, medium-light skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", name: "couple with heart: woman, woman, dark skin tone, medium skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", name: "couple with heart: woman, woman, dark skin tone, medium-dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", name: "couple with heart: woman, woman, dark skin tone", group: "People & Body", subgroup: "family", version: 13.1, shortcodes: &[] },
+    EmojiInfo { emoji: "\u{1f46a}", name: "family", group: "People & Body", subgroup: "family", version: 0.6, shortcodes: &["family"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}", name: "family: man, woman, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_woman_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}", name: "family: man, woman, girl", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_woman_girl"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", name: "family: man, woman, girl, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_woman_girl_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", name: "family: man, woman, boy, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_woman_boy_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", name: "family: man, woman, girl, girl", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_woman_girl_girl"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}", name: "family: man, man, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_man_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}", name: "family: man, man, girl", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_man_girl"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}", name: "family: man, man, girl, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_man_girl_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}", name: "family: man, man, boy, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_man_boy_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}", name: "family: man, man, girl, girl", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_man_man_girl_girl"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}", name: "family: woman, woman, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_woman_woman_boy"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}", name: "family: woman, woman, girl", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_woman_woman_girl"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", name: "family: woman, woman, girl, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_woman_woman_girl_boy"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", name: "family: woman, woman, boy, boy", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_woman_woman_boy_boy"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", name: "family: woman, woman, girl, girl", group: "People & Body", subgroup: "family", version: 2.0, shortcodes: &["family_woman_woman_girl_girl"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f466}", name: "family: man, boy", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_man_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}", name: "family: man, boy, boy", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_man_boy_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f467}", name: "family: man, girl", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_man_girl"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}", name: "family: man, girl, boy", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_man_girl_boy"] },
+    EmojiInfo { emoji: "\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}", name: "family: man, girl, girl", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_man_girl_girl"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f466}", name: "family: woman, boy", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_woman_boy"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", name: "family: woman, boy, boy", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_woman_boy_boy"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f467}", name: "family: woman, girl", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_woman_girl"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", name: "family: woman, girl, boy", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_woman_girl_boy"] },
+    EmojiInfo { emoji: "\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", name: "family: woman, girl, girl", group: "People & Body", subgroup: "family", version: 4.0, shortcodes: &["family_woman_girl_girl"] },
+    EmojiInfo { emoji: "\u{1f5e3}\u{fe0f}", name: "speaking head", group: "People & Body", subgroup: "person-symbol", version: 0.7, shortcodes: &["speaking_head"] },
+    EmojiInfo { emoji: "\u{1f464}", name: "bust in silhouette", group: "People & Body", subgroup: "person-symbol", version: 0.6, shortcodes: &["bust_in_silhouette"] },
+    EmojiInfo { emoji: "\u{1f465}", name: "busts in silhouette", group: "People & Body", subgroup: "person-symbol", version: 1.0, shortcodes: &["busts_in_silhouette"] },
+    EmojiInfo { emoji: "\u{1fac2}", name: "people hugging", group: "People & Body", subgroup: "person-symbol", version: 13.0, shortcodes: &["people_hugging"] },
+    EmojiInfo { emoji: "\u{1f463}", name: "footprints", group: "People & Body", subgroup: "person-symbol", version: 0.6, shortcodes: &["footprints"] },
+    EmojiInfo { emoji: "\u{1f435}", name: "monkey face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["monkey_face"] },
+    EmojiInfo { emoji: "\u{1f412}", name: "monkey", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["monkey"] },
+    EmojiInfo { emoji: "\u{1f98d}", name: "gorilla", group: "Animals & Nature", subgroup: "animal-mammal", version: 3.0, shortcodes: &["gorilla"] },
+    EmojiInfo { emoji: "\u{1f9a7}", name: "orangutan", group: "Animals & Nature", subgroup: "animal-mammal", version: 12.0, shortcodes: &["orangutan"] },
+    EmojiInfo { emoji: "\u{1f436}", name: "dog face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["dog"] },
+    EmojiInfo { emoji: "\u{1f415}", name: "dog", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.7, shortcodes: &["dog2"] },
+    EmojiInfo { emoji: "\u{1f9ae}", name: "guide dog", group: "Animals & Nature", subgroup: "animal-mammal", version: 12.0, shortcodes: &["guide_dog"] },
+    EmojiInfo { emoji: "\u{1f415}\u{200d}\u{1f9ba}", name: "service dog", group: "Animals & Nature", subgroup: "animal-mammal", version: 12.0, shortcodes: &["service_dog"] },
+    EmojiInfo { emoji: "\u{1f429}", name: "poodle", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["poodle"] },
+    EmojiInfo { emoji: "\u{1f43a}", name: "wolf", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["wolf"] },
+    EmojiInfo { emoji: "\u{1f98a}", name: "fox", group: "Animals & Nature", subgroup: "animal-mammal", version: 3.0, shortcodes: &["fox_face"] },
+    EmojiInfo { emoji: "\u{1f99d}", name: "raccoon", group: "Animals & Nature", subgroup: "animal-mammal", version: 11.0, shortcodes: &["raccoon"] },
+    EmojiInfo { emoji: "\u{1f431}", name: "cat face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["cat"] },
+    EmojiInfo { emoji: "\u{1f408}", name: "cat", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.7, shortcodes: &["cat2"] },
+    EmojiInfo { emoji: "\u{1f408}\u{200d}\u{2b1b}", name: "black cat", group: "Animals & Nature", subgroup: "animal-mammal", version: 13.0, shortcodes: &["black_cat"] },
+    EmojiInfo { emoji: "\u{1f981}", name: "lion", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["lion"] },
+    EmojiInfo { emoji: "\u{1f42f}", name: "tiger face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["tiger"] },
+    EmojiInfo { emoji: "\u{1f405}", name: "tiger", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["tiger2"] },
+    EmojiInfo { emoji: "\u{1f406}", name: "leopard", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["leopard"] },
+    EmojiInfo { emoji: "\u{1f434}", name: "horse face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["horse"] },
+    EmojiInfo { emoji: "\u{1f40e}", name: "horse", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["racehorse"] },
+    EmojiInfo { emoji: "\u{1f984}", name: "unicorn", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["unicorn"] },
+    EmojiInfo { emoji: "\u{1f993}", name: "zebra", group: "Animals & Nature", subgroup: "animal-mammal", version: 5.0, shortcodes: &["zebra"] },
+    EmojiInfo { emoji: "\u{1f98c}", name: "deer", group: "Animals & Nature", subgroup: "animal-mammal", version: 3.0, shortcodes: &["deer"] },
+    EmojiInfo { emoji: "\u{1f9ac}", name: "bison", group: "Animals & Nature", subgroup: "animal-mammal", version: 13.0, shortcodes: &["bison"] },
+    EmojiInfo { emoji: "\u{1f42e}", name: "cow face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["cow"] },
+    EmojiInfo { emoji: "\u{1f402}", name: "ox", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["ox"] },
+    EmojiInfo { emoji: "\u{1f403}", name: "water buffalo", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["water_buffalo"] },
+    EmojiInfo { emoji: "\u{1f404}", name: "cow", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["cow2"] },
+    EmojiInfo { emoji: "\u{1f437}", name: "pig face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["pig"] },
+    EmojiInfo { emoji: "\u{1f416}", name: "pig", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["pig2"] },
+    EmojiInfo { emoji: "\u{1f417}", name: "boar", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["boar"] },
+    EmojiInfo { emoji: "\u{1f43d}", name: "pig nose", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["pig_nose"] },
+    EmojiInfo { emoji: "\u{1f40f}", name: "ram", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["ram"] },
+    EmojiInfo { emoji: "\u{1f411}", name: "ewe", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["sheep"] },
+    EmojiInfo { emoji: "\u{1f410}", name: "goat", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["goat"] },
+    EmojiInfo { emoji: "\u{1f42a}", name: "camel", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["dromedary_camel"] },
+    EmojiInfo { emoji: "\u{1f42b}", name: "two-hump camel", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["camel"] },
+    EmojiInfo { emoji: "\u{1f999}", name: "llama", group: "Animals & Nature", subgroup: "animal-mammal", version: 11.0, shortcodes: &["llama"] },
+    EmojiInfo { emoji: "\u{1f992}", name: "giraffe", group: "Animals & Nature", subgroup: "animal-mammal", version: 5.0, shortcodes: &["giraffe"] },
+    EmojiInfo { emoji: "\u{1f418}", name: "elephant", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["elephant"] },
+    EmojiInfo { emoji: "\u{1f9a3}", name: "mammoth", group: "Animals & Nature", subgroup: "animal-mammal", version: 13.0, shortcodes: &["mammoth"] },
+    EmojiInfo { emoji: "\u{1f98f}", name: "rhinoceros", group: "Animals & Nature", subgroup: "animal-mammal", version: 3.0, shortcodes: &["rhinoceros"] },
+    EmojiInfo { emoji: "\u{1f99b}", name: "hippopotamus", group: "Animals & Nature", subgroup: "animal-mammal", version: 11.0, shortcodes: &["hippopotamus"] },
+    EmojiInfo { emoji: "\u{1f42d}", name: "mouse face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["mouse"] },
+    EmojiInfo { emoji: "\u{1f401}", name: "mouse", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["mouse2"] },
+    EmojiInfo { emoji: "\u{1f400}", name: "rat", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["rat"] },
+    EmojiInfo { emoji: "\u{1f439}", name: "hamster", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["hamster"] },
+    EmojiInfo { emoji: "\u{1f430}", name: "rabbit face", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["rabbit"] },
+    EmojiInfo { emoji: "\u{1f407}", name: "rabbit", group: "Animals & Nature", subgroup: "animal-mammal", version: 1.0, shortcodes: &["rabbit2"] },
+    EmojiInfo { emoji: "\u{1f43f}\u{fe0f}", name: "chipmunk", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.7, shortcodes: &["chipmunk"] },
+    EmojiInfo { emoji: "\u{1f9ab}", name: "beaver", group: "Animals & Nature", subgroup: "animal-mammal", version: 13.0, shortcodes: &["beaver"] },
+    EmojiInfo { emoji: "\u{1f994}", name: "hedgehog", group: "Animals & Nature", subgroup: "animal-mammal", version: 5.0, shortcodes: &["hedgehog"] },
+    EmojiInfo { emoji: "\u{1f987}", name: "bat", group: "Animals & Nature", subgroup: "animal-mammal", version: 3.0, shortcodes: &["bat"] },
+    EmojiInfo { emoji: "\u{1f43b}", name: "bear", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["bear"] },
+    EmojiInfo { emoji: "\u{1f43b}\u{200d}\u{2744}\u{fe0f}", name: "polar bear", group: "Animals & Nature", subgroup: "animal-mammal", version: 13.0, shortcodes: &["polar_bear"] },
+    EmojiInfo { emoji: "\u{1f428}", name: "koala", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["koala"] },
+    EmojiInfo { emoji: "\u{1f43c}", name: "panda", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["panda_face"] },
+    EmojiInfo { emoji: "\u{1f9a5}", name: "sloth", group: "Animals & Nature", subgroup: "animal-mammal", version: 12.0, shortcodes: &["sloth"] },
+    EmojiInfo { emoji: "\u{1f9a6}", name: "otter", group: "Animals & Nature", subgroup: "animal-mammal", version: 12.0, shortcodes: &["otter"] },
+    EmojiInfo { emoji: "\u{1f9a8}", name: "skunk", group: "Animals & Nature", subgroup: "animal-mammal", version: 12.0, shortcodes: &["skunk"] },
+    EmojiInfo { emoji: "\u{1f998}", name: "kangaroo", group: "Animals & Nature", subgroup: "animal-mammal", version: 11.0, shortcodes: &["kangaroo"] },
+    EmojiInfo { emoji: "\u{1f9a1}", name: "badger", group: "Animals & Nature", subgroup: "animal-mammal", version: 11.0, shortcodes: &["badger"] },
+    EmojiInfo { emoji: "\u{1f43e}", name: "paw prints", group: "Animals & Nature", subgroup: "animal-mammal", version: 0.6, shortcodes: &["feet", "paw_prints"] },
+    EmojiInfo { emoji: "\u{1f983}", name: "turkey", group: "Animals & Nature", subgroup: "animal-bird", version: 1.0, shortcodes: &["turkey"] },
+    EmojiInfo { emoji: "\u{1f414}", name: "chicken", group: "Animals & Nature", subgroup: "animal-bird", version: 0.6, shortcodes: &["chicken"] },
+    EmojiInfo { emoji: "\u{1f413}", name: "rooster", group: "Animals & Nature", subgroup: "animal-bird", version: 1.0, shortcodes: &["rooster"] },
+    EmojiInfo { emoji: "\u{1f423}", name: "hatching chick", group: "Animals & Nature", subgroup: "animal-bird", version: 0.6, shortcodes: &["hatching_chick"] },
+    EmojiInfo { emoji: "\u{1f424}", name: "baby chick", group: "Animals & Nature", subgroup: "animal-bird", version: 0.6, shortcodes: &["baby_chick"] },
+    EmojiInfo { emoji: "\u{1f425}", name: "front-facing baby chick", group: "Animals & Nature", subgroup: "animal-bird", version: 0.6, shortcodes: &["hatched_chick"] },
+    EmojiInfo { emoji: "\u{1f426}", name: "bird", group: "Animals & Nature", subgroup: "animal-bird", version: 0.6, shortcodes: &["bird"] },
+    EmojiInfo { emoji: "\u{1f427}", name: "penguin", group: "Animals & Nature", subgroup: "animal-bird", version: 0.6, shortcodes: &["penguin"] },
+    EmojiInfo { emoji: "\u{1f54a}\u{fe0f}", name: "dove", group: "Animals & Nature", subgroup: "animal-bird", version: 0.7, shortcodes: &["dove"] },
+    EmojiInfo { emoji: "\u{1f985}", name: "eagle", group: "Animals & Nature", subgroup: "animal-bird", version: 3.0, shortcodes: &["eagle"] },
+    EmojiInfo { emoji: "\u{1f986}", name: "duck", group: "Animals & Nature", subgroup: "animal-bird", version: 3.0, shortcodes: &["duck"] },
+    EmojiInfo { emoji: "\u{1f9a2}", name: "swan", group: "Animals & Nature", subgroup: "animal-bird", version: 11.0, shortcodes: &["swan"] },
+    EmojiInfo { emoji: "\u{1f989}", name: "owl", group: "Animals & Nature", subgroup: "animal-bird", version: 3.0, shortcodes: &["owl"] },
+    EmojiInfo { emoji: "\u{1f9a4}", name: "dodo", group: "Animals & Nature", subgroup: "animal-bird", version: 13.0, shortcodes: &["dodo"] },
+    EmojiInfo { emoji: "\u{1fab6}", name: "feather", group: "Animals & Nature", subgroup: "animal-bird", version: 13.0, shortcodes: &["feather"] },
+    EmojiInfo { emoji: "\u{1f9a9}", name: "flamingo", group: "Animals & Nature", subgroup: "animal-bird", version: 12.0, shortcodes: &["flamingo"] },
+    EmojiInfo { emoji: "\u{1f99a}", name: "peacock", group: "Animals & Nature", subgroup: "animal-bird", version: 11.0, shortcodes: &["peacock"] },
+    EmojiInfo { emoji: "\u{1f99c}", name: "parrot", group: "Animals & Nature", subgroup: "animal-bird", version: 11.0, shortcodes: &["parrot"] },
+    EmojiInfo { emoji: "\u{1f438}", name: "frog", group: "Animals & Nature", subgroup: "animal-amphibian", version: 0.6, shortcodes: &["frog"] },
+    EmojiInfo { emoji: "\u{1f40a}", name: "crocodile", group: "Animals & Nature", subgroup: "animal-reptile", version: 1.0, shortcodes: &["crocodile"] },
+    EmojiInfo { emoji: "\u{1f422}", name: "turtle", group: "Animals & Nature", subgroup: "animal-reptile", version: 0.6, shortcodes: &["turtle"] },
+    EmojiInfo { emoji: "\u{1f98e}", name: "lizard", group: "Animals & Nature", subgroup: "animal-reptile", version: 3.0, shortcodes: &["lizard"] },
+    EmojiInfo { emoji: "\u{1f40d}", name: "snake", group: "Animals & Nature", subgroup: "animal-reptile", version: 0.6, shortcodes: &["snake"] },
+    EmojiInfo { emoji: "\u{1f432}", name: "dragon face", group: "Animals & Nature", subgroup: "animal-reptile", version: 0.6, shortcodes: &["dragon_face"] },
+    EmojiInfo { emoji: "\u{1f409}", name: "dragon", group: "Animals & Nature", subgroup: "animal-reptile", version: 1.0, shortcodes: &["dragon"] },
+    EmojiInfo { emoji: "\u{1f995}", name: "sauropod", group: "Animals & Nature", subgroup: "animal-reptile", version: 5.0, shortcodes: &["sauropod"] },
+    EmojiInfo { emoji: "\u{1f996}", name: "T-Rex", group: "Animals & Nature", subgroup: "animal-reptile", version: 5.0, shortcodes: &["t-rex"] },
+    EmojiInfo { emoji: "\u{1f433}", name: "spouting whale", group: "Animals & Nature", subgroup: "animal-marine", version: 0.6, shortcodes: &["whale"] },
+    EmojiInfo { emoji: "\u{1f40b}", name: "whale", group: "Animals & Nature", subgroup: "animal-marine", version: 1.0, shortcodes: &["whale2"] },
+    EmojiInfo { emoji: "\u{1f42c}", name: "dolphin", group: "Animals & Nature", subgroup: "animal-marine", version: 0.6, shortcodes: &["dolphin", "flipper"] },
+    EmojiInfo { emoji: "\u{1f9ad}", name: "seal", group: "Animals & Nature", subgroup: "animal-marine", version: 13.0, shortcodes: &["seal"] },
+    EmojiInfo { emoji: "\u{1f41f}", name: "fish", group: "Animals & Nature", subgroup: "animal-marine", version: 0.6, shortcodes: &["fish"] },
+    EmojiInfo { emoji: "\u{1f420}", name: "tropical fish", group: "Animals & Nature", subgroup: "animal-marine", version: 0.6, shortcodes: &["tropical_fish"] },
+    EmojiInfo { emoji: "\u{1f421}", name: "blowfish", group: "Animals & Nature", subgroup: "animal-marine", version: 0.6, shortcodes: &["blowfish"] },
+    EmojiInfo { emoji: "\u{1f988}", name: "shark", group: "Animals & Nature", subgroup: "animal-marine", version: 3.0, shortcodes: &["shark"] },
+    EmojiInfo { emoji: "\u{1f419}", name: "octopus", group: "Animals & Nature", subgroup: "animal-marine", version: 0.6, shortcodes: &["octopus"] },
+    EmojiInfo { emoji: "\u{1f41a}", name: "spiral shell", group: "Animals & Nature", subgroup: "animal-marine", version: 0.6, shortcodes: &["shell"] },
+    EmojiInfo { emoji: "\u{1f40c}", name: "snail", group: "Animals & Nature", subgroup: "animal-bug", version: 0.6, shortcodes: &["snail"] },
+    EmojiInfo { emoji: "\u{1f98b}", name: "butterfly", group: "Animals & Nature", subgroup: "animal-bug", version: 3.0, shortcodes: &["butterfly"] },
+    EmojiInfo { emoji: "\u{1f41b}", name: "bug", group: "Animals & Nature", subgroup: "animal-bug", version: 0.6, shortcodes: &["bug"] },
+    EmojiInfo { emoji: "\u{1f41c}", name: "ant", group: "Animals & Nature", subgroup: "animal-bug", version: 0.6, shortcodes: &["ant"] },
+    EmojiInfo { emoji: "\u{1f41d}", name: "honeybee", group: "Animals & Nature", subgroup: "animal-bug", version: 0.6, shortcodes: &["bee", "honeybee"] },
+    EmojiInfo { emoji: "\u{1fab2}", name: "beetle", group: "Animals & Nature", subgroup: "animal-bug", version: 13.0, shortcodes: &["beetle"] },
+    EmojiInfo { emoji: "\u{1f41e}", name: "lady beetle", group: "Animals & Nature", subgroup: "animal-bug", version: 0.6, shortcodes: &["lady_beetle"] },
+    EmojiInfo { emoji: "\u{1f997}", name: "cricket", group: "Animals & Nature", subgroup: "animal-bug", version: 5.0, shortcodes: &["cricket"] },
+    EmojiInfo { emoji: "\u{1fab3}", name: "cockroach", group: "Animals & Nature", subgroup: "animal-bug", version: 13.0, shortcodes: &["cockroach"] },
+    EmojiInfo { emoji: "\u{1f577}\u{fe0f}", name: "spider", group: "Animals & Nature", subgroup: "animal-bug", version: 0.7, shortcodes: &["spider"] },
+    EmojiInfo { emoji: "\u{1f578}\u{fe0f}", name: "spider web", group: "Animals & Nature", subgroup: "animal-bug", version: 0.7, shortcodes: &["spider_web"] },
+    EmojiInfo { emoji: "\u{1f982}", name: "scorpion", group: "Animals & Nature", subgroup: "animal-bug", version: 1.0, shortcodes: &["scorpion"] },
+    EmojiInfo { emoji: "\u{1f99f}", name: "mosquito", group: "Animals & Nature", subgroup: "animal-bug", version: 11.0, shortcodes: &["mosquito"] },
+    EmojiInfo { emoji: "\u{1fab0}", name: "fly", group: "Animals & Nature", subgroup: "animal-bug", version: 13.0, shortcodes: &["fly"] },
+    EmojiInfo { emoji: "\u{1fab1}", name: "worm", group: "Animals & Nature", subgroup: "animal-bug", version: 13.0, shortcodes: &["worm"] },
+    EmojiInfo { emoji: "\u{1f9a0}", name: "microbe", group: "Animals & Nature", subgroup: "animal-bug", version: 11.0, shortcodes: &["microbe"] },
+    EmojiInfo { emoji: "\u{1f490}", name: "bouquet", group: "Animals & Nature", subgroup: "plant-flower", version: 0.6, shortcodes: &["bouquet"] },
+    EmojiInfo { emoji: "\u{1f338}", name: "cherry blossom", group: "Animals & Nature", subgroup: "plant-flower", version: 0.6, shortcodes: &["cherry_blossom"] },
+    EmojiInfo { emoji: "\u{1f4ae}", name: "white flower", group: "Animals & Nature", subgroup: "plant-flower", version: 0.6, shortcodes: &["white_flower"] },
+    EmojiInfo { emoji: "\u{1f3f5}\u{fe0f}", name: "rosette", group: "Animals & Nature", subgroup: "plant-flower", version: 0.7, shortcodes: &["rosette"] },
+    EmojiInfo { emoji: "\u{1f339}", name: "rose", group: "Animals & Nature", subgroup: "plant-flower", version: 0.6, shortcodes: &["rose"] },
+    EmojiInfo { emoji: "\u{1f940}", name: "wilted flower", group: "Animals & Nature", subgroup: "plant-flower", version: 3.0, shortcodes: &["wilted_flower"] },
+    EmojiInfo { emoji: "\u{1f33a}", name: "hibiscus", group: "Animals & Nature", subgroup: "plant-flower", version: 0.6, shortcodes: &["hibiscus"] },
+    EmojiInfo { emoji: "\u{1f33b}", name: "sunflower", group: "Animals & Nature", subgroup: "plant-flower", version: 0.6, shortcodes: &["sunflower"] },
+    EmojiInfo { emoji: "\u{1f33c}", name: "blossom", group: "Animals & Nature", subgroup: "plant-flower", version: 0.6, shortcodes: &["blossom"] },
+    EmojiInfo { emoji: "\u{1f337}", name: "tulip", group: "Animals & Nature", subgroup: "plant-flower", version: 0.6, shortcodes: &["tulip"] },
+    EmojiInfo { emoji: "\u{1f331}", name: "seedling", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["seedling"] },
+    EmojiInfo { emoji: "\u{1fab4}", name: "potted plant", group: "Animals & Nature", subgroup: "plant-other", version: 13.0, shortcodes: &["potted_plant"] },
+    EmojiInfo { emoji: "\u{1f332}", name: "evergreen tree", group: "Animals & Nature", subgroup: "plant-other", version: 1.0, shortcodes: &["evergreen_tree"] },
+    EmojiInfo { emoji: "\u{1f333}", name: "deciduous tree", group: "Animals & Nature", subgroup: "plant-other", version: 1.0, shortcodes: &["deciduous_tree"] },
+    EmojiInfo { emoji: "\u{1f334}", name: "palm tree", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["palm_tree"] },
+    EmojiInfo { emoji: "\u{1f335}", name: "cactus", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["cactus"] },
+    EmojiInfo { emoji: "\u{1f33e}", name: "sheaf of rice", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["ear_of_rice"] },
+    EmojiInfo { emoji: "\u{1f33f}", name: "herb", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["herb"] },
+    EmojiInfo { emoji: "\u{2618}\u{fe0f}", name: "shamrock", group: "Animals & Nature", subgroup: "plant-other", version: 1.0, shortcodes: &["shamrock"] },
+    EmojiInfo { emoji: "\u{1f340}", name: "four leaf clover", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["four_leaf_clover"] },
+    EmojiInfo { emoji: "\u{1f341}", name: "maple leaf", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["maple_leaf"] },
+    EmojiInfo { emoji: "\u{1f342}", name: "fallen leaf", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["fallen_leaf"] },
+    EmojiInfo { emoji: "\u{1f343}", name: "leaf fluttering in wind", group: "Animals & Nature", subgroup: "plant-other", version: 0.6, shortcodes: &["leaves"] },
+    EmojiInfo { emoji: "\u{1f347}", name: "grapes", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["grapes"] },
+    EmojiInfo { emoji: "\u{1f348}", name: "melon", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["melon"] },
+    EmojiInfo { emoji: "\u{1f349}", name: "watermelon", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["watermelon"] },
+    EmojiInfo { emoji: "\u{1f34a}", name: "tangerine", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["tangerine", "orange", "mandarin"] },
+    EmojiInfo { emoji: "\u{1f34b}", name: "lemon", group: "Food & Drink", subgroup: "food-fruit", version: 1.0, shortcodes: &["lemon"] },
+    EmojiInfo { emoji: "\u{1f34c}", name: "banana", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["banana"] },
+    EmojiInfo { emoji: "\u{1f34d}", name: "pineapple", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["pineapple"] },
+    EmojiInfo { emoji: "\u{1f96d}", name: "mango", group: "Food & Drink", subgroup: "food-fruit", version: 11.0, shortcodes: &["mango"] },
+    EmojiInfo { emoji: "\u{1f34e}", name: "red apple", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["apple"] },
+    EmojiInfo { emoji: "\u{1f34f}", name: "green apple", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["green_apple"] },
+    EmojiInfo { emoji: "\u{1f350}", name: "pear", group: "Food & Drink", subgroup: "food-fruit", version: 1.0, shortcodes: &["pear"] },
+    EmojiInfo { emoji: "\u{1f351}", name: "peach", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["peach"] },
+    EmojiInfo { emoji: "\u{1f352}", name: "cherries", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["cherries"] },
+    EmojiInfo { emoji: "\u{1f353}", name: "strawberry", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["strawberry"] },
+    EmojiInfo { emoji: "\u{1fad0}", name: "blueberries", group: "Food & Drink", subgroup: "food-fruit", version: 13.0, shortcodes: &["blueberries"] },
+    EmojiInfo { emoji: "\u{1f95d}", name: "kiwi fruit", group: "Food & Drink", subgroup: "food-fruit", version: 3.0, shortcodes: &["kiwi_fruit"] },
+    EmojiInfo { emoji: "\u{1f345}", name: "tomato", group: "Food & Drink", subgroup: "food-fruit", version: 0.6, shortcodes: &["tomato"] },
+    EmojiInfo { emoji: "\u{1fad2}", name: "olive", group: "Food & Drink", subgroup: "food-fruit", version: 13.0, shortcodes: &["olive"] },
+    EmojiInfo { emoji: "\u{1f965}", name: "coconut", group: "Food & Drink", subgroup: "food-fruit", version: 5.0, shortcodes: &["coconut"] },
+    EmojiInfo { emoji: "\u{1f951}", name: "avocado", group: "Food & Drink", subgroup: "food-vegetable", version: 3.0, shortcodes: &["avocado"] },
+    EmojiInfo { emoji: "\u{1f346}", name: "eggplant", group: "Food & Drink", subgroup: "food-vegetable", version: 0.6, shortcodes: &["eggplant"] },
+    EmojiInfo { emoji: "\u{1f954}", name: "potato", group: "Food & Drink", subgroup: "food-vegetable", version: 3.0, shortcodes: &["potato"] },
+    EmojiInfo { emoji: "\u{1f955}", name: "carrot", group: "Food & Drink", subgroup: "food-vegetable", version: 3.0, shortcodes: &["carrot"] },
+    EmojiInfo { emoji: "\u{1f33d}", name: "ear of corn", group: "Food & Drink", subgroup: "food-vegetable", version: 0.6, shortcodes: &["corn"] },
+    EmojiInfo { emoji: "\u{1f336}\u{fe0f}", name: "hot pepper", group: "Food & Drink", subgroup: "food-vegetable", version: 0.7, shortcodes: &["hot_pepper"] },
+    EmojiInfo { emoji: "\u{1fad1}", name: "bell pepper", group: "Food & Drink", subgroup: "food-vegetable", version: 13.0, shortcodes: &["bell_pepper"] },
+    EmojiInfo { emoji: "\u{1f952}", name: "cucumber", group: "Food & Drink", subgroup: "food-vegetable", version: 3.0, shortcodes: &["cucumber"] },
+    EmojiInfo { emoji: "\u{1f96c}", name: "leafy green", group: "Food & Drink", subgroup: "food-vegetable", version: 11.0, shortcodes: &["leafy_green"] },
+    EmojiInfo { emoji: "\u{1f966}", name: "broccoli", group: "Food & Drink", subgroup: "food-vegetable", version: 5.0, shortcodes: &["broccoli"] },
+    EmojiInfo { emoji: "\u{1f9c4}", name: "garlic", group: "Food & Drink", subgroup: "food-vegetable", version: 12.0, shortcodes: &["garlic"] },
+    EmojiInfo { emoji: "\u{1f9c5}", name: "onion", group: "Food & Drink", subgroup: "food-vegetable", version: 12.0, shortcodes: &["onion"] },
+    EmojiInfo { emoji: "\u{1f344}", name: "mushroom", group: "Food & Drink", subgroup: "food-vegetable", version: 0.6, shortcodes: &["mushroom"] },
+    EmojiInfo { emoji: "\u{1f95c}", name: "peanuts", group: "Food & Drink", subgroup: "food-vegetable", version: 3.0, shortcodes: &["peanuts"] },
+    EmojiInfo { emoji: "\u{1f330}", name: "chestnut", group: "Food & Drink", subgroup: "food-vegetable", version: 0.6, shortcodes: &["chestnut"] },
+    EmojiInfo { emoji: "\u{1f35e}", name: "bread", group: "Food & Drink", subgroup: "food-prepared", version: 0.6, shortcodes: &["bread"] },
+    EmojiInfo { emoji: "\u{1f950}", name: "croissant", group: "Food & Drink", subgroup: "food-prepared", version: 3.0, shortcodes: &["croissant"] },
+    EmojiInfo { emoji: "\u{1f956}", name: "baguette bread", group: "Food & Drink", subgroup: "food-prepared", version: 3.0, shortcodes: &["baguette_bread"] },
+    EmojiInfo { emoji: "\u{1fad3}", name: "flatbread", group: "Food & Drink", subgroup: "food-prepared", version: 13.0, shortcodes: &["flatbread"] },
+    EmojiInfo { emoji: "\u{1f968}", name: "pretzel", group: "Food & Drink", subgroup: "food-prepared", version: 5.0, shortcodes: &["pretzel"] },
+    EmojiInfo { emoji: "\u{1f96f}", name: "bagel", group: "Food & Drink", subgroup: "food-prepared", version: 11.0, shortcodes: &["bagel"] },
+    EmojiInfo { emoji: "\u{1f95e}", name: "pancakes", group: "Food & Drink", subgroup: "food-prepared", version: 3.0, shortcodes: &["pancakes"] },
+    EmojiInfo { emoji: "\u{1f9c7}", name: "waffle", group: "Food & Drink", subgroup: "food-prepared", version: 12.0, shortcodes: &["waffle"] },
+    EmojiInfo { emoji: "\u{1f9c0}", name: "cheese wedge", group: "Food & Drink", subgroup: "food-prepared", version: 1.0, shortcodes: &["cheese"] },
+    EmojiInfo { emoji: "\u{1f356}", name: "meat on bone", group: "Food & Drink", subgroup: "food-prepared", version: 0.6, shortcodes: &["meat_on_bone"] },
+    EmojiInfo { emoji: "\u{1f357}", name: "poultry leg", group: "Food & Drink", subgroup: "food-prepared", version: 0.6, shortcodes: &["poultry_leg"] },
+    EmojiInfo { emoji: "\u{1f969}", name: "cut of meat", group: "Food & Drink", subgroup: "food-prepared", version: 5.0, shortcodes: &["cut_of_meat"] },
+    EmojiInfo { emoji: "\u{1f953}", name: "bacon", group: "Food & Drink", subgroup: "food-prepared", version: 3.0, shortcodes: &["bacon"] },
+    EmojiInfo { emoji: "\u{1f354}", name: "hamburger", group: "Food & Drink", subgroup: "food-prepared", version: 0.6, shortcodes: &["hamburger"] },
+    EmojiInfo { emoji: "\u{1f35f}", name: "french fries", group: "Food & Drink", subgroup: "food-prepared", version: 0.6, shortcodes: &["fries"] },
+    EmojiInfo { emoji: "\u{1f355}", name: "pizza", group: "Food & Drink", subgroup: "food-prepared", version: 0.6, shortcodes: &["pizza"] },
+    EmojiInfo { emoji: "\u{1f32d}", name: "hot dog", group: "Food & Drink", subgroup: "food-prepared", version: 1.0, shortcodes: &["hotdog"] },
+    EmojiInfo { emoji: "\u{1f96a}", name: "sandwich", group: "Food & Drink", subgroup: "food-prepared", version: 5.0, shortcodes: &["sandwich"] },
+    EmojiInfo { emoji: "\u{1f32e}", name: "taco", group: "Food & Drink", subgroup: "food-prepared", version: 1.0, shortcodes: &["taco"] },
+    EmojiInfo { emoji: "\u{1f32f}", name: "burrito", group: "Food & Drink", subgroup: "food-prepared", version: 1.0, shortcodes: &["burrito"] },
+    EmojiInfo { emoji: "\u{1fad4}", name: "tamale", group: "Food & Drink", subgroup: "food-prepared", version: 13.0, shortcodes: &["tamale"] },
+    EmojiInfo { emoji: "\u{1f959}", name: "stuffed flatbread", group: "Food & Drink", subgroup: "food-prepared", version: 3.0, shortcodes: &["stuffed_flatbread"] },
+    EmojiInfo { emoji: "\u{1f9c6}", name: "falafel", group: "Food & Drink", subgroup: "food-prepared", version: 12.0, shortcodes: &["falafel"] },
+    EmojiInfo { emoji: "\u{1f95a}", name: "egg", group: "Food & Drink", subgroup: "food-prepared", version: 3.0, shortcodes: &["egg"] },
+    EmojiInfo { emoji: "\u{1f373}", name: "cooking", group: "Food & Drink", subgroup: "food-prepared", version: 0.6, shortcodes: &["fried_egg"] },
+    EmojiInfo { emoji: "\u{1f958}", name: "shallow pan of food", group: "Food & Drink", subgroup: "food-prepared", version: 3.0, shortcodes: &["shallow_pan_of_food"] },
+    EmojiInfo { emoji: "\u{1f372}", name: "pot of food", group: "Food & Drink", subgroup: "food-prepared", version: 0.6, shortcodes: &["stew"] },
+    EmojiInfo { emoji: "\u{1fad5}", name: "fondue", group: "Food & Drink", subgroup: "food-prepared", version: 13.0, shortcodes: &["fondue"] },
+    EmojiInfo { emoji: "\u{1f963}", name: "bowl with spoon", group: "Food & Drink", subgroup: "food-prepared", version: 5.0, shortcodes: &["bowl_with_spoon"] },
+    EmojiInfo { emoji: "\u{1f957}", name: "green salad", group: "Food & Drink", subgroup: "food-prepared", version: 3.0, shortcodes: &["green_salad"] },
+    EmojiInfo { emoji: "\u{1f37f}", name: "popcorn", group: "Food & Drink", subgroup: "food-prepared", version: 1.0, shortcodes: &["popcorn"] },
+    EmojiInfo { emoji: "\u{1f9c8}", name: "butter", group: "Food & Drink", subgroup: "food-prepared", version: 12.0, shortcodes: &["butter"] },
+    EmojiInfo { emoji: "\u{1f9c2}", name: "salt", group: "Food & Drink", subgroup: "food-prepared", version: 11.0, shortcodes: &["salt"] },
+    EmojiInfo { emoji: "\u{1f96b}", name: "canned food", group: "Food & Drink", subgroup: "food-prepared", version: 5.0, shortcodes: &["canned_food"] },
+    EmojiInfo { emoji: "\u{1f371}", name: "bento box", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["bento"] },
+    EmojiInfo { emoji: "\u{1f358}", name: "rice cracker", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["rice_cracker"] },
+    EmojiInfo { emoji: "\u{1f359}", name: "rice ball", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["rice_ball"] },
+    EmojiInfo { emoji: "\u{1f35a}", name: "cooked rice", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["rice"] },
+    EmojiInfo { emoji: "\u{1f35b}", name: "curry rice", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["curry"] },
+    EmojiInfo { emoji: "\u{1f35c}", name: "steaming bowl", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["ramen"] },
+    EmojiInfo { emoji: "\u{1f35d}", name: "spaghetti", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["spaghetti"] },
+    EmojiInfo { emoji: "\u{1f360}", name: "roasted sweet potato", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["sweet_potato"] },
+    EmojiInfo { emoji: "\u{1f362}", name: "oden", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["oden"] },
+    EmojiInfo { emoji: "\u{1f363}", name: "sushi", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["sushi"] },
+    EmojiInfo { emoji: "\u{1f364}", name: "fried shrimp", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["fried_shrimp"] },
+    EmojiInfo { emoji: "\u{1f365}", name: "fish cake with swirl", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["fish_cake"] },
+    EmojiInfo { emoji: "\u{1f96e}", name: "moon cake", group: "Food & Drink", subgroup: "food-asian", version: 11.0, shortcodes: &["moon_cake"] },
+    EmojiInfo { emoji: "\u{1f361}", name: "dango", group: "Food & Drink", subgroup: "food-asian", version: 0.6, shortcodes: &["dango"] },
+    EmojiInfo { emoji: "\u{1f95f}", name: "dumpling", group: "Food & Drink", subgroup: "food-asian", version: 5.0, shortcodes: &["dumpling"] },
+    EmojiInfo { emoji: "\u{1f960}", name: "fortune cookie", group: "Food & Drink", subgroup: "food-asian", version: 5.0, shortcodes: &["fortune_cookie"] },
+    EmojiInfo { emoji: "\u{1f961}", name: "takeout box", group: "Food & Drink", subgroup: "food-asian", version: 5.0, shortcodes: &["takeout_box"] },
+    EmojiInfo { emoji: "\u{1f980}", name: "crab", group: "Food & Drink", subgroup: "food-marine", version: 1.0, shortcodes: &["crab"] },
+    EmojiInfo { emoji: "\u{1f99e}", name: "lobster", group: "Food & Drink", subgroup: "food-marine", version: 11.0, shortcodes: &["lobster"] },
+    EmojiInfo { emoji: "\u{1f990}", name: "shrimp", group: "Food & Drink", subgroup: "food-marine", version: 3.0, shortcodes: &["shrimp"] },
+    EmojiInfo { emoji: "\u{1f991}", name: "squid", group: "Food & Drink", subgroup: "food-marine", version: 3.0, shortcodes: &["squid"] },
+    EmojiInfo { emoji: "\u{1f9aa}", name: "oyster", group: "Food & Drink", subgroup: "food-marine", version: 12.0, shortcodes: &["oyster"] },
+    EmojiInfo { emoji: "\u{1f366}", name: "soft ice cream", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["icecream"] },
+    EmojiInfo { emoji: "\u{1f367}", name: "shaved ice", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["shaved_ice"] },
+    EmojiInfo { emoji: "\u{1f368}", name: "ice cream", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["ice_cream"] },
+    EmojiInfo { emoji: "\u{1f369}", name: "doughnut", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["doughnut"] },
+    EmojiInfo { emoji: "\u{1f36a}", name: "cookie", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["cookie"] },
+    EmojiInfo { emoji: "\u{1f382}", name: "birthday cake", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["birthday"] },
+    EmojiInfo { emoji: "\u{1f370}", name: "shortcake", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["cake"] },
+    EmojiInfo { emoji: "\u{1f9c1}", name: "cupcake", group: "Food & Drink", subgroup: "food-sweet", version: 11.0, shortcodes: &["cupcake"] },
+    EmojiInfo { emoji: "\u{1f967}", name: "pie", group: "Food & Drink", subgroup: "food-sweet", version: 5.0, shortcodes: &["pie"] },
+    EmojiInfo { emoji: "\u{1f36b}", name: "chocolate bar", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["chocolate_bar"] },
+    EmojiInfo { emoji: "\u{1f36c}", name: "candy", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["candy"] },
+    EmojiInfo { emoji: "\u{1f36d}", name: "lollipop", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["lollipop"] },
+    EmojiInfo { emoji: "\u{1f36e}", name: "custard", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["custard"] },
+    EmojiInfo { emoji: "\u{1f36f}", name: "honey pot", group: "Food & Drink", subgroup: "food-sweet", version: 0.6, shortcodes: &["honey_pot"] },
+    EmojiInfo { emoji: "\u{1f37c}", name: "baby bottle", group: "Food & Drink", subgroup: "drink", version: 1.0, shortcodes: &["baby_bottle"] },
+    EmojiInfo { emoji: "\u{1f95b}", name: "glass of milk", group: "Food & Drink", subgroup: "drink", version: 3.0, shortcodes: &["milk_glass"] },
+    EmojiInfo { emoji: "\u{2615}", name: "hot beverage", group: "Food & Drink", subgroup: "drink", version: 0.6, shortcodes: &["coffee"] },
+    EmojiInfo { emoji: "\u{1fad6}", name: "teapot", group: "Food & Drink", subgroup: "drink", version: 13.0, shortcodes: &["teapot"] },
+    EmojiInfo { emoji: "\u{1f375}", name: "teacup without handle", group: "Food & Drink", subgroup: "drink", version: 0.6, shortcodes: &["tea"] },
+    EmojiInfo { emoji: "\u{1f376}", name: "sake", group: "Food & Drink", subgroup: "drink", version: 0.6, shortcodes: &["sake"] },
+    EmojiInfo { emoji: "\u{1f37e}", name: "bottle with popping cork", group: "Food & Drink", subgroup: "drink", version: 1.0, shortcodes: &["champagne"] },
+    EmojiInfo { emoji: "\u{1f377}", name: "wine glass", group: "Food & Drink", subgroup: "drink", version: 0.6, shortcodes: &["wine_glass"] },
+    EmojiInfo { emoji: "\u{1f378}", name: "cocktail glass", group: "Food & Drink", subgroup: "drink", version: 0.6, shortcodes: &["cocktail"] },
+    EmojiInfo { emoji: "\u{1f379}", name: "tropical drink", group: "Food & Drink", subgroup: "drink", version: 0.6, shortcodes: &["tropical_drink"] },
+    EmojiInfo { emoji: "\u{1f37a}", name: "beer mug", group: "Food & Drink", subgroup: "drink", version: 0.6, shortcodes: &["beer"] },
+    EmojiInfo { emoji: "\u{1f37b}", name: "clinking beer mugs", group: "Food & Drink", subgroup: "drink", version: 0.6, shortcodes: &["beers"] },
+    EmojiInfo { emoji: "\u{1f942}", name: "clinking glasses", group: "Food & Drink", subgroup: "drink", version: 3.0, shortcodes: &["clinking_glasses"] },
+    EmojiInfo { emoji: "\u{1f943}", name: "tumbler glass", group: "Food & Drink", subgroup: "drink", version: 3.0, shortcodes: &["tumbler_glass"] },
+    EmojiInfo { emoji: "\u{1f964}", name: "cup with straw", group: "Food & Drink", subgroup: "drink", version: 5.0, shortcodes: &["cup_with_straw"] },
+    EmojiInfo { emoji: "\u{1f9cb}", name: "bubble tea", group: "Food & Drink", subgroup: "drink", version: 13.0, shortcodes: &["bubble_tea"] },
+    EmojiInfo { emoji: "\u{1f9c3}", name: "beverage box", group: "Food & Drink", subgroup: "drink", version: 12.0, shortcodes: &["beverage_box"] },
+    EmojiInfo { emoji: "\u{1f9c9}", name: "mate", group: "Food & Drink", subgroup: "drink", version: 12.0, shortcodes: &["mate"] },
+    EmojiInfo { emoji: "\u{1f9ca}", name: "ice", group: "Food & Drink", subgroup: "drink", version: 12.0, shortcodes: &["ice_cube"] },
+    EmojiInfo { emoji: "\u{1f962}", name: "chopsticks", group: "Food & Drink", subgroup: "dishware", version: 5.0, shortcodes: &["chopsticks"] },
+    EmojiInfo { emoji: "\u{1f37d}\u{fe0f}", name: "fork and knife with plate", group: "Food & Drink", subgroup: "dishware", version: 0.7, shortcodes: &["plate_with_cutlery"] },
+    EmojiInfo { emoji: "\u{1f374}", name: "fork and knife", group: "Food & Drink", subgroup: "dishware", version: 0.6, shortcodes: &["fork_and_knife"] },
+    EmojiInfo { emoji: "\u{1f944}", name: "spoon", group: "Food & Drink", subgroup: "dishware", version: 3.0, shortcodes: &["spoon"] },
+    EmojiInfo { emoji: "\u{1f52a}", name: "kitchen knife", group: "Food & Drink", subgroup: "dishware", version: 0.6, shortcodes: &["hocho", "knife"] },
+    EmojiInfo { emoji: "\u{1f3fa}", name: "amphora", group: "Food & Drink", subgroup: "dishware", version: 1.0, shortcodes: &["amphora"] },
+    EmojiInfo { emoji: "\u{1f30d}", name: "globe showing Europe-Africa", group: "Travel & Places", subgroup: "place-map", version: 0.7, shortcodes: &["earth_africa"] },
+    EmojiInfo { emoji: "\u{1f30e}", name: "globe showing Americas", group: "Travel & Places", subgroup: "place-map", version: 0.7, shortcodes: &["earth_americas"] },
+    EmojiInfo { emoji: "\u{1f30f}", name: "globe showing Asia-Australia", group: "Travel & Places", subgroup: "place-map", version: 0.6, shortcodes: &["earth_asia"] },
+    EmojiInfo { emoji: "\u{1f310}", name: "globe with meridians", group: "Travel & Places", subgroup: "place-map", version: 1.0, shortcodes: &["globe_with_meridians"] },
+    EmojiInfo { emoji: "\u{1f5fa}\u{fe0f}", name: "world map", group: "Travel & Places", subgroup: "place-map", version: 0.7, shortcodes: &["world_map"] },
+    EmojiInfo { emoji: "\u{1f5fe}", name: "map of Japan", group: "Travel & Places", subgroup: "place-map", version: 0.6, shortcodes: &["japan"] },
+    EmojiInfo { emoji: "\u{1f9ed}", name: "compass", group: "Travel & Places", subgroup: "place-map", version: 11.0, shortcodes: &["compass"] },
+    EmojiInfo { emoji: "\u{1f3d4}\u{fe0f}", name: "snow-capped mountain", group: "Travel & Places", subgroup: "place-geographic", version: 0.7, shortcodes: &["mountain_snow"] },
+    EmojiInfo { emoji: "\u{26f0}\u{fe0f}", name: "mountain", group: "Travel & Places", subgroup: "place-geographic", version: 0.7, shortcodes: &["mountain"] },
+    EmojiInfo { emoji: "\u{1f30b}", name: "volcano", group: "Travel & Places", subgroup: "place-geographic", version: 0.6, shortcodes: &["volcano"] },
+    EmojiInfo { emoji: "\u{1f5fb}", name: "mount fuji", group: "Travel & Places", subgroup: "place-geographic", version: 0.6, shortcodes: &["mount_fuji"] },
+    EmojiInfo { emoji: "\u{1f3d5}\u{fe0f}", name: "camping", group: "Travel & Places", subgroup: "place-geographic", version: 0.7, shortcodes: &["camping"] },
+    EmojiInfo { emoji: "\u{1f3d6}\u{fe0f}", name: "beach with umbrella", group: "Travel & Places", subgroup: "place-geographic", version: 0.7, shortcodes: &["beach_umbrella"] },
+    EmojiInfo { emoji: "\u{1f3dc}\u{fe0f}", name: "desert", group: "Travel & Places", subgroup: "place-geographic", version: 0.7, shortcodes: &["desert"] },
+    EmojiInfo { emoji: "\u{1f3dd}\u{fe0f}", name: "desert island", group: "Travel & Places", subgroup: "place-geographic", version: 0.7, shortcodes: &["desert_island"] },
+    EmojiInfo { emoji: "\u{1f3de}\u{fe0f}", name: "national park", group: "Travel & Places", subgroup: "place-geographic", version: 0.7, shortcodes: &["national_park"] },
+    EmojiInfo { emoji: "\u{1f3df}\u{fe0f}", name: "stadium", group: "Travel & Places", subgroup: "place-building", version: 0.7, shortcodes: &["stadium"] },
+    EmojiInfo { emoji: "\u{1f3db}\u{fe0f}", name: "classical building", group: "Travel & Places", subgroup: "place-building", version: 0.7, shortcodes: &["classical_building"] },
+    EmojiInfo { emoji: "\u{1f3d7}\u{fe0f}", name: "building construction", group: "Travel & Places", subgroup: "place-building", version: 0.7, shortcodes: &["building_construction"] },
+    EmojiInfo { emoji: "\u{1f9f1}", name: "brick", group: "Travel & Places", subgroup: "place-building", version: 11.0, shortcodes: &["bricks"] },
+    EmojiInfo { emoji: "\u{1faa8}", name: "rock", group: "Travel & Places", subgroup: "place-building", version: 13.0, shortcodes: &["rock"] },
+    EmojiInfo { emoji: "\u{1fab5}", name: "wood", group: "Travel & Places", subgroup: "place-building", version: 13.0, shortcodes: &["wood"] },
+    EmojiInfo { emoji: "\u{1f6d6}", name: "hut", group: "Travel & Places", subgroup: "place-building", version: 13.0, shortcodes: &["hut"] },
+    EmojiInfo { emoji: "\u{1f3d8}\u{fe0f}", name: "houses", group: "Travel & Places", subgroup: "place-building", version: 0.7, shortcodes: &["houses"] },
+    EmojiInfo { emoji: "\u{1f3da}\u{fe0f}", name: "derelict house", group: "Travel & Places", subgroup: "place-building", version: 0.7, shortcodes: &["derelict_house"] },
+    EmojiInfo { emoji: "\u{1f3e0}", name: "house", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["house"] },
+    EmojiInfo { emoji: "\u{1f3e1}", name: "house with garden", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["house_with_garden"] },
+    EmojiInfo { emoji: "\u{1f3e2}", name: "office building", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["office"] },
+    EmojiInfo { emoji: "\u{1f3e3}", name: "Japanese post office", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["post_office"] },
+    EmojiInfo { emoji: "\u{1f3e4}", name: "post office", group: "Travel & Places", subgroup: "place-building", version: 1.0, shortcodes: &["european_post_office"] },
+    EmojiInfo { emoji: "\u{1f3e5}", name: "hospital", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["hospital"] },
+    EmojiInfo { emoji: "\u{1f3e6}", name: "bank", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["bank"] },
+    EmojiInfo { emoji: "\u{1f3e8}", name: "hotel", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["hotel"] },
+    EmojiInfo { emoji: "\u{1f3e9}", name: "love hotel", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["love_hotel"] },
+    EmojiInfo { emoji: "\u{1f3ea}", name: "convenience store", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["convenience_store"] },
+    EmojiInfo { emoji: "\u{1f3eb}", name: "school", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["school"] },
+    EmojiInfo { emoji: "\u{1f3ec}", name: "department store", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["department_store"] },
+    EmojiInfo { emoji: "\u{1f3ed}", name: "factory", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["factory"] },
+    EmojiInfo { emoji: "\u{1f3ef}", name: "Japanese castle", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["japanese_castle"] },
+    EmojiInfo { emoji: "\u{1f3f0}", name: "castle", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["european_castle"] },
+    EmojiInfo { emoji: "\u{1f492}", name: "wedding", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["wedding"] },
+    EmojiInfo { emoji: "\u{1f5fc}", name: "Tokyo tower", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["tokyo_tower"] },
+    EmojiInfo { emoji: "\u{1f5fd}", name: "Statue of Liberty", group: "Travel & Places", subgroup: "place-building", version: 0.6, shortcodes: &["statue_of_liberty"] },
+    EmojiInfo { emoji: "\u{26ea}", name: "church", group: "Travel & Places", subgroup: "place-religious", version: 0.6, shortcodes: &["church"] },
+    EmojiInfo { emoji: "\u{1f54c}", name: "mosque", group: "Travel & Places", subgroup: "place-religious", version: 1.0, shortcodes: &["mosque"] },
+    EmojiInfo { emoji: "\u{1f6d5}", name: "hindu temple", group: "Travel & Places", subgroup: "place-religious", version: 12.0, shortcodes: &["hindu_temple"] },
+    EmojiInfo { emoji: "\u{1f54d}", name: "synagogue", group: "Travel & Places", subgroup: "place-religious", version: 1.0, shortcodes: &["synagogue"] },
+    EmojiInfo { emoji: "\u{26e9}\u{fe0f}", name: "shinto shrine", group: "Travel & Places", subgroup: "place-religious", version: 0.7, shortcodes: &["shinto_shrine"] },
+    EmojiInfo { emoji: "\u{1f54b}", name: "kaaba", group: "Travel & Places", subgroup: "place-religious", version: 1.0, shortcodes: &["kaaba"] },
+    EmojiInfo { emoji: "\u{26f2}", name: "fountain", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["fountain"] },
+    EmojiInfo { emoji: "\u{26fa}", name: "tent", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["tent"] },
+    EmojiInfo { emoji: "\u{1f301}", name: "foggy", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["foggy"] },
+    EmojiInfo { emoji: "\u{1f303}", name: "night with stars", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["night_with_stars"] },
+    EmojiInfo { emoji: "\u{1f3d9}\u{fe0f}", name: "cityscape", group: "Travel & Places", subgroup: "place-other", version: 0.7, shortcodes: &["cityscape"] },
+    EmojiInfo { emoji: "\u{1f304}", name: "sunrise over mountains", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["sunrise_over_mountains"] },
+    EmojiInfo { emoji: "\u{1f305}", name: "sunrise", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["sunrise"] },
+    EmojiInfo { emoji: "\u{1f306}", name: "cityscape at dusk", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["city_sunset"] },
+    EmojiInfo { emoji: "\u{1f307}", name: "sunset", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["city_sunrise"] },
+    EmojiInfo { emoji: "\u{1f309}", name: "bridge at night", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["bridge_at_night"] },
+    EmojiInfo { emoji: "\u{2668}\u{fe0f}", name: "hot springs", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["hotsprings"] },
+    EmojiInfo { emoji: "\u{1f3a0}", name: "carousel horse", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["carousel_horse"] },
+    EmojiInfo { emoji: "\u{1f3a1}", name: "ferris wheel", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["ferris_wheel"] },
+    EmojiInfo { emoji: "\u{1f3a2}", name: "roller coaster", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["roller_coaster"] },
+    EmojiInfo { emoji: "\u{1f488}", name: "barber pole", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["barber"] },
+    EmojiInfo { emoji: "\u{1f3aa}", name: "circus tent", group: "Travel & Places", subgroup: "place-other", version: 0.6, shortcodes: &["circus_tent"] },
+    EmojiInfo { emoji: "\u{1f682}", name: "locomotive", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["steam_locomotive"] },
+    EmojiInfo { emoji: "\u{1f683}", name: "railway car", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["railway_car"] },
+    EmojiInfo { emoji: "\u{1f684}", name: "high-speed train", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["bullettrain_side"] },
+    EmojiInfo { emoji: "\u{1f685}", name: "bullet train", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["bullettrain_front"] },
+    EmojiInfo { emoji: "\u{1f686}", name: "train", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["train2"] },
+    EmojiInfo { emoji: "\u{1f687}", name: "metro", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["metro"] },
+    EmojiInfo { emoji: "\u{1f688}", name: "light rail", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["light_rail"] },
+    EmojiInfo { emoji: "\u{1f689}", name: "station", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["station"] },
+    EmojiInfo { emoji: "\u{1f68a}", name: "tram", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["tram"] },
+    EmojiInfo { emoji: "\u{1f69d}", name: "monorail", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["monorail"] },
+    EmojiInfo { emoji: "\u{1f69e}", name: "mountain railway", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["mountain_railway"] },
+    EmojiInfo { emoji: "\u{1f68b}", name: "tram car", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["train"] },
+    EmojiInfo { emoji: "\u{1f68c}", name: "bus", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["bus"] },
+    EmojiInfo { emoji: "\u{1f68d}", name: "oncoming bus", group: "Travel & Places", subgroup: "transport-ground", version: 0.7, shortcodes: &["oncoming_bus"] },
+    EmojiInfo { emoji: "\u{1f68e}", name: "trolleybus", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["trolleybus"] },
+    EmojiInfo { emoji: "\u{1f690}", name: "minibus", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["minibus"] },
+    EmojiInfo { emoji: "\u{1f691}", name: "ambulance", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["ambulance"] },
+    EmojiInfo { emoji: "\u{1f692}", name: "fire engine", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["fire_engine"] },
+    EmojiInfo { emoji: "\u{1f693}", name: "police car", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["police_car"] },
+    EmojiInfo { emoji: "\u{1f694}", name: "oncoming police car", group: "Travel & Places", subgroup: "transport-ground", version: 0.7, shortcodes: &["oncoming_police_car"] },
+    EmojiInfo { emoji: "\u{1f695}", name: "taxi", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["taxi"] },
+    EmojiInfo { emoji: "\u{1f696}", name: "oncoming taxi", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["oncoming_taxi"] },
+    EmojiInfo { emoji: "\u{1f697}", name: "automobile", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["car", "red_car"] },
+    EmojiInfo { emoji: "\u{1f698}", name: "oncoming automobile", group: "Travel & Places", subgroup: "transport-ground", version: 0.7, shortcodes: &["oncoming_automobile"] },
+    EmojiInfo { emoji: "\u{1f699}", name: "sport utility vehicle", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["blue_car"] },
+    EmojiInfo { emoji: "\u{1f6fb}", name: "pickup truck", group: "Travel & Places", subgroup: "transport-ground", version: 13.0, shortcodes: &["pickup_truck"] },
+    EmojiInfo { emoji: "\u{1f69a}", name: "delivery truck", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["truck"] },
+    EmojiInfo { emoji: "\u{1f69b}", name: "articulated lorry", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["articulated_lorry"] },
+    EmojiInfo { emoji: "\u{1f69c}", name: "tractor", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["tractor"] },
+    EmojiInfo { emoji: "\u{1f3ce}\u{fe0f}", name: "racing car", group: "Travel & Places", subgroup: "transport-ground", version: 0.7, shortcodes: &["racing_car"] },
+    EmojiInfo { emoji: "\u{1f3cd}\u{fe0f}", name: "motorcycle", group: "Travel & Places", subgroup: "transport-ground", version: 0.7, shortcodes: &["motorcycle"] },
+    EmojiInfo { emoji: "\u{1f6f5}", name: "motor scooter", group: "Travel & Places", subgroup: "transport-ground", version: 3.0, shortcodes: &["motor_scooter"] },
+    EmojiInfo { emoji: "\u{1f9bd}", name: "manual wheelchair", group: "Travel & Places", subgroup: "transport-ground", version: 12.0, shortcodes: &["manual_wheelchair"] },
+    EmojiInfo { emoji: "\u{1f9bc}", name: "motorized wheelchair", group: "Travel & Places", subgroup: "transport-ground", version: 12.0, shortcodes: &["motorized_wheelchair"] },
+    EmojiInfo { emoji: "\u{1f6fa}", name: "auto rickshaw", group: "Travel & Places", subgroup: "transport-ground", version: 12.0, shortcodes: &["auto_rickshaw"] },
+    EmojiInfo { emoji: "\u{1f6b2}", name: "bicycle", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["bike"] },
+    EmojiInfo { emoji: "\u{1f6f4}", name: "kick scooter", group: "Travel & Places", subgroup: "transport-ground", version: 3.0, shortcodes: &["kick_scooter"] },
+    EmojiInfo { emoji: "\u{1f6f9}", name: "skateboard", group: "Travel & Places", subgroup: "transport-ground", version: 11.0, shortcodes: &["skateboard"] },
+    EmojiInfo { emoji: "\u{1f6fc}", name: "roller skate", group: "Travel & Places", subgroup: "transport-ground", version: 13.0, shortcodes: &["roller_skate"] },
+    EmojiInfo { emoji: "\u{1f68f}", name: "bus stop", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["busstop"] },
+    EmojiInfo { emoji: "\u{1f6e3}\u{fe0f}", name: "motorway", group: "Travel & Places", subgroup: "transport-ground", version: 0.7, shortcodes: &["motorway"] },
+    EmojiInfo { emoji: "\u{1f6e4}\u{fe0f}", name: "railway track", group: "Travel & Places", subgroup: "transport-ground", version: 0.7, shortcodes: &["railway_track"] },
+    EmojiInfo { emoji: "\u{1f6e2}\u{fe0f}", name: "oil drum", group: "Travel & Places", subgroup: "transport-ground", version: 0.7, shortcodes: &["oil_drum"] },
+    EmojiInfo { emoji: "\u{26fd}", name: "fuel pump", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["fuelpump"] },
+    EmojiInfo { emoji: "\u{1f6a8}", name: "police car light", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["rotating_light"] },
+    EmojiInfo { emoji: "\u{1f6a5}", name: "horizontal traffic light", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["traffic_light"] },
+    EmojiInfo { emoji: "\u{1f6a6}", name: "vertical traffic light", group: "Travel & Places", subgroup: "transport-ground", version: 1.0, shortcodes: &["vertical_traffic_light"] },
+    EmojiInfo { emoji: "\u{1f6d1}", name: "stop sign", group: "Travel & Places", subgroup: "transport-ground", version: 3.0, shortcodes: &["stop_sign"] },
+    EmojiInfo { emoji: "\u{1f6a7}", name: "construction", group: "Travel & Places", subgroup: "transport-ground", version: 0.6, shortcodes: &["construction"] },
+    EmojiInfo { emoji: "\u{2693}", name: "anchor", group: "Travel & Places", subgroup: "transport-water", version: 0.6, shortcodes: &["anchor"] },
+    EmojiInfo { emoji: "\u{26f5}", name: "sailboat", group: "Travel & Places", subgroup: "transport-water", version: 0.6, shortcodes: &["boat", "sailboat"] },
+    EmojiInfo { emoji: "\u{1f6f6}", name: "canoe", group: "Travel & Places", subgroup: "transport-water", version: 3.0, shortcodes: &["canoe"] },
+    EmojiInfo { emoji: "\u{1f6a4}", name: "speedboat", group: "Travel & Places", subgroup: "transport-water", version: 0.6, shortcodes: &["speedboat"] },
+    EmojiInfo { emoji: "\u{1f6f3}\u{fe0f}", name: "passenger ship", group: "Travel & Places", subgroup: "transport-water", version: 0.7, shortcodes: &["passenger_ship"] },
+    EmojiInfo { emoji: "\u{26f4}\u{fe0f}", name: "ferry", group: "Travel & Places", subgroup: "transport-water", version: 0.7, shortcodes: &["ferry"] },
+    EmojiInfo { emoji: "\u{1f6e5}\u{fe0f}", name: "motor boat", group: "Travel & Places", subgroup: "transport-water", version: 0.7, shortcodes: &["motor_boat"] },
+    EmojiInfo { emoji: "\u{1f6a2}", name: "ship", group: "Travel & Places", subgroup: "transport-water", version: 0.6, shortcodes: &["ship"] },
+    EmojiInfo { emoji: "\u{2708}\u{fe0f}", name: "airplane", group: "Travel & Places", subgroup: "transport-air", version: 0.6, shortcodes: &["airplane"] },
+    EmojiInfo { emoji: "\u{1f6e9}\u{fe0f}", name: "small airplane", group: "Travel & Places", subgroup: "transport-air", version: 0.7, shortcodes: &["small_airplane"] },
+    EmojiInfo { emoji: "\u{1f6eb}", name: "airplane departure", group: "Travel & Places", subgroup: "transport-air", version: 1.0, shortcodes: &["flight_departure"] },
+    EmojiInfo { emoji: "\u{1f6ec}", name: "airplane arrival", group: "Travel & Places", subgroup: "transport-air", version: 1.0, shortcodes: &["flight_arrival"] },
+    EmojiInfo { emoji: "\u{1fa82}", name: "parachute", group: "Travel & Places", subgroup: "transport-air", version: 12.0, shortcodes: &["parachute"] },
+    EmojiInfo { emoji: "\u{1f4ba}", name: "seat", group: "Travel & Places", subgroup: "transport-air", version: 0.6, shortcodes: &["seat"] },
+    EmojiInfo { emoji: "\u{1f681}", name: "helicopter", group: "Travel & Places", subgroup: "transport-air", version: 1.0, shortcodes: &["helicopter"] },
+    EmojiInfo { emoji: "\u{1f69f}", name: "suspension railway", group: "Travel & Places", subgroup: "transport-air", version: 1.0, shortcodes: &["suspension_railway"] },
+    EmojiInfo { emoji: "\u{1f6a0}", name: "mountain cableway", group: "Travel & Places", subgroup: "transport-air", version: 1.0, shortcodes: &["mountain_cableway"] },
+    EmojiInfo { emoji: "\u{1f6a1}", name: "aerial tramway", group: "Travel & Places", subgroup: "transport-air", version: 1.0, shortcodes: &["aerial_tramway"] },
+    EmojiInfo { emoji: "\u{1f6f0}\u{fe0f}", name: "satellite", group: "Travel & Places", subgroup: "transport-air", version: 0.7, shortcodes: &["artificial_satellite"] },
+    EmojiInfo { emoji: "\u{1f680}", name: "rocket", group: "Travel & Places", subgroup: "transport-air", version: 0.6, shortcodes: &["rocket"] },
+    EmojiInfo { emoji: "\u{1f6f8}", name: "flying saucer", group: "Travel & Places", subgroup: "transport-air", version: 5.0, shortcodes: &["flying_saucer"] },
+    EmojiInfo { emoji: "\u{1f6ce}\u{fe0f}", name: "bellhop bell", group: "Travel & Places", subgroup: "hotel", version: 0.7, shortcodes: &["bellhop_bell"] },
+    EmojiInfo { emoji: "\u{1f9f3}", name: "luggage", group: "Travel & Places", subgroup: "hotel", version: 11.0, shortcodes: &["luggage"] },
+    EmojiInfo { emoji: "\u{231b}", name: "hourglass done", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["hourglass"] },
+    EmojiInfo { emoji: "\u{23f3}", name: "hourglass not done", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["hourglass_flowing_sand"] },
+    EmojiInfo { emoji: "\u{231a}", name: "watch", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["watch"] },
+    EmojiInfo { emoji: "\u{23f0}", name: "alarm clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["alarm_clock"] },
+    EmojiInfo { emoji: "\u{23f1}\u{fe0f}", name: "stopwatch", group: "Travel & Places", subgroup: "time", version: 1.0, shortcodes: &["stopwatch"] },
+    EmojiInfo { emoji: "\u{23f2}\u{fe0f}", name: "timer clock", group: "Travel & Places", subgroup: "time", version: 1.0, shortcodes: &["timer_clock"] },
+    EmojiInfo { emoji: "\u{1f570}\u{fe0f}", name: "mantelpiece clock", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["mantelpiece_clock"] },
+    EmojiInfo { emoji: "\u{1f55b}", name: "twelve o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock12"] },
+    EmojiInfo { emoji: "\u{1f567}", name: "twelve-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock1230"] },
+    EmojiInfo { emoji: "\u{1f550}", name: "one o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock1"] },
+    EmojiInfo { emoji: "\u{1f55c}", name: "one-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock130"] },
+    EmojiInfo { emoji: "\u{1f551}", name: "two o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock2"] },
+    EmojiInfo { emoji: "\u{1f55d}", name: "two-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock230"] },
+    EmojiInfo { emoji: "\u{1f552}", name: "three o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock3"] },
+    EmojiInfo { emoji: "\u{1f55e}", name: "three-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock330"] },
+    EmojiInfo { emoji: "\u{1f553}", name: "four o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock4"] },
+    EmojiInfo { emoji: "\u{1f55f}", name: "four-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock430"] },
+    EmojiInfo { emoji: "\u{1f554}", name: "five o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock5"] },
+    EmojiInfo { emoji: "\u{1f560}", name: "five-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock530"] },
+    EmojiInfo { emoji: "\u{1f555}", name: "six o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock6"] },
+    EmojiInfo { emoji: "\u{1f561}", name: "six-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock630"] },
+    EmojiInfo { emoji: "\u{1f556}", name: "seven o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock7"] },
+    EmojiInfo { emoji: "\u{1f562}", name: "seven-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock730"] },
+    EmojiInfo { emoji: "\u{1f557}", name: "eight o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock8"] },
+    EmojiInfo { emoji: "\u{1f563}", name: "eight-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock830"] },
+    EmojiInfo { emoji: "\u{1f558}", name: "nine o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock9"] },
+    EmojiInfo { emoji: "\u{1f564}", name: "nine-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock930"] },
+    EmojiInfo { emoji: "\u{1f559}", name: "ten o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock10"] },
+    EmojiInfo { emoji: "\u{1f565}", name: "ten-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock1030"] },
+    EmojiInfo { emoji: "\u{1f55a}", name: "eleven o’clock", group: "Travel & Places", subgroup: "time", version: 0.6, shortcodes: &["clock11"] },
+    EmojiInfo { emoji: "\u{1f566}", name: "eleven-thirty", group: "Travel & Places", subgroup: "time", version: 0.7, shortcodes: &["clock1130"] },
+    EmojiInfo { emoji: "\u{1f311}", name: "new moon", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["new_moon"] },
+    EmojiInfo { emoji: "\u{1f312}", name: "waxing crescent moon", group: "Travel & Places", subgroup: "sky & weather", version: 1.0, shortcodes: &["waxing_crescent_moon"] },
+    EmojiInfo { emoji: "\u{1f313}", name: "first quarter moon", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["first_quarter_moon"] },
+    EmojiInfo { emoji: "\u{1f314}", name: "waxing gibbous moon", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["moon", "waxing_gibbous_moon"] },
+    EmojiInfo { emoji: "\u{1f315}", name: "full moon", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["full_moon"] },
+    EmojiInfo { emoji: "\u{1f316}", name: "waning gibbous moon", group: "Travel & Places", subgroup: "sky & weather", version: 1.0, shortcodes: &["waning_gibbous_moon"] },
+    EmojiInfo { emoji: "\u{1f317}", name: "last quarter moon", group: "Travel & Places", subgroup: "sky & weather", version: 1.0, shortcodes: &["last_quarter_moon"] },
+    EmojiInfo { emoji: "\u{1f318}", name: "waning crescent moon", group: "Travel & Places", subgroup: "sky & weather", version: 1.0, shortcodes: &["waning_crescent_moon"] },
+    EmojiInfo { emoji: "\u{1f319}", name: "crescent moon", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["crescent_moon"] },
+    EmojiInfo { emoji: "\u{1f31a}", name: "new moon face", group: "Travel & Places", subgroup: "sky & weather", version: 1.0, shortcodes: &["new_moon_with_face"] },
+    EmojiInfo { emoji: "\u{1f31b}", name: "first quarter moon face", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["first_quarter_moon_with_face"] },
+    EmojiInfo { emoji: "\u{1f31c}", name: "last quarter moon face", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["last_quarter_moon_with_face"] },
+    EmojiInfo { emoji: "\u{1f321}\u{fe0f}", name: "thermometer", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["thermometer"] },
+    EmojiInfo { emoji: "\u{2600}\u{fe0f}", name: "sun", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["sunny"] },
+    EmojiInfo { emoji: "\u{1f31d}", name: "full moon face", group: "Travel & Places", subgroup: "sky & weather", version: 1.0, shortcodes: &["full_moon_with_face"] },
+    EmojiInfo { emoji: "\u{1f31e}", name: "sun with face", group: "Travel & Places", subgroup: "sky & weather", version: 1.0, shortcodes: &["sun_with_face"] },
+    EmojiInfo { emoji: "\u{1fa90}", name: "ringed planet", group: "Travel & Places", subgroup: "sky & weather", version: 12.0, shortcodes: &["ringed_planet"] },
+    EmojiInfo { emoji: "\u{2b50}", name: "star", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["star"] },
+    EmojiInfo { emoji: "\u{1f31f}", name: "glowing star", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["star2"] },
+    EmojiInfo { emoji: "\u{1f320}", name: "shooting star", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["stars"] },
+    EmojiInfo { emoji: "\u{1f30c}", name: "milky way", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["milky_way"] },
+    EmojiInfo { emoji: "\u{2601}\u{fe0f}", name: "cloud", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["cloud"] },
+    EmojiInfo { emoji: "\u{26c5}", name: "sun behind cloud", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["partly_sunny"] },
+    EmojiInfo { emoji: "\u{26c8}\u{fe0f}", name: "cloud with lightning and rain", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["cloud_with_lightning_and_rain"] },
+    EmojiInfo { emoji: "\u{1f324}\u{fe0f}", name: "sun behind small cloud", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["sun_behind_small_cloud"] },
+    EmojiInfo { emoji: "\u{1f325}\u{fe0f}", name: "sun behind large cloud", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["sun_behind_large_cloud"] },
+    EmojiInfo { emoji: "\u{1f326}\u{fe0f}", name: "sun behind rain cloud", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["sun_behind_rain_cloud"] },
+    EmojiInfo { emoji: "\u{1f327}\u{fe0f}", name: "cloud with rain", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["cloud_with_rain"] },
+    EmojiInfo { emoji: "\u{1f328}\u{fe0f}", name: "cloud with snow", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["cloud_with_snow"] },
+    EmojiInfo { emoji: "\u{1f329}\u{fe0f}", name: "cloud with lightning", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["cloud_with_lightning"] },
+    EmojiInfo { emoji: "\u{1f32a}\u{fe0f}", name: "tornado", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["tornado"] },
+    EmojiInfo { emoji: "\u{1f32b}\u{fe0f}", name: "fog", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["fog"] },
+    EmojiInfo { emoji: "\u{1f32c}\u{fe0f}", name: "wind face", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["wind_face"] },
+    EmojiInfo { emoji: "\u{1f300}", name: "cyclone", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["cyclone"] },
+    EmojiInfo { emoji: "\u{1f308}", name: "rainbow", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["rainbow"] },
+    EmojiInfo { emoji: "\u{1f302}", name: "closed umbrella", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["closed_umbrella"] },
+    EmojiInfo { emoji: "\u{2602}\u{fe0f}", name: "umbrella", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["open_umbrella"] },
+    EmojiInfo { emoji: "\u{2614}", name: "umbrella with rain drops", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["umbrella"] },
+    EmojiInfo { emoji: "\u{26f1}\u{fe0f}", name: "umbrella on ground", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["parasol_on_ground"] },
+    EmojiInfo { emoji: "\u{26a1}", name: "high voltage", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["zap"] },
+    EmojiInfo { emoji: "\u{2744}\u{fe0f}", name: "snowflake", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["snowflake"] },
+    EmojiInfo { emoji: "\u{2603}\u{fe0f}", name: "snowman", group: "Travel & Places", subgroup: "sky & weather", version: 0.7, shortcodes: &["snowman_with_snow"] },
+    EmojiInfo { emoji: "\u{26c4}", name: "snowman without snow", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["snowman"] },
+    EmojiInfo { emoji: "\u{2604}\u{fe0f}", name: "comet", group: "Travel & Places", subgroup: "sky & weather", version: 1.0, shortcodes: &["comet"] },
+    EmojiInfo { emoji: "\u{1f525}", name: "fire", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["fire"] },
+    EmojiInfo { emoji: "\u{1f4a7}", name: "droplet", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["droplet"] },
+    EmojiInfo { emoji: "\u{1f30a}", name: "water wave", group: "Travel & Places", subgroup: "sky & weather", version: 0.6, shortcodes: &["ocean"] },
+    EmojiInfo { emoji: "\u{1f383}", name: "jack-o-lantern", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["jack_o_lantern"] },
+    EmojiInfo { emoji: "\u{1f384}", name: "Christmas tree", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["christmas_tree"] },
+    EmojiInfo { emoji: "\u{1f386}", name: "fireworks", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["fireworks"] },
+    EmojiInfo { emoji: "\u{1f387}", name: "sparkler", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["sparkler"] },
+    EmojiInfo { emoji: "\u{1f9e8}", name: "firecracker", group: "Activities", subgroup: "event", version: 11.0, shortcodes: &["firecracker"] },
+    EmojiInfo { emoji: "\u{2728}", name: "sparkles", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["sparkles"] },
+    EmojiInfo { emoji: "\u{1f388}", name: "balloon", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["balloon"] },
+    EmojiInfo { emoji: "\u{1f389}", name: "party popper", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["tada"] },
+    EmojiInfo { emoji: "\u{1f38a}", name: "confetti ball", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["confetti_ball"] },
+    EmojiInfo { emoji: "\u{1f38b}", name: "tanabata tree", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["tanabata_tree"] },
+    EmojiInfo { emoji: "\u{1f38d}", name: "pine decoration", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["bamboo"] },
+    EmojiInfo { emoji: "\u{1f38e}", name: "Japanese dolls", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["dolls"] },
+    EmojiInfo { emoji: "\u{1f38f}", name: "carp streamer", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["flags"] },
+    EmojiInfo { emoji: "\u{1f390}", name: "wind chime", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["wind_chime"] },
+    EmojiInfo { emoji: "\u{1f391}", name: "moon viewing ceremony", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["rice_scene"] },
+    EmojiInfo { emoji: "\u{1f9e7}", name: "red envelope", group: "Activities", subgroup: "event", version: 11.0, shortcodes: &["red_envelope"] },
+    EmojiInfo { emoji: "\u{1f380}", name: "ribbon", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["ribbon"] },
+    EmojiInfo { emoji: "\u{1f381}", name: "wrapped gift", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["gift"] },
+    EmojiInfo { emoji: "\u{1f397}\u{fe0f}", name: "reminder ribbon", group: "Activities", subgroup: "event", version: 0.7, shortcodes: &["reminder_ribbon"] },
+    EmojiInfo { emoji: "\u{1f39f}\u{fe0f}", name: "admission tickets", group: "Activities", subgroup: "event", version: 0.7, shortcodes: &["tickets"] },
+    EmojiInfo { emoji: "\u{1f3ab}", name: "ticket", group: "Activities", subgroup: "event", version: 0.6, shortcodes: &["ticket"] },
+    EmojiInfo { emoji: "\u{1f396}\u{fe0f}", name: "military medal", group: "Activities", subgroup: "award-medal", version: 0.7, shortcodes: &["medal_military"] },
+    EmojiInfo { emoji: "\u{1f3c6}", name: "trophy", group: "Activities", subgroup: "award-medal", version: 0.6, shortcodes: &["trophy"] },
+    EmojiInfo { emoji: "\u{1f3c5}", name: "sports medal", group: "Activities", subgroup: "award-medal", version: 1.0, shortcodes: &["medal_sports"] },
+    EmojiInfo { emoji: "\u{1f947}", name: "1st place medal", group: "Activities", subgroup: "award-medal", version: 3.0, shortcodes: &["1st_place_medal"] },
+    EmojiInfo { emoji: "\u{1f948}", name: "2nd place medal", group: "Activities", subgroup: "award-medal", version: 3.0, shortcodes: &["2nd_place_medal"] },
+    EmojiInfo { emoji: "\u{1f949}", name: "3rd place medal", group: "Activities", subgroup: "award-medal", version: 3.0, shortcodes: &["3rd_place_medal"] },
+    EmojiInfo { emoji: "\u{26bd}", name: "soccer ball", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["soccer"] },
+    EmojiInfo { emoji: "\u{26be}", name: "baseball", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["baseball"] },
+    EmojiInfo { emoji: "\u{1f94e}", name: "softball", group: "Activities", subgroup: "sport", version: 11.0, shortcodes: &["softball"] },
+    EmojiInfo { emoji: "\u{1f3c0}", name: "basketball", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["basketball"] },
+    EmojiInfo { emoji: "\u{1f3d0}", name: "volleyball", group: "Activities", subgroup: "sport", version: 1.0, shortcodes: &["volleyball"] },
+    EmojiInfo { emoji: "\u{1f3c8}", name: "american football", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["football"] },
+    EmojiInfo { emoji: "\u{1f3c9}", name: "rugby football", group: "Activities", subgroup: "sport", version: 1.0, shortcodes: &["rugby_football"] },
+    EmojiInfo { emoji: "\u{1f3be}", name: "tennis", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["tennis"] },
+    EmojiInfo { emoji: "\u{1f94f}", name: "flying disc", group: "Activities", subgroup: "sport", version: 11.0, shortcodes: &["flying_disc"] },
+    EmojiInfo { emoji: "\u{1f3b3}", name: "bowling", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["bowling"] },
+    EmojiInfo { emoji: "\u{1f3cf}", name: "cricket game", group: "Activities", subgroup: "sport", version: 1.0, shortcodes: &["cricket_game"] },
+    EmojiInfo { emoji: "\u{1f3d1}", name: "field hockey", group: "Activities", subgroup: "sport", version: 1.0, shortcodes: &["field_hockey"] },
+    EmojiInfo { emoji: "\u{1f3d2}", name: "ice hockey", group: "Activities", subgroup: "sport", version: 1.0, shortcodes: &["ice_hockey"] },
+    EmojiInfo { emoji: "\u{1f94d}", name: "lacrosse", group: "Activities", subgroup: "sport", version: 11.0, shortcodes: &["lacrosse"] },
+    EmojiInfo { emoji: "\u{1f3d3}", name: "ping pong", group: "Activities", subgroup: "sport", version: 1.0, shortcodes: &["ping_pong"] },
+    EmojiInfo { emoji: "\u{1f3f8}", name: "badminton", group: "Activities", subgroup: "sport", version: 1.0, shortcodes: &["badminton"] },
+    EmojiInfo { emoji: "\u{1f94a}", name: "boxing glove", group: "Activities", subgroup: "sport", version: 3.0, shortcodes: &["boxing_glove"] },
+    EmojiInfo { emoji: "\u{1f94b}", name: "martial arts uniform", group: "Activities", subgroup: "sport", version: 3.0, shortcodes: &["martial_arts_uniform"] },
+    EmojiInfo { emoji: "\u{1f945}", name: "goal net", group: "Activities", subgroup: "sport", version: 3.0, shortcodes: &["goal_net"] },
+    EmojiInfo { emoji: "\u{26f3}", name: "flag in hole", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["golf"] },
+    EmojiInfo { emoji: "\u{26f8}\u{fe0f}", name: "ice skate", group: "Activities", subgroup: "sport", version: 0.7, shortcodes: &["ice_skate"] },
+    EmojiInfo { emoji: "\u{1f3a3}", name: "fishing pole", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["fishing_pole_and_fish"] },
+    EmojiInfo { emoji: "\u{1f93f}", name: "diving mask", group: "Activities", subgroup: "sport", version: 12.0, shortcodes: &["diving_mask"] },
+    EmojiInfo { emoji: "\u{1f3bd}", name: "running shirt", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["running_shirt_with_sash"] },
+    EmojiInfo { emoji: "\u{1f3bf}", name: "skis", group: "Activities", subgroup: "sport", version: 0.6, shortcodes: &["ski"] },
+    EmojiInfo { emoji: "\u{1f6f7}", name: "sled", group: "Activities", subgroup: "sport", version: 5.0, shortcodes: &["sled"] },
+    EmojiInfo { emoji: "\u{1f94c}", name: "curling stone", group: "Activities", subgroup: "sport", version: 5.0, shortcodes: &["curling_stone"] },
+    EmojiInfo { emoji: "\u{1f3af}", name: "bullseye", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["dart"] },
+    EmojiInfo { emoji: "\u{1fa80}", name: "yo-yo", group: "Activities", subgroup: "game", version: 12.0, shortcodes: &["yo_yo"] },
+    EmojiInfo { emoji: "\u{1fa81}", name: "kite", group: "Activities", subgroup: "game", version: 12.0, shortcodes: &["kite"] },
+    EmojiInfo { emoji: "\u{1f3b1}", name: "pool 8 ball", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["8ball"] },
+    EmojiInfo { emoji: "\u{1f52e}", name: "crystal ball", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["crystal_ball"] },
+    EmojiInfo { emoji: "\u{1fa84}", name: "magic wand", group: "Activities", subgroup: "game", version: 13.0, shortcodes: &["magic_wand"] },
+    EmojiInfo { emoji: "\u{1f9ff}", name: "nazar amulet", group: "Activities", subgroup: "game", version: 11.0, shortcodes: &["nazar_amulet"] },
+    EmojiInfo { emoji: "\u{1f3ae}", name: "video game", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["video_game"] },
+    EmojiInfo { emoji: "\u{1f579}\u{fe0f}", name: "joystick", group: "Activities", subgroup: "game", version: 0.7, shortcodes: &["joystick"] },
+    EmojiInfo { emoji: "\u{1f3b0}", name: "slot machine", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["slot_machine"] },
+    EmojiInfo { emoji: "\u{1f3b2}", name: "game die", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["game_die"] },
+    EmojiInfo { emoji: "\u{1f9e9}", name: "puzzle piece", group: "Activities", subgroup: "game", version: 11.0, shortcodes: &["jigsaw"] },
+    EmojiInfo { emoji: "\u{1f9f8}", name: "teddy bear", group: "Activities", subgroup: "game", version: 11.0, shortcodes: &["teddy_bear"] },
+    EmojiInfo { emoji: "\u{1fa85}", name: "piñata", group: "Activities", subgroup: "game", version: 13.0, shortcodes: &["pinata"] },
+    EmojiInfo { emoji: "\u{1fa86}", name: "nesting dolls", group: "Activities", subgroup: "game", version: 13.0, shortcodes: &["nesting_dolls"] },
+    EmojiInfo { emoji: "\u{2660}\u{fe0f}", name: "spade suit", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["spades"] },
+    EmojiInfo { emoji: "\u{2665}\u{fe0f}", name: "heart suit", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["hearts"] },
+    EmojiInfo { emoji: "\u{2666}\u{fe0f}", name: "diamond suit", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["diamonds"] },
+    EmojiInfo { emoji: "\u{2663}\u{fe0f}", name: "club suit", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["clubs"] },
+    EmojiInfo { emoji: "\u{265f}\u{fe0f}", name: "chess pawn", group: "Activities", subgroup: "game", version: 11.0, shortcodes: &["chess_pawn"] },
+    EmojiInfo { emoji: "\u{1f0cf}", name: "joker", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["black_joker"] },
+    EmojiInfo { emoji: "\u{1f004}", name: "mahjong red dragon", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["mahjong"] },
+    EmojiInfo { emoji: "\u{1f3b4}", name: "flower playing cards", group: "Activities", subgroup: "game", version: 0.6, shortcodes: &["flower_playing_cards"] },
+    EmojiInfo { emoji: "\u{1f3ad}", name: "performing arts", group: "Activities", subgroup: "arts & crafts", version: 0.6, shortcodes: &["performing_arts"] },
+    EmojiInfo { emoji: "\u{1f5bc}\u{fe0f}", name: "framed picture", group: "Activities", subgroup: "arts & crafts", version: 0.7, shortcodes: &["framed_picture"] },
+    EmojiInfo { emoji: "\u{1f3a8}", name: "artist palette", group: "Activities", subgroup: "arts & crafts", version: 0.6, shortcodes: &["art"] },
+    EmojiInfo { emoji: "\u{1f9f5}", name: "thread", group: "Activities", subgroup: "arts & crafts", version: 11.0, shortcodes: &["thread"] },
+    EmojiInfo { emoji: "\u{1faa1}", name: "sewing needle", group: "Activities", subgroup: "arts & crafts", version: 13.0, shortcodes: &["sewing_needle"] },
+    EmojiInfo { emoji: "\u{1f9f6}", name: "yarn", group: "Activities", subgroup: "arts & crafts", version: 11.0, shortcodes: &["yarn"] },
+    EmojiInfo { emoji: "\u{1faa2}", name: "knot", group: "Activities", subgroup: "arts & crafts", version: 13.0, shortcodes: &["knot"] },
+    EmojiInfo { emoji: "\u{1f453}", name: "glasses", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["eyeglasses"] },
+    EmojiInfo { emoji: "\u{1f576}\u{fe0f}", name: "sunglasses", group: "Objects", subgroup: "clothing", version: 0.7, shortcodes: &["dark_sunglasses"] },
+    EmojiInfo { emoji: "\u{1f97d}", name: "goggles", group: "Objects", subgroup: "clothing", version: 11.0, shortcodes: &["goggles"] },
+    EmojiInfo { emoji: "\u{1f97c}", name: "lab coat", group: "Objects", subgroup: "clothing", version: 11.0, shortcodes: &["lab_coat"] },
+    EmojiInfo { emoji: "\u{1f9ba}", name: "safety vest", group: "Objects", subgroup: "clothing", version: 12.0, shortcodes: &["safety_vest"] },
+    EmojiInfo { emoji: "\u{1f454}", name: "necktie", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["necktie"] },
+    EmojiInfo { emoji: "\u{1f455}", name: "t-shirt", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["shirt", "tshirt"] },
+    EmojiInfo { emoji: "\u{1f456}", name: "jeans", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["jeans"] },
+    EmojiInfo { emoji: "\u{1f9e3}", name: "scarf", group: "Objects", subgroup: "clothing", version: 5.0, shortcodes: &["scarf"] },
+    EmojiInfo { emoji: "\u{1f9e4}", name: "gloves", group: "Objects", subgroup: "clothing", version: 5.0, shortcodes: &["gloves"] },
+    EmojiInfo { emoji: "\u{1f9e5}", name: "coat", group: "Objects", subgroup: "clothing", version: 5.0, shortcodes: &["coat"] },
+    EmojiInfo { emoji: "\u{1f9e6}", name: "socks", group: "Objects", subgroup: "clothing", version: 5.0, shortcodes: &["socks"] },
+    EmojiInfo { emoji: "\u{1f457}", name: "dress", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["dress"] },
+    EmojiInfo { emoji: "\u{1f458}", name: "kimono", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["kimono"] },
+    EmojiInfo { emoji: "\u{1f97b}", name: "sari", group: "Objects", subgroup: "clothing", version: 12.0, shortcodes: &["sari"] },
+    EmojiInfo { emoji: "\u{1fa71}", name: "one-piece swimsuit", group: "Objects", subgroup: "clothing", version: 12.0, shortcodes: &["one_piece_swimsuit"] },
+    EmojiInfo { emoji: "\u{1fa72}", name: "briefs", group: "Objects", subgroup: "clothing", version: 12.0, shortcodes: &["swim_brief"] },
+    EmojiInfo { emoji: "\u{1fa73}", name: "shorts", group: "Objects", subgroup: "clothing", version: 12.0, shortcodes: &["shorts"] },
+    EmojiInfo { emoji: "\u{1f459}", name: "bikini", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["bikini"] },
+    EmojiInfo { emoji: "\u{1f45a}", name: "woman’s clothes", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["womans_clothes"] },
+    EmojiInfo { emoji: "\u{1f45b}", name: "purse", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["purse"] },
+    EmojiInfo { emoji: "\u{1f45c}", name: "handbag", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["handbag"] },
+    EmojiInfo { emoji: "\u{1f45d}", name: "clutch bag", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["pouch"] },
+    EmojiInfo { emoji: "\u{1f6cd}\u{fe0f}", name: "shopping bags", group: "Objects", subgroup: "clothing", version: 0.7, shortcodes: &["shopping"] },
+    EmojiInfo { emoji: "\u{1f392}", name: "backpack", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["school_satchel"] },
+    EmojiInfo { emoji: "\u{1fa74}", name: "thong sandal", group: "Objects", subgroup: "clothing", version: 13.0, shortcodes: &["thong_sandal"] },
+    EmojiInfo { emoji: "\u{1f45e}", name: "man’s shoe", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["mans_shoe", "shoe"] },
+    EmojiInfo { emoji: "\u{1f45f}", name: "running shoe", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["athletic_shoe"] },
+    EmojiInfo { emoji: "\u{1f97e}", name: "hiking boot", group: "Objects", subgroup: "clothing", version: 11.0, shortcodes: &["hiking_boot"] },
+    EmojiInfo { emoji: "\u{1f97f}", name: "flat shoe", group: "Objects", subgroup: "clothing", version: 11.0, shortcodes: &["flat_shoe"] },
+    EmojiInfo { emoji: "\u{1f460}", name: "high-heeled shoe", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["high_heel"] },
+    EmojiInfo { emoji: "\u{1f461}", name: "woman’s sandal", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["sandal"] },
+    EmojiInfo { emoji: "\u{1fa70}", name: "ballet shoes", group: "Objects", subgroup: "clothing", version: 12.0, shortcodes: &["ballet_shoes"] },
+    EmojiInfo { emoji: "\u{1f462}", name: "woman’s boot", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["boot"] },
+    EmojiInfo { emoji: "\u{1f451}", name: "crown", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["crown"] },
+    EmojiInfo { emoji: "\u{1f452}", name: "woman’s hat", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["womans_hat"] },
+    EmojiInfo { emoji: "\u{1f3a9}", name: "top hat", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["tophat"] },
+    EmojiInfo { emoji: "\u{1f393}", name: "graduation cap", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["mortar_board"] },
+    EmojiInfo { emoji: "\u{1f9e2}", name: "billed cap", group: "Objects", subgroup: "clothing", version: 5.0, shortcodes: &["billed_cap"] },
+    EmojiInfo { emoji: "\u{1fa96}", name: "military helmet", group: "Objects", subgroup: "clothing", version: 13.0, shortcodes: &["military_helmet"] },
+    EmojiInfo { emoji: "\u{26d1}\u{fe0f}", name: "rescue worker’s helmet", group: "Objects", subgroup: "clothing", version: 0.7, shortcodes: &["rescue_worker_helmet"] },
+    EmojiInfo { emoji: "\u{1f4ff}", name: "prayer beads", group: "Objects", subgroup: "clothing", version: 1.0, shortcodes: &["prayer_beads"] },
+    EmojiInfo { emoji: "\u{1f484}", name: "lipstick", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["lipstick"] },
+    EmojiInfo { emoji: "\u{1f48d}", name: "ring", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["ring"] },
+    EmojiInfo { emoji: "\u{1f48e}", name: "gem stone", group: "Objects", subgroup: "clothing", version: 0.6, shortcodes: &["gem"] },
+    EmojiInfo { emoji: "\u{1f507}", name: "muted speaker", group: "Objects", subgroup: "sound", version: 1.0, shortcodes: &["mute"] },
+    EmojiInfo { emoji: "\u{1f508}", name: "speaker low volume", group: "Objects", subgroup: "sound", version: 0.7, shortcodes: &["speaker"] },
+    EmojiInfo { emoji: "\u{1f509}", name: "speaker medium volume", group: "Objects", subgroup: "sound", version: 1.0, shortcodes: &["sound"] },
+    EmojiInfo { emoji: "\u{1f50a}", name: "speaker high volume", group: "Objects", subgroup: "sound", version: 0.6, shortcodes: &["loud_sound"] },
+    EmojiInfo { emoji: "\u{1f4e2}", name: "loudspeaker", group: "Objects", subgroup: "sound", version: 0.6, shortcodes: &["loudspeaker"] },
+    EmojiInfo { emoji: "\u{1f4e3}", name: "megaphone", group: "Objects", subgroup: "sound", version: 0.6, shortcodes: &["mega"] },
+    EmojiInfo { emoji: "\u{1f4ef}", name: "postal horn", group: "Objects", subgroup: "sound", version: 1.0, shortcodes: &["postal_horn"] },
+    EmojiInfo { emoji: "\u{1f514}", name: "bell", group: "Objects", subgroup: "sound", version: 0.6, shortcodes: &["bell"] },
+    EmojiInfo { emoji: "\u{1f515}", name: "bell with slash", group: "Objects", subgroup: "sound", version: 1.0, shortcodes: &["no_bell"] },
+    EmojiInfo { emoji: "\u{1f3bc}", name: "musical score", group: "Objects", subgroup: "music", version: 0.6, shortcodes: &["musical_score"] },
+    EmojiInfo { emoji: "\u{1f3b5}", name: "musical note", group: "Objects", subgroup: "music", version: 0.6, shortcodes: &["musical_note"] },
+    EmojiInfo { emoji: "\u{1f3b6}", name: "musical notes", group: "Objects", subgroup: "music", version: 0.6, shortcodes: &["notes"] },
+    EmojiInfo { emoji: "\u{1f399}\u{fe0f}", name: "studio microphone", group: "Objects", subgroup: "music", version: 0.7, shortcodes: &["studio_microphone"] },
+    EmojiInfo { emoji: "\u{1f39a}\u{fe0f}", name: "level slider", group: "Objects", subgroup: "music", version: 0.7, shortcodes: &["level_slider"] },
+    EmojiInfo { emoji: "\u{1f39b}\u{fe0f}", name: "control knobs", group: "Objects", subgroup: "music", version: 0.7, shortcodes: &["control_knobs"] },
+    EmojiInfo { emoji: "\u{1f3a4}", name: "microphone", group: "Objects", subgroup: "music", version: 0.6, shortcodes: &["microphone"] },
+    EmojiInfo { emoji: "\u{1f3a7}", name: "headphone", group: "Objects", subgroup: "music", version: 0.6, shortcodes: &["headphones"] },
+    EmojiInfo { emoji: "\u{1f4fb}", name: "radio", group: "Objects", subgroup: "music", version: 0.6, shortcodes: &["radio"] },
+    EmojiInfo { emoji: "\u{1f3b7}", name: "saxophone", group: "Objects", subgroup: "musical-instrument", version: 0.6, shortcodes: &["saxophone"] },
+    EmojiInfo { emoji: "\u{1fa97}", name: "accordion", group: "Objects", subgroup: "musical-instrument", version: 13.0, shortcodes: &["accordion"] },
+    EmojiInfo { emoji: "\u{1f3b8}", name: "guitar", group: "Objects", subgroup: "musical-instrument", version: 0.6, shortcodes: &["guitar"] },
+    EmojiInfo { emoji: "\u{1f3b9}", name: "musical keyboard", group: "Objects", subgroup: "musical-instrument", version: 0.6, shortcodes: &["musical_keyboard"] },
+    EmojiInfo { emoji: "\u{1f3ba}", name: "trumpet", group: "Objects", subgroup: "musical-instrument", version: 0.6, shortcodes: &["trumpet"] },
+    EmojiInfo { emoji: "\u{1f3bb}", name: "violin", group: "Objects", subgroup: "musical-instrument", version: 0.6, shortcodes: &["violin"] },
+    EmojiInfo { emoji: "\u{1fa95}", name: "banjo", group: "Objects", subgroup: "musical-instrument", version: 12.0, shortcodes: &["banjo"] },
+    EmojiInfo { emoji: "\u{1f941}", name: "drum", group: "Objects", subgroup: "musical-instrument", version: 3.0, shortcodes: &["drum"] },
+    EmojiInfo { emoji: "\u{1fa98}", name: "long drum", group: "Objects", subgroup: "musical-instrument", version: 13.0, shortcodes: &["long_drum"] },
+    EmojiInfo { emoji: "\u{1f4f1}", name: "mobile phone", group: "Objects", subgroup: "phone", version: 0.6, shortcodes: &["iphone"] },
+    EmojiInfo { emoji: "\u{1f4f2}", name: "mobile phone with arrow", group: "Objects", subgroup: "phone", version: 0.6, shortcodes: &["calling"] },
+    EmojiInfo { emoji: "\u{260e}\u{fe0f}", name: "telephone", group: "Objects", subgroup: "phone", version: 0.6, shortcodes: &["phone", "telephone"] },
+    EmojiInfo { emoji: "\u{1f4de}", name: "telephone receiver", group: "Objects", subgroup: "phone", version: 0.6, shortcodes: &["telephone_receiver"] },
+    EmojiInfo { emoji: "\u{1f4df}", name: "pager", group: "Objects", subgroup: "phone", version: 0.6, shortcodes: &["pager"] },
+    EmojiInfo { emoji: "\u{1f4e0}", name: "fax machine", group: "Objects", subgroup: "phone", version: 0.6, shortcodes: &["fax"] },
+    EmojiInfo { emoji: "\u{1f50b}", name: "battery", group: "Objects", subgroup: "computer", version: 0.6, shortcodes: &["battery"] },
+    EmojiInfo { emoji: "\u{1f50c}", name: "electric plug", group: "Objects", subgroup: "computer", version: 0.6, shortcodes: &["electric_plug"] },
+    EmojiInfo { emoji: "\u{1f4bb}", name: "laptop", group: "Objects", subgroup: "computer", version: 0.6, shortcodes: &["computer"] },
+    EmojiInfo { emoji: "\u{1f5a5}\u{fe0f}", name: "desktop computer", group: "Objects", subgroup: "computer", version: 0.7, shortcodes: &["desktop_computer"] },
+    EmojiInfo { emoji: "\u{1f5a8}\u{fe0f}", name: "printer", group: "Objects", subgroup: "computer", version: 0.7, shortcodes: &["printer"] },
+    EmojiInfo { emoji: "\u{2328}\u{fe0f}", name: "keyboard", group: "Objects", subgroup: "computer", version: 1.0, shortcodes: &["keyboard"] },
+    EmojiInfo { emoji: "\u{1f5b1}\u{fe0f}", name: "computer mouse", group: "Objects", subgroup: "computer", version: 0.7, shortcodes: &["computer_mouse"] },
+    EmojiInfo { emoji: "\u{1f5b2}\u{fe0f}", name: "trackball", group: "Objects", subgroup: "computer", version: 0.7, shortcodes: &["trackball"] },
+    EmojiInfo { emoji: "\u{1f4bd}", name: "computer disk", group: "Objects", subgroup: "computer", version: 0.6, shortcodes: &["minidisc"] },
+    EmojiInfo { emoji: "\u{1f4be}", name: "floppy disk", group: "Objects", subgroup: "computer", version: 0.6, shortcodes: &["floppy_disk"] },
+    EmojiInfo { emoji: "\u{1f4bf}", name: "optical disk", group: "Objects", subgroup: "computer", version: 0.6, shortcodes: &["cd"] },
+    EmojiInfo { emoji: "\u{1f4c0}", name: "dvd", group: "Objects", subgroup: "computer", version: 0.6, shortcodes: &["dvd"] },
+    EmojiInfo { emoji: "\u{1f9ee}", name: "abacus", group: "Objects", subgroup: "computer", version: 11.0, shortcodes: &["abacus"] },
+    EmojiInfo { emoji: "\u{1f3a5}", name: "movie camera", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["movie_camera"] },
+    EmojiInfo { emoji: "\u{1f39e}\u{fe0f}", name: "film frames", group: "Objects", subgroup: "light & video", version: 0.7, shortcodes: &["film_strip"] },
+    EmojiInfo { emoji: "\u{1f4fd}\u{fe0f}", name: "film projector", group: "Objects", subgroup: "light & video", version: 0.7, shortcodes: &["film_projector"] },
+    EmojiInfo { emoji: "\u{1f3ac}", name: "clapper board", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["clapper"] },
+    EmojiInfo { emoji: "\u{1f4fa}", name: "television", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["tv"] },
+    EmojiInfo { emoji: "\u{1f4f7}", name: "camera", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["camera"] },
+    EmojiInfo { emoji: "\u{1f4f8}", name: "camera with flash", group: "Objects", subgroup: "light & video", version: 1.0, shortcodes: &["camera_flash"] },
+    EmojiInfo { emoji: "\u{1f4f9}", name: "video camera", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["video_camera"] },
+    EmojiInfo { emoji: "\u{1f4fc}", name: "videocassette", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["vhs"] },
+    EmojiInfo { emoji: "\u{1f50d}", name: "magnifying glass tilted left", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["mag"] },
+    EmojiInfo { emoji: "\u{1f50e}", name: "magnifying glass tilted right", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["mag_right"] },
+    EmojiInfo { emoji: "\u{1f56f}\u{fe0f}", name: "candle", group: "Objects", subgroup: "light & video", version: 0.7, shortcodes: &["candle"] },
+    EmojiInfo { emoji: "\u{1f4a1}", name: "light bulb", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["bulb"] },
+    EmojiInfo { emoji: "\u{1f526}", name: "flashlight", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["flashlight"] },
+    EmojiInfo { emoji: "\u{1f3ee}", name: "red paper lantern", group: "Objects", subgroup: "light & video", version: 0.6, shortcodes: &["izakaya_lantern", "lantern"] },
+    EmojiInfo { emoji: "\u{1fa94}", name: "diya lamp", group: "Objects", subgroup: "light & video", version: 12.0, shortcodes: &["diya_lamp"] },
+    EmojiInfo { emoji: "\u{1f4d4}", name: "notebook with decorative cover", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["notebook_with_decorative_cover"] },
+    EmojiInfo { emoji: "\u{1f4d5}", name: "closed book", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["closed_book"] },
+    EmojiInfo { emoji: "\u{1f4d6}", name: "open book", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["book", "open_book"] },
+    EmojiInfo { emoji: "\u{1f4d7}", name: "green book", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["green_book"] },
+    EmojiInfo { emoji: "\u{1f4d8}", name: "blue book", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["blue_book"] },
+    EmojiInfo { emoji: "\u{1f4d9}", name: "orange book", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["orange_book"] },
+    EmojiInfo { emoji: "\u{1f4da}", name: "books", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["books"] },
+    EmojiInfo { emoji: "\u{1f4d3}", name: "notebook", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["notebook"] },
+    EmojiInfo { emoji: "\u{1f4d2}", name: "ledger", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["ledger"] },
+    EmojiInfo { emoji: "\u{1f4c3}", name: "page with curl", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["page_with_curl"] },
+    EmojiInfo { emoji: "\u{1f4dc}", name: "scroll", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["scroll"] },
+    EmojiInfo { emoji: "\u{1f4c4}", name: "page facing up", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["page_facing_up"] },
+    EmojiInfo { emoji: "\u{1f4f0}", name: "newspaper", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["newspaper"] },
+    EmojiInfo { emoji: "\u{1f5de}\u{fe0f}", name: "rolled-up newspaper", group: "Objects", subgroup: "book-paper", version: 0.7, shortcodes: &["newspaper_roll"] },
+    EmojiInfo { emoji: "\u{1f4d1}", name: "bookmark tabs", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["bookmark_tabs"] },
+    EmojiInfo { emoji: "\u{1f516}", name: "bookmark", group: "Objects", subgroup: "book-paper", version: 0.6, shortcodes: &["bookmark"] },
+    EmojiInfo { emoji: "\u{1f3f7}\u{fe0f}", name: "label", group: "Objects", subgroup: "book-paper", version: 0.7, shortcodes: &["label"] },
+    EmojiInfo { emoji: "\u{1f4b0}", name: "money bag", group: "Objects", subgroup: "money", version: 0.6, shortcodes: &["moneybag"] },
+    EmojiInfo { emoji: "\u{1fa99}", name: "coin", group: "Objects", subgroup: "money", version: 13.0, shortcodes: &["coin"] },
+    EmojiInfo { emoji: "\u{1f4b4}", name: "yen banknote", group: "Objects", subgroup: "money", version: 0.6, shortcodes: &["yen"] },
+    EmojiInfo { emoji: "\u{1f4b5}", name: "dollar banknote", group: "Objects", subgroup: "money", version: 0.6, shortcodes: &["dollar"] },
+    EmojiInfo { emoji: "\u{1f4b6}", name: "euro banknote", group: "Objects", subgroup: "money", version: 1.0, shortcodes: &["euro"] },
+    EmojiInfo { emoji: "\u{1f4b7}", name: "pound banknote", group: "Objects", subgroup: "money", version: 1.0, shortcodes: &["pound"] },
+    EmojiInfo { emoji: "\u{1f4b8}", name: "money with wings", group: "Objects", subgroup: "money", version: 0.6, shortcodes: &["money_with_wings"] },
+    EmojiInfo { emoji: "\u{1f4b3}", name: "credit card", group: "Objects", subgroup: "money", version: 0.6, shortcodes: &["credit_card"] },
+    EmojiInfo { emoji: "\u{1f9fe}", name: "receipt", group: "Objects", subgroup: "money", version: 11.0, shortcodes: &["receipt"] },
+    EmojiInfo { emoji: "\u{1f4b9}", name: "chart increasing with yen", group: "Objects", subgroup: "money", version: 0.6, shortcodes: &["chart"] },
+    EmojiInfo { emoji: "\u{2709}\u{fe0f}", name: "envelope", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["envelope"] },
+    EmojiInfo { emoji: "\u{1f4e7}", name: "e-mail", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["email", "e-mail"] },
+    EmojiInfo { emoji: "\u{1f4e8}", name: "incoming envelope", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["incoming_envelope"] },
+    EmojiInfo { emoji: "\u{1f4e9}", name: "envelope with arrow", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["envelope_with_arrow"] },
+    EmojiInfo { emoji: "\u{1f4e4}", name: "outbox tray", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["outbox_tray"] },
+    EmojiInfo { emoji: "\u{1f4e5}", name: "inbox tray", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["inbox_tray"] },
+    EmojiInfo { emoji: "\u{1f4e6}", name: "package", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["package"] },
+    EmojiInfo { emoji: "\u{1f4eb}", name: "closed mailbox with raised flag", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["mailbox"] },
+    EmojiInfo { emoji: "\u{1f4ea}", name: "closed mailbox with lowered flag", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["mailbox_closed"] },
+    EmojiInfo { emoji: "\u{1f4ec}", name: "open mailbox with raised flag", group: "Objects", subgroup: "mail", version: 0.7, shortcodes: &["mailbox_with_mail"] },
+    EmojiInfo { emoji: "\u{1f4ed}", name: "open mailbox with lowered flag", group: "Objects", subgroup: "mail", version: 0.7, shortcodes: &["mailbox_with_no_mail"] },
+    EmojiInfo { emoji: "\u{1f4ee}", name: "postbox", group: "Objects", subgroup: "mail", version: 0.6, shortcodes: &["postbox"] },
+    EmojiInfo { emoji: "\u{1f5f3}\u{fe0f}", name: "ballot box with ballot", group: "Objects", subgroup: "mail", version: 0.7, shortcodes: &["ballot_box"] },
+    EmojiInfo { emoji: "\u{270f}\u{fe0f}", name: "pencil", group: "Objects", subgroup: "writing", version: 0.6, shortcodes: &["pencil2"] },
+    EmojiInfo { emoji: "\u{2712}\u{fe0f}", name: "black nib", group: "Objects", subgroup: "writing", version: 0.6, shortcodes: &["black_nib"] },
+    EmojiInfo { emoji: "\u{1f58b}\u{fe0f}", name: "fountain pen", group: "Objects", subgroup: "writing", version: 0.7, shortcodes: &["fountain_pen"] },
+    EmojiInfo { emoji: "\u{1f58a}\u{fe0f}", name: "pen", group: "Objects", subgroup: "writing", version: 0.7, shortcodes: &["pen"] },
+    EmojiInfo { emoji: "\u{1f58c}\u{fe0f}", name: "paintbrush", group: "Objects", subgroup: "writing", version: 0.7, shortcodes: &["paintbrush"] },
+    EmojiInfo { emoji: "\u{1f58d}\u{fe0f}", name: "crayon", group: "Objects", subgroup: "writing", version: 0.7, shortcodes: &["crayon"] },
+    EmojiInfo { emoji: "\u{1f4dd}", name: "memo", group: "Objects", subgroup: "writing", version: 0.6, shortcodes: &["memo", "pencil"] },
+    EmojiInfo { emoji: "\u{1f4bc}", name: "briefcase", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["briefcase"] },
+    EmojiInfo { emoji: "\u{1f4c1}", name: "file folder", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["file_folder"] },
+    EmojiInfo { emoji: "\u{1f4c2}", name: "open file folder", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["open_file_folder"] },
+    EmojiInfo { emoji: "\u{1f5c2}\u{fe0f}", name: "card index dividers", group: "Objects", subgroup: "office", version: 0.7, shortcodes: &["card_index_dividers"] },
+    EmojiInfo { emoji: "\u{1f4c5}", name: "calendar", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["date"] },
+    EmojiInfo { emoji: "\u{1f4c6}", name: "tear-off calendar", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["calendar"] },
+    EmojiInfo { emoji: "\u{1f5d2}\u{fe0f}", name: "spiral notepad", group: "Objects", subgroup: "office", version: 0.7, shortcodes: &["spiral_notepad"] },
+    EmojiInfo { emoji: "\u{1f5d3}\u{fe0f}", name: "spiral calendar", group: "Objects", subgroup: "office", version: 0.7, shortcodes: &["spiral_calendar"] },
+    EmojiInfo { emoji: "\u{1f4c7}", name: "card index", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["card_index"] },
+    EmojiInfo { emoji: "\u{1f4c8}", name: "chart increasing", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["chart_with_upwards_trend"] },
+    EmojiInfo { emoji: "\u{1f4c9}", name: "chart decreasing", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["chart_with_downwards_trend"] },
+    EmojiInfo { emoji: "\u{1f4ca}", name: "bar chart", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["bar_chart"] },
+    EmojiInfo { emoji: "\u{1f4cb}", name: "clipboard", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["clipboard"] },
+    EmojiInfo { emoji: "\u{1f4cc}", name: "pushpin", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["pushpin"] },
+    EmojiInfo { emoji: "\u{1f4cd}", name: "round pushpin", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["round_pushpin"] },
+    EmojiInfo { emoji: "\u{1f4ce}", name: "paperclip", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["paperclip"] },
+    EmojiInfo { emoji: "\u{1f587}\u{fe0f}", name: "linked paperclips", group: "Objects", subgroup: "office", version: 0.7, shortcodes: &["paperclips"] },
+    EmojiInfo { emoji: "\u{1f4cf}", name: "straight ruler", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["straight_ruler"] },
+    EmojiInfo { emoji: "\u{1f4d0}", name: "triangular ruler", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["triangular_ruler"] },
+    EmojiInfo { emoji: "\u{2702}\u{fe0f}", name: "scissors", group: "Objects", subgroup: "office", version: 0.6, shortcodes: &["scissors"] },
+    EmojiInfo { emoji: "\u{1f5c3}\u{fe0f}", name: "card file box", group: "Objects", subgroup: "office", version: 0.7, shortcodes: &["card_file_box"] },
+    EmojiInfo { emoji: "\u{1f5c4}\u{fe0f}", name: "file cabinet", group: "Objects", subgroup: "office", version: 0.7, shortcodes: &["file_cabinet"] },
+    EmojiInfo { emoji: "\u{1f5d1}\u{fe0f}", name: "wastebasket", group: "Objects", subgroup: "office", version: 0.7, shortcodes: &["wastebasket"] },
+    EmojiInfo { emoji: "\u{1f512}", name: "locked", group: "Objects", subgroup: "lock", version: 0.6, shortcodes: &["lock"] },
+    EmojiInfo { emoji: "\u{1f513}", name: "unlocked", group: "Objects", subgroup: "lock", version: 0.6, shortcodes: &["unlock"] },
+    EmojiInfo { emoji: "\u{1f50f}", name: "locked with pen", group: "Objects", subgroup: "lock", version: 0.6, shortcodes: &["lock_with_ink_pen"] },
+    EmojiInfo { emoji: "\u{1f510}", name: "locked with key", group: "Objects", subgroup: "lock", version: 0.6, shortcodes: &["closed_lock_with_key"] },
+    EmojiInfo { emoji: "\u{1f511}", name: "key", group: "Objects", subgroup: "lock", version: 0.6, shortcodes: &["key"] },
+    EmojiInfo { emoji: "\u{1f5dd}\u{fe0f}", name: "old key", group: "Objects", subgroup: "lock", version: 0.7, shortcodes: &["old_key"] },
+    EmojiInfo { emoji: "\u{1f528}", name: "hammer", group: "Objects", subgroup: "tool", version: 0.6, shortcodes: &["hammer"] },
+    EmojiInfo { emoji: "\u{1fa93}", name: "axe", group: "Objects", subgroup: "tool", version: 12.0, shortcodes: &["axe"] },
+    EmojiInfo { emoji: "\u{26cf}\u{fe0f}", name: "pick", group: "Objects", subgroup: "tool", version: 0.7, shortcodes: &["pick"] },
+    EmojiInfo { emoji: "\u{2692}\u{fe0f}", name: "hammer and pick", group: "Objects", subgroup: "tool", version: 1.0, shortcodes: &["hammer_and_pick"] },
+    EmojiInfo { emoji: "\u{1f6e0}\u{fe0f}", name: "hammer and wrench", group: "Objects", subgroup: "tool", version: 0.7, shortcodes: &["hammer_and_wrench"] },
+    EmojiInfo { emoji: "\u{1f5e1}\u{fe0f}", name: "dagger", group: "Objects", subgroup: "tool", version: 0.7, shortcodes: &["dagger"] },
+    EmojiInfo { emoji: "\u{2694}\u{fe0f}", name: "crossed swords", group: "Objects", subgroup: "tool", version: 1.0, shortcodes: &["crossed_swords"] },
+    EmojiInfo { emoji: "\u{1f52b}", name: "water pistol", group: "Objects", subgroup: "tool", version: 0.6, shortcodes: &["gun"] },
+    EmojiInfo { emoji: "\u{1fa83}", name: "boomerang", group: "Objects", subgroup: "tool", version: 13.0, shortcodes: &["boomerang"] },
+    EmojiInfo { emoji: "\u{1f3f9}", name: "bow and arrow", group: "Objects", subgroup: "tool", version: 1.0, shortcodes: &["bow_and_arrow"] },
+    EmojiInfo { emoji: "\u{1f6e1}\u{fe0f}", name: "shield", group: "Objects", subgroup: "tool", version: 0.7, shortcodes: &["shield"] },
+    EmojiInfo { emoji: "\u{1fa9a}", name: "carpentry saw", group: "Objects", subgroup: "tool", version: 13.0, shortcodes: &["carpentry_saw"] },
+    EmojiInfo { emoji: "\u{1f527}", name: "wrench", group: "Objects", subgroup: "tool", version: 0.6, shortcodes: &["wrench"] },
+    EmojiInfo { emoji: "\u{1fa9b}", name: "screwdriver", group: "Objects", subgroup: "tool", version: 13.0, shortcodes: &["screwdriver"] },
+    EmojiInfo { emoji: "\u{1f529}", name: "nut and bolt", group: "Objects", subgroup: "tool", version: 0.6, shortcodes: &["nut_and_bolt"] },
+    EmojiInfo { emoji: "\u{2699}\u{fe0f}", name: "gear", group: "Objects", subgroup: "tool", version: 1.0, shortcodes: &["gear"] },
+    EmojiInfo { emoji: "\u{1f5dc}\u{fe0f}", name: "clamp", group: "Objects", subgroup: "tool", version: 0.7, shortcodes: &["clamp"] },
+    EmojiInfo { emoji: "\u{2696}\u{fe0f}", name: "balance scale", group: "Objects", subgroup: "tool", version: 1.0, shortcodes: &["balance_scale"] },
+    EmojiInfo { emoji: "\u{1f9af}", name: "white cane", group: "Objects", subgroup: "tool", version: 12.0, shortcodes: &["probing_cane"] },
+    EmojiInfo { emoji: "\u{1f517}", name: "link", group: "Objects", subgroup: "tool", version: 0.6, shortcodes: &["link"] },
+    EmojiInfo { emoji: "\u{26d3}\u{fe0f}", name: "chains", group: "Objects", subgroup: "tool", version: 0.7, shortcodes: &["chains"] },
+    EmojiInfo { emoji: "\u{1fa9d}", name: "hook", group: "Objects", subgroup: "tool", version: 13.0, shortcodes: &["hook"] },
+    EmojiInfo { emoji: "\u{1f9f0}", name: "toolbox", group: "Objects", subgroup: "tool", version: 11.0, shortcodes: &["toolbox"] },
+    EmojiInfo { emoji: "\u{1f9f2}", name: "magnet", group: "Objects", subgroup: "tool", version: 11.0, shortcodes: &["magnet"] },
+    EmojiInfo { emoji: "\u{1fa9c}", name: "ladder", group: "Objects", subgroup: "tool", version: 13.0, shortcodes: &["ladder"] },
+    EmojiInfo { emoji: "\u{2697}\u{fe0f}", name: "alembic", group: "Objects", subgroup: "science", version: 1.0, shortcodes: &["alembic"] },
+    EmojiInfo { emoji: "\u{1f9ea}", name: "test tube", group: "Objects", subgroup: "science", version: 11.0, shortcodes: &["test_tube"] },
+    EmojiInfo { emoji: "\u{1f9eb}", name: "petri dish", group: "Objects", subgroup: "science", version: 11.0, shortcodes: &["petri_dish"] },
+    EmojiInfo { emoji: "\u{1f9ec}", name: "dna", group: "Objects", subgroup: "science", version: 11.0, shortcodes: &["dna"] },
+    EmojiInfo { emoji: "\u{1f52c}", name: "microscope", group: "Objects", subgroup: "science", version: 1.0, shortcodes: &["microscope"] },
+    EmojiInfo { emoji: "\u{1f52d}", name: "telescope", group: "Objects", subgroup: "science", version: 1.0, shortcodes: &["telescope"] },
+    EmojiInfo { emoji: "\u{1f4e1}", name: "satellite antenna", group: "Objects", subgroup: "science", version: 0.6, shortcodes: &["satellite"] },
+    EmojiInfo { emoji: "\u{1f489}", name: "syringe", group: "Objects", subgroup: "medical", version: 0.6, shortcodes: &["syringe"] },
+    EmojiInfo { emoji: "\u{1fa78}", name: "drop of blood", group: "Objects", subgroup: "medical", version: 12.0, shortcodes: &["drop_of_blood"] },
+    EmojiInfo { emoji: "\u{1f48a}", name: "pill", group: "Objects", subgroup: "medical", version: 0.6, shortcodes: &["pill"] },
+    EmojiInfo { emoji: "\u{1fa79}", name: "adhesive bandage", group: "Objects", subgroup: "medical", version: 12.0, shortcodes: &["adhesive_bandage"] },
+    EmojiInfo { emoji: "\u{1fa7a}", name: "stethoscope", group: "Objects", subgroup: "medical", version: 12.0, shortcodes: &["stethoscope"] },
+    EmojiInfo { emoji: "\u{1f6aa}", name: "door", group: "Objects", subgroup: "household", version: 0.6, shortcodes: &["door"] },
+    EmojiInfo { emoji: "\u{1f6d7}", name: "elevator", group: "Objects", subgroup: "household", version: 13.0, shortcodes: &["elevator"] },
+    EmojiInfo { emoji: "\u{1fa9e}", name: "mirror", group: "Objects", subgroup: "household", version: 13.0, shortcodes: &["mirror"] },
+    EmojiInfo { emoji: "\u{1fa9f}", name: "window", group: "Objects", subgroup: "household", version: 13.0, shortcodes: &["window"] },
+    EmojiInfo { emoji: "\u{1f6cf}\u{fe0f}", name: "bed", group: "Objects", subgroup: "household", version: 0.7, shortcodes: &["bed"] },
+    EmojiInfo { emoji: "\u{1f6cb}\u{fe0f}", name: "couch and lamp", group: "Objects", subgroup: "household", version: 0.7, shortcodes: &["couch_and_lamp"] },
+    EmojiInfo { emoji: "\u{1fa91}", name: "chair", group: "Objects", subgroup: "household", version: 12.0, shortcodes: &["chair"] },
+    EmojiInfo { emoji: "\u{1f6bd}", name: "toilet", group: "Objects", subgroup: "household", version: 0.6, shortcodes: &["toilet"] },
+    EmojiInfo { emoji: "\u{1faa0}", name: "plunger", group: "Objects", subgroup: "household", version: 13.0, shortcodes: &["plunger"] },
+    EmojiInfo { emoji: "\u{1f6bf}", name: "shower", group: "Objects", subgroup: "household", version: 1.0, shortcodes: &["shower"] },
+    EmojiInfo { emoji: "\u{1f6c1}", name: "bathtub", group: "Objects", subgroup: "household", version: 1.0, shortcodes: &["bathtub"] },
+    EmojiInfo { emoji: "\u{1faa4}", name: "mouse trap", group: "Objects", subgroup: "household", version: 13.0, shortcodes: &["mouse_trap"] },
+    EmojiInfo { emoji: "\u{1fa92}", name: "razor", group: "Objects", subgroup: "household", version: 12.0, shortcodes: &["razor"] },
+    EmojiInfo { emoji: "\u{1f9f4}", name: "lotion bottle", group: "Objects", subgroup: "household", version: 11.0, shortcodes: &["lotion_bottle"] },
+    EmojiInfo { emoji: "\u{1f9f7}", name: "safety pin", group: "Objects", subgroup: "household", version: 11.0, shortcodes: &["safety_pin"] },
+    EmojiInfo { emoji: "\u{1f9f9}", name: "broom", group: "Objects", subgroup: "household", version: 11.0, shortcodes: &["broom"] },
+    EmojiInfo { emoji: "\u{1f9fa}", name: "basket", group: "Objects", subgroup: "household", version: 11.0, shortcodes: &["basket"] },
+    EmojiInfo { emoji: "\u{1f9fb}", name: "roll of paper", group: "Objects", subgroup: "household", version: 11.0, shortcodes: &["roll_of_paper"] },
+    EmojiInfo { emoji: "\u{1faa3}", name: "bucket", group: "Objects", subgroup: "household", version: 13.0, shortcodes: &["bucket"] },
+    EmojiInfo { emoji: "\u{1f9fc}", name: "soap", group: "Objects", subgroup: "household", version: 11.0, shortcodes: &["soap"] },
+    EmojiInfo { emoji: "\u{1faa5}", name: "toothbrush", group: "Objects", subgroup: "household", version: 13.0, shortcodes: &["toothbrush"] },
+    EmojiInfo { emoji: "\u{1f9fd}", name: "sponge", group: "Objects", subgroup: "household", version: 11.0, shortcodes: &["sponge"] },
+    EmojiInfo { emoji: "\u{1f9ef}", name: "fire extinguisher", group: "Objects", subgroup: "household", version: 11.0, shortcodes: &["fire_extinguisher"] },
+    EmojiInfo { emoji: "\u{1f6d2}", name: "shopping cart", group: "Objects", subgroup: "household", version: 3.0, shortcodes: &["shopping_cart"] },
+    EmojiInfo { emoji: "\u{1f6ac}", name: "cigarette", group: "Objects", subgroup: "other-object", version: 0.6, shortcodes: &["smoking"] },
+    EmojiInfo { emoji: "\u{26b0}\u{fe0f}", name: "coffin", group: "Objects", subgroup: "other-object", version: 1.0, shortcodes: &["coffin"] },
+    EmojiInfo { emoji: "\u{1faa6}", name: "headstone", group: "Objects", subgroup: "other-object", version: 13.0, shortcodes: &["headstone"] },
+    EmojiInfo { emoji: "\u{26b1}\u{fe0f}", name: "funeral urn", group: "Objects", subgroup: "other-object", version: 1.0, shortcodes: &["funeral_urn"] },
+    EmojiInfo { emoji: "\u{1f5ff}", name: "moai", group: "Objects", subgroup: "other-object", version: 0.6, shortcodes: &["moyai"] },
+    EmojiInfo { emoji: "\u{1faa7}", name: "placard", group: "Objects", subgroup: "other-object", version: 13.0, shortcodes: &["placard"] },
+    EmojiInfo { emoji: "\u{1f3e7}", name: "ATM sign", group: "Symbols", subgroup: "transport-sign", version: 0.6, shortcodes: &["atm"] },
+    EmojiInfo { emoji: "\u{1f6ae}", name: "litter in bin sign", group: "Symbols", subgroup: "transport-sign", version: 1.0, shortcodes: &["put_litter_in_its_place"] },
+    EmojiInfo { emoji: "\u{1f6b0}", name: "potable water", group: "Symbols", subgroup: "transport-sign", version: 1.0, shortcodes: &["potable_water"] },
+    EmojiInfo { emoji: "\u{267f}", name: "wheelchair symbol", group: "Symbols", subgroup: "transport-sign", version: 0.6, shortcodes: &["wheelchair"] },
+    EmojiInfo { emoji: "\u{1f6b9}", name: "men’s room", group: "Symbols", subgroup: "transport-sign", version: 0.6, shortcodes: &["mens"] },
+    EmojiInfo { emoji: "\u{1f6ba}", name: "women’s room", group: "Symbols", subgroup: "transport-sign", version: 0.6, shortcodes: &["womens"] },
+    EmojiInfo { emoji: "\u{1f6bb}", name: "restroom", group: "Symbols", subgroup: "transport-sign", version: 0.6, shortcodes: &["restroom"] },
+    EmojiInfo { emoji: "\u{1f6bc}", name: "baby symbol", group: "Symbols", subgroup: "transport-sign", version: 0.6, shortcodes: &["baby_symbol"] },
+    EmojiInfo { emoji: "\u{1f6be}", name: "water closet", group: "Symbols", subgroup: "transport-sign", version: 0.6, shortcodes: &["wc"] },
+    EmojiInfo { emoji: "\u{1f6c2}", name: "passport control", group: "Symbols", subgroup: "transport-sign", version: 1.0, shortcodes: &["passport_control"] },
+    EmojiInfo { emoji: "\u{1f6c3}", name: "customs", group: "Symbols", subgroup: "transport-sign", version: 1.0, shortcodes: &["customs"] },
+    EmojiInfo { emoji: "\u{1f6c4}", name: "baggage claim", group: "Symbols", subgroup: "transport-sign", version: 1.0, shortcodes: &["baggage_claim"] },
+    EmojiInfo { emoji: "\u{1f6c5}", name: "left luggage", group: "Symbols", subgroup: "transport-sign", version: 1.0, shortcodes: &["left_luggage"] },
+    EmojiInfo { emoji: "\u{26a0}\u{fe0f}", name: "warning", group: "Symbols", subgroup: "warning", version: 0.6, shortcodes: &["warning"] },
+    EmojiInfo { emoji: "\u{1f6b8}", name: "children crossing", group: "Symbols", subgroup: "warning", version: 1.0, shortcodes: &["children_crossing"] },
+    EmojiInfo { emoji: "\u{26d4}", name: "no entry", group: "Symbols", subgroup: "warning", version: 0.6, shortcodes: &["no_entry"] },
+    EmojiInfo { emoji: "\u{1f6ab}", name: "prohibited", group: "Symbols", subgroup: "warning", version: 0.6, shortcodes: &["no_entry_sign"] },
+    EmojiInfo { emoji: "\u{1f6b3}", name: "no bicycles", group: "Symbols", subgroup: "warning", version: 1.0, shortcodes: &["no_bicycles"] },
+    EmojiInfo { emoji: "\u{1f6ad}", name: "no smoking", group: "Symbols", subgroup: "warning", version: 0.6, shortcodes: &["no_smoking"] },
+    EmojiInfo { emoji: "\u{1f6af}", name: "no littering", group: "Symbols", subgroup: "warning", version: 1.0, shortcodes: &["do_not_litter"] },
+    EmojiInfo { emoji: "\u{1f6b1}", name: "non-potable water", group: "Symbols", subgroup: "warning", version: 1.0, shortcodes: &["non-potable_water"] },
+    EmojiInfo { emoji: "\u{1f6b7}", name: "no pedestrians", group: "Symbols", subgroup: "warning", version: 1.0, shortcodes: &["no_pedestrians"] },
+    EmojiInfo { emoji: "\u{1f4f5}", name: "no mobile phones", group: "Symbols", subgroup: "warning", version: 1.0, shortcodes: &["no_mobile_phones"] },
+    EmojiInfo { emoji: "\u{1f51e}", name: "no one under eighteen", group: "Symbols", subgroup: "warning", version: 0.6, shortcodes: &["underage"] },
+    EmojiInfo { emoji: "\u{2622}\u{fe0f}", name: "radioactive", group: "Symbols", subgroup: "warning", version: 1.0, shortcodes: &["radioactive"] },
+    EmojiInfo { emoji: "\u{2623}\u{fe0f}", name: "biohazard", group: "Symbols", subgroup: "warning", version: 1.0, shortcodes: &["biohazard"] },
+    EmojiInfo { emoji: "\u{2b06}\u{fe0f}", name: "up arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_up"] },
+    EmojiInfo { emoji: "\u{2197}\u{fe0f}", name: "up-right arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_upper_right"] },
+    EmojiInfo { emoji: "\u{27a1}\u{fe0f}", name: "right arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_right"] },
+    EmojiInfo { emoji: "\u{2198}\u{fe0f}", name: "down-right arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_lower_right"] },
+    EmojiInfo { emoji: "\u{2b07}\u{fe0f}", name: "down arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_down"] },
+    EmojiInfo { emoji: "\u{2199}\u{fe0f}", name: "down-left arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_lower_left"] },
+    EmojiInfo { emoji: "\u{2b05}\u{fe0f}", name: "left arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_left"] },
+    EmojiInfo { emoji: "\u{2196}\u{fe0f}", name: "up-left arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_upper_left"] },
+    EmojiInfo { emoji: "\u{2195}\u{fe0f}", name: "up-down arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_up_down"] },
+    EmojiInfo { emoji: "\u{2194}\u{fe0f}", name: "left-right arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["left_right_arrow"] },
+    EmojiInfo { emoji: "\u{21a9}\u{fe0f}", name: "right arrow curving left", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["leftwards_arrow_with_hook"] },
+    EmojiInfo { emoji: "\u{21aa}\u{fe0f}", name: "left arrow curving right", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_right_hook"] },
+    EmojiInfo { emoji: "\u{2934}\u{fe0f}", name: "right arrow curving up", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_heading_up"] },
+    EmojiInfo { emoji: "\u{2935}\u{fe0f}", name: "right arrow curving down", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrow_heading_down"] },
+    EmojiInfo { emoji: "\u{1f503}", name: "clockwise vertical arrows", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["arrows_clockwise"] },
+    EmojiInfo { emoji: "\u{1f504}", name: "counterclockwise arrows button", group: "Symbols", subgroup: "arrow", version: 1.0, shortcodes: &["arrows_counterclockwise"] },
+    EmojiInfo { emoji: "\u{1f519}", name: "BACK arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["back"] },
+    EmojiInfo { emoji: "\u{1f51a}", name: "END arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["end"] },
+    EmojiInfo { emoji: "\u{1f51b}", name: "ON! arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["on"] },
+    EmojiInfo { emoji: "\u{1f51c}", name: "SOON arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["soon"] },
+    EmojiInfo { emoji: "\u{1f51d}", name: "TOP arrow", group: "Symbols", subgroup: "arrow", version: 0.6, shortcodes: &["top"] },
+    EmojiInfo { emoji: "\u{1f6d0}", name: "place of worship", group: "Symbols", subgroup: "religion", version: 1.0, shortcodes: &["place_of_worship"] },
+    EmojiInfo { emoji: "\u{269b}\u{fe0f}", name: "atom symbol", group: "Symbols", subgroup: "religion", version: 1.0, shortcodes: &["atom_symbol"] },
+    EmojiInfo { emoji: "\u{1f549}\u{fe0f}", name: "om", group: "Symbols", subgroup: "religion", version: 0.7, shortcodes: &["om"] },
+    EmojiInfo { emoji: "\u{2721}\u{fe0f}", name: "star of David", group: "Symbols", subgroup: "religion", version: 0.7, shortcodes: &["star_of_david"] },
+    EmojiInfo { emoji: "\u{2638}\u{fe0f}", name: "wheel of dharma", group: "Symbols", subgroup: "religion", version: 0.7, shortcodes: &["wheel_of_dharma"] },
+    EmojiInfo { emoji: "\u{262f}\u{fe0f}", name: "yin yang", group: "Symbols", subgroup: "religion", version: 0.7, shortcodes: &["yin_yang"] },
+    EmojiInfo { emoji: "\u{271d}\u{fe0f}", name: "latin cross", group: "Symbols", subgroup: "religion", version: 0.7, shortcodes: &["latin_cross"] },
+    EmojiInfo { emoji: "\u{2626}\u{fe0f}", name: "orthodox cross", group: "Symbols", subgroup: "religion", version: 1.0, shortcodes: &["orthodox_cross"] },
+    EmojiInfo { emoji: "\u{262a}\u{fe0f}", name: "star and crescent", group: "Symbols", subgroup: "religion", version: 0.7, shortcodes: &["star_and_crescent"] },
+    EmojiInfo { emoji: "\u{262e}\u{fe0f}", name: "peace symbol", group: "Symbols", subgroup: "religion", version: 1.0, shortcodes: &["peace_symbol"] },
+    EmojiInfo { emoji: "\u{1f54e}", name: "menorah", group: "Symbols", subgroup: "religion", version: 1.0, shortcodes: &["menorah"] },
+    EmojiInfo { emoji: "\u{1f52f}", name: "dotted six-pointed star", group: "Symbols", subgroup: "religion", version: 0.6, shortcodes: &["six_pointed_star"] },
+    EmojiInfo { emoji: "\u{2648}", name: "Aries", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["aries"] },
+    EmojiInfo { emoji: "\u{2649}", name: "Taurus", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["taurus"] },
+    EmojiInfo { emoji: "\u{264a}", name: "Gemini", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["gemini"] },
+    EmojiInfo { emoji: "\u{264b}", name: "Cancer", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["cancer"] },
+    EmojiInfo { emoji: "\u{264c}", name: "Leo", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["leo"] },
+    EmojiInfo { emoji: "\u{264d}", name: "Virgo", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["virgo"] },
+    EmojiInfo { emoji: "\u{264e}", name: "Libra", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["libra"] },
+    EmojiInfo { emoji: "\u{264f}", name: "Scorpio", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["scorpius"] },
+    EmojiInfo { emoji: "\u{2650}", name: "Sagittarius", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["sagittarius"] },
+    EmojiInfo { emoji: "\u{2651}", name: "Capricorn", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["capricorn"] },
+    EmojiInfo { emoji: "\u{2652}", name: "Aquarius", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["aquarius"] },
+    EmojiInfo { emoji: "\u{2653}", name: "Pisces", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["pisces"] },
+    EmojiInfo { emoji: "\u{26ce}", name: "Ophiuchus", group: "Symbols", subgroup: "zodiac", version: 0.6, shortcodes: &["ophiuchus"] },
+    EmojiInfo { emoji: "\u{1f500}", name: "shuffle tracks button", group: "Symbols", subgroup: "av-symbol", version: 1.0, shortcodes: &["twisted_rightwards_arrows"] },
+    EmojiInfo { emoji: "\u{1f501}", name: "repeat button", group: "Symbols", subgroup: "av-symbol", version: 1.0, shortcodes: &["repeat"] },
+    EmojiInfo { emoji: "\u{1f502}", name: "repeat single button", group: "Symbols", subgroup: "av-symbol", version: 1.0, shortcodes: &["repeat_one"] },
+    EmojiInfo { emoji: "\u{25b6}\u{fe0f}", name: "play button", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["arrow_forward"] },
+    EmojiInfo { emoji: "\u{23e9}", name: "fast-forward button", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["fast_forward"] },
+    EmojiInfo { emoji: "\u{23ed}\u{fe0f}", name: "next track button", group: "Symbols", subgroup: "av-symbol", version: 0.7, shortcodes: &["next_track_button"] },
+    EmojiInfo { emoji: "\u{23ef}\u{fe0f}", name: "play or pause button", group: "Symbols", subgroup: "av-symbol", version: 1.0, shortcodes: &["play_or_pause_button"] },
+    EmojiInfo { emoji: "\u{25c0}\u{fe0f}", name: "reverse button", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["arrow_backward"] },
+    EmojiInfo { emoji: "\u{23ea}", name: "fast reverse button", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["rewind"] },
+    EmojiInfo { emoji: "\u{23ee}\u{fe0f}", name: "last track button", group: "Symbols", subgroup: "av-symbol", version: 0.7, shortcodes: &["previous_track_button"] },
+    EmojiInfo { emoji: "\u{1f53c}", name: "upwards button", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["arrow_up_small"] },
+    EmojiInfo { emoji: "\u{23eb}", name: "fast up button", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["arrow_double_up"] },
+    EmojiInfo { emoji: "\u{1f53d}", name: "downwards button", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["arrow_down_small"] },
+    EmojiInfo { emoji: "\u{23ec}", name: "fast down button", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["arrow_double_down"] },
+    EmojiInfo { emoji: "\u{23f8}\u{fe0f}", name: "pause button", group: "Symbols", subgroup: "av-symbol", version: 0.7, shortcodes: &["pause_button"] },
+    EmojiInfo { emoji: "\u{23f9}\u{fe0f}", name: "stop button", group: "Symbols", subgroup: "av-symbol", version: 0.7, shortcodes: &["stop_button"] },
+    EmojiInfo { emoji: "\u{23fa}\u{fe0f}", name: "record button", group: "Symbols", subgroup: "av-symbol", version: 0.7, shortcodes: &["record_button"] },
+    EmojiInfo { emoji: "\u{23cf}\u{fe0f}", name: "eject button", group: "Symbols", subgroup: "av-symbol", version: 1.0, shortcodes: &["eject_button"] },
+    EmojiInfo { emoji: "\u{1f3a6}", name: "cinema", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["cinema"] },
+    EmojiInfo { emoji: "\u{1f505}", name: "dim button", group: "Symbols", subgroup: "av-symbol", version: 1.0, shortcodes: &["low_brightness"] },
+    EmojiInfo { emoji: "\u{1f506}", name: "bright button", group: "Symbols", subgroup: "av-symbol", version: 1.0, shortcodes: &["high_brightness"] },
+    EmojiInfo { emoji: "\u{1f4f6}", name: "antenna bars", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["signal_strength"] },
+    EmojiInfo { emoji: "\u{1f4f3}", name: "vibration mode", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["vibration_mode"] },
+    EmojiInfo { emoji: "\u{1f4f4}", name: "mobile phone off", group: "Symbols", subgroup: "av-symbol", version: 0.6, shortcodes: &["mobile_phone_off"] },
+    EmojiInfo { emoji: "\u{2640}\u{fe0f}", name: "female sign", group: "Symbols", subgroup: "gender", version: 4.0, shortcodes: &["female_sign"] },
+    EmojiInfo { emoji: "\u{2642}\u{fe0f}", name: "male sign", group: "Symbols", subgroup: "gender", version: 4.0, shortcodes: &["male_sign"] },
+    EmojiInfo { emoji: "\u{26a7}\u{fe0f}", name: "transgender symbol", group: "Symbols", subgroup: "gender", version: 13.0, shortcodes: &["transgender_symbol"] },
+    EmojiInfo { emoji: "\u{2716}\u{fe0f}", name: "multiply", group: "Symbols", subgroup: "math", version: 0.6, shortcodes: &["heavy_multiplication_x"] },
+    EmojiInfo { emoji: "\u{2795}", name: "plus", group: "Symbols", subgroup: "math", version: 0.6, shortcodes: &["heavy_plus_sign"] },
+    EmojiInfo { emoji: "\u{2796}", name: "minus", group: "Symbols", subgroup: "math", version: 0.6, shortcodes: &["heavy_minus_sign"] },
+    EmojiInfo { emoji: "\u{2797}", name: "divide", group: "Symbols", subgroup: "math", version: 0.6, shortcodes: &["heavy_division_sign"] },
+    EmojiInfo { emoji: "\u{267e}\u{fe0f}", name: "infinity", group: "Symbols", subgroup: "math", version: 11.0, shortcodes: &["infinity"] },
+    EmojiInfo { emoji: "\u{203c}\u{fe0f}", name: "double exclamation mark", group: "Symbols", subgroup: "punctuation", version: 0.6, shortcodes: &["bangbang"] },
+    EmojiInfo { emoji: "\u{2049}\u{fe0f}", name: "exclamation question mark", group: "Symbols", subgroup: "punctuation", version: 0.6, shortcodes: &["interrobang"] },
+    EmojiInfo { emoji: "\u{2753}", name: "red question mark", group: "Symbols", subgroup: "punctuation", version: 0.6, shortcodes: &["question"] },
+    EmojiInfo { emoji: "\u{2754}", name: "white question mark", group: "Symbols", subgroup: "punctuation", version: 0.6, shortcodes: &["grey_question"] },
+    EmojiInfo { emoji: "\u{2755}", name: "white exclamation mark", group: "Symbols", subgroup: "punctuation", version: 0.6, shortcodes: &["grey_exclamation"] },
+    EmojiInfo { emoji: "\u{2757}", name: "red exclamation mark", group: "Symbols", subgroup: "punctuation", version: 0.6, shortcodes: &["exclamation", "heavy_exclamation_mark"] },
+    EmojiInfo { emoji: "\u{3030}\u{fe0f}", name: "wavy dash", group: "Symbols", subgroup: "punctuation", version: 0.6, shortcodes: &["wavy_dash"] },
+    EmojiInfo { emoji: "\u{1f4b1}", name: "currency exchange", group: "Symbols", subgroup: "currency", version: 0.6, shortcodes: &["currency_exchange"] },
+    EmojiInfo { emoji: "\u{1f4b2}", name: "heavy dollar sign", group: "Symbols", subgroup: "currency", version: 0.6, shortcodes: &["heavy_dollar_sign"] },
+    EmojiInfo { emoji: "\u{2695}\u{fe0f}", name: "medical symbol", group: "Symbols", subgroup: "other-symbol", version: 4.0, shortcodes: &["medical_symbol"] },
+    EmojiInfo { emoji: "\u{267b}\u{fe0f}", name: "recycling symbol", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["recycle"] },
+    EmojiInfo { emoji: "\u{269c}\u{fe0f}", name: "fleur-de-lis", group: "Symbols", subgroup: "other-symbol", version: 1.0, shortcodes: &["fleur_de_lis"] },
+    EmojiInfo { emoji: "\u{1f531}", name: "trident emblem", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["trident"] },
+    EmojiInfo { emoji: "\u{1f4db}", name: "name badge", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["name_badge"] },
+    EmojiInfo { emoji: "\u{1f530}", name: "Japanese symbol for beginner", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["beginner"] },
+    EmojiInfo { emoji: "\u{2b55}", name: "hollow red circle", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["o"] },
+    EmojiInfo { emoji: "\u{2705}", name: "check mark button", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["white_check_mark"] },
+    EmojiInfo { emoji: "\u{2611}\u{fe0f}", name: "check box with check", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["ballot_box_with_check"] },
+    EmojiInfo { emoji: "\u{2714}\u{fe0f}", name: "check mark", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["heavy_check_mark"] },
+    EmojiInfo { emoji: "\u{274c}", name: "cross mark", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["x"] },
+    EmojiInfo { emoji: "\u{274e}", name: "cross mark button", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["negative_squared_cross_mark"] },
+    EmojiInfo { emoji: "\u{27b0}", name: "curly loop", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["curly_loop"] },
+    EmojiInfo { emoji: "\u{27bf}", name: "double curly loop", group: "Symbols", subgroup: "other-symbol", version: 1.0, shortcodes: &["loop"] },
+    EmojiInfo { emoji: "\u{303d}\u{fe0f}", name: "part alternation mark", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["part_alternation_mark"] },
+    EmojiInfo { emoji: "\u{2733}\u{fe0f}", name: "eight-spoked asterisk", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["eight_spoked_asterisk"] },
+    EmojiInfo { emoji: "\u{2734}\u{fe0f}", name: "eight-pointed star", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["eight_pointed_black_star"] },
+    EmojiInfo { emoji: "\u{2747}\u{fe0f}", name: "sparkle", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["sparkle"] },
+    EmojiInfo { emoji: "\u{a9}\u{fe0f}", name: "copyright", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["copyright"] },
+    EmojiInfo { emoji: "\u{ae}\u{fe0f}", name: "registered", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["registered"] },
+    EmojiInfo { emoji: "\u{2122}\u{fe0f}", name: "trade mark", group: "Symbols", subgroup: "other-symbol", version: 0.6, shortcodes: &["tm"] },
+    EmojiInfo { emoji: "\u{23}\u{fe0f}\u{20e3}", name: "keycap: #", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["hash"] },
+    EmojiInfo { emoji: "\u{2a}\u{fe0f}\u{20e3}", name: "keycap: *", group: "Symbols", subgroup: "keycap", version: 2.0, shortcodes: &["asterisk"] },
+    EmojiInfo { emoji: "\u{30}\u{fe0f}\u{20e3}", name: "keycap: 0", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["zero"] },
+    EmojiInfo { emoji: "\u{31}\u{fe0f}\u{20e3}", name: "keycap: 1", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["one"] },
+    EmojiInfo { emoji: "\u{32}\u{fe0f}\u{20e3}", name: "keycap: 2", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["two"] },
+    EmojiInfo { emoji: "\u{33}\u{fe0f}\u{20e3}", name: "keycap: 3", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["three"] },
+    EmojiInfo { emoji: "\u{34}\u{fe0f}\u{20e3}", name: "keycap: 4", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["four"] },
+    EmojiInfo { emoji: "\u{35}\u{fe0f}\u{20e3}", name: "keycap: 5", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["five"] },
+    EmojiInfo { emoji: "\u{36}\u{fe0f}\u{20e3}", name: "keycap: 6", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["six"] },
+    EmojiInfo { emoji: "\u{37}\u{fe0f}\u{20e3}", name: "keycap: 7", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["seven"] },
+    EmojiInfo { emoji: "\u{38}\u{fe0f}\u{20e3}", name: "keycap: 8", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["eight"] },
+    EmojiInfo { emoji: "\u{39}\u{fe0f}\u{20e3}", name: "keycap: 9", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["nine"] },
+    EmojiInfo { emoji: "\u{1f51f}", name: "keycap: 10", group: "Symbols", subgroup: "keycap", version: 0.6, shortcodes: &["keycap_ten"] },
+    EmojiInfo { emoji: "\u{1f520}", name: "input latin uppercase", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["capital_abcd"] },
+    EmojiInfo { emoji: "\u{1f521}", name: "input latin lowercase", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["abcd"] },
+    EmojiInfo { emoji: "\u{1f522}", name: "input numbers", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["1234"] },
+    EmojiInfo { emoji: "\u{1f523}", name: "input symbols", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["symbols"] },
+    EmojiInfo { emoji: "\u{1f524}", name: "input latin letters", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["abc"] },
+    EmojiInfo { emoji: "\u{1f170}\u{fe0f}", name: "A button (blood type)", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["a"] },
+    EmojiInfo { emoji: "\u{1f18e}", name: "AB button (blood type)", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["ab"] },
+    EmojiInfo { emoji: "\u{1f171}\u{fe0f}", name: "B button (blood type)", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["b"] },
+    EmojiInfo { emoji: "\u{1f191}", name: "CL button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["cl"] },
+    EmojiInfo { emoji: "\u{1f192}", name: "COOL button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["cool"] },
+    EmojiInfo { emoji: "\u{1f193}", name: "FREE button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["free"] },
+    EmojiInfo { emoji: "\u{2139}\u{fe0f}", name: "information", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["information_source"] },
+    EmojiInfo { emoji: "\u{1f194}", name: "ID button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["id"] },
+    EmojiInfo { emoji: "\u{24c2}\u{fe0f}", name: "circled M", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["m"] },
+    EmojiInfo { emoji: "\u{1f195}", name: "NEW button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["new"] },
+    EmojiInfo { emoji: "\u{1f196}", name: "NG button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["ng"] },
+    EmojiInfo { emoji: "\u{1f17e}\u{fe0f}", name: "O button (blood type)", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["o2"] },
+    EmojiInfo { emoji: "\u{1f197}", name: "OK button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["ok"] },
+    EmojiInfo { emoji: "\u{1f17f}\u{fe0f}", name: "P button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["parking"] },
+    EmojiInfo { emoji: "\u{1f198}", name: "SOS button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["sos"] },
+    EmojiInfo { emoji: "\u{1f199}", name: "UP! button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["up"] },
+    EmojiInfo { emoji: "\u{1f19a}", name: "VS button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["vs"] },
+    EmojiInfo { emoji: "\u{1f201}", name: "Japanese “here” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["koko"] },
+    EmojiInfo { emoji: "\u{1f202}\u{fe0f}", name: "Japanese “service charge” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["sa"] },
+    EmojiInfo { emoji: "\u{1f237}\u{fe0f}", name: "Japanese “monthly amount” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u6708"] },
+    EmojiInfo { emoji: "\u{1f236}", name: "Japanese “not free of charge” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u6709"] },
+    EmojiInfo { emoji: "\u{1f22f}", name: "Japanese “reserved” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u6307"] },
+    EmojiInfo { emoji: "\u{1f250}", name: "Japanese “bargain” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["ideograph_advantage"] },
+    EmojiInfo { emoji: "\u{1f239}", name: "Japanese “discount” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u5272"] },
+    EmojiInfo { emoji: "\u{1f21a}", name: "Japanese “free of charge” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u7121"] },
+    EmojiInfo { emoji: "\u{1f232}", name: "Japanese “prohibited” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u7981"] },
+    EmojiInfo { emoji: "\u{1f251}", name: "Japanese “acceptable” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["accept"] },
+    EmojiInfo { emoji: "\u{1f238}", name: "Japanese “application” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u7533"] },
+    EmojiInfo { emoji: "\u{1f234}", name: "Japanese “passing grade” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u5408"] },
+    EmojiInfo { emoji: "\u{1f233}", name: "Japanese “vacancy” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u7a7a"] },
+    EmojiInfo { emoji: "\u{3297}\u{fe0f}", name: "Japanese “congratulations” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["congratulations"] },
+    EmojiInfo { emoji: "\u{3299}\u{fe0f}", name: "Japanese “secret” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["secret"] },
+    EmojiInfo { emoji: "\u{1f23a}", name: "Japanese “open for business” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u55b6"] },
+    EmojiInfo { emoji: "\u{1f235}", name: "Japanese “no vacancy” button", group: "Symbols", subgroup: "alphanum", version: 0.6, shortcodes: &["u6e80"] },
+    EmojiInfo { emoji: "\u{1f534}", name: "red circle", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["red_circle"] },
+    EmojiInfo { emoji: "\u{1f7e0}", name: "orange circle", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["orange_circle"] },
+    EmojiInfo { emoji: "\u{1f7e1}", name: "yellow circle", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["yellow_circle"] },
+    EmojiInfo { emoji: "\u{1f7e2}", name: "green circle", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["green_circle"] },
+    EmojiInfo { emoji: "\u{1f535}", name: "blue circle", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["large_blue_circle"] },
+    EmojiInfo { emoji: "\u{1f7e3}", name: "purple circle", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["purple_circle"] },
+    EmojiInfo { emoji: "\u{1f7e4}", name: "brown circle", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["brown_circle"] },
+    EmojiInfo { emoji: "\u{26ab}", name: "black circle", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["black_circle"] },
+    EmojiInfo { emoji: "\u{26aa}", name: "white circle", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["white_circle"] },
+    EmojiInfo { emoji: "\u{1f7e5}", name: "red square", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["red_square"] },
+    EmojiInfo { emoji: "\u{1f7e7}", name: "orange square", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["orange_square"] },
+    EmojiInfo { emoji: "\u{1f7e8}", name: "yellow square", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["yellow_square"] },
+    EmojiInfo { emoji: "\u{1f7e9}", name: "green square", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["green_square"] },
+    EmojiInfo { emoji: "\u{1f7e6}", name: "blue square", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["blue_square"] },
+    EmojiInfo { emoji: "\u{1f7ea}", name: "purple square", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["purple_square"] },
+    EmojiInfo { emoji: "\u{1f7eb}", name: "brown square", group: "Symbols", subgroup: "geometric", version: 12.0, shortcodes: &["brown_square"] },
+    EmojiInfo { emoji: "\u{2b1b}", name: "black large square", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["black_large_square"] },
+    EmojiInfo { emoji: "\u{2b1c}", name: "white large square", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["white_large_square"] },
+    EmojiInfo { emoji: "\u{25fc}\u{fe0f}", name: "black medium square", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["black_medium_square"] },
+    EmojiInfo { emoji: "\u{25fb}\u{fe0f}", name: "white medium square", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["white_medium_square"] },
+    EmojiInfo { emoji: "\u{25fe}", name: "black medium-small square", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["black_medium_small_square"] },
+    EmojiInfo { emoji: "\u{25fd}", name: "white medium-small square", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["white_medium_small_square"] },
+    EmojiInfo { emoji: "\u{25aa}\u{fe0f}", name: "black small square", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["black_small_square"] },
+    EmojiInfo { emoji: "\u{25ab}\u{fe0f}", name: "white small square", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["white_small_square"] },
+    EmojiInfo { emoji: "\u{1f536}", name: "large orange diamond", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["large_orange_diamond"] },
+    EmojiInfo { emoji: "\u{1f537}", name: "large blue diamond", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["large_blue_diamond"] },
+    EmojiInfo { emoji: "\u{1f538}", name: "small orange diamond", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["small_orange_diamond"] },
+    EmojiInfo { emoji: "\u{1f539}", name: "small blue diamond", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["small_blue_diamond"] },
+    EmojiInfo { emoji: "\u{1f53a}", name: "red triangle pointed up", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["small_red_triangle"] },
+    EmojiInfo { emoji: "\u{1f53b}", name: "red triangle pointed down", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["small_red_triangle_down"] },
+    EmojiInfo { emoji: "\u{1f4a0}", name: "diamond with a dot", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["diamond_shape_with_a_dot_inside"] },
+    EmojiInfo { emoji: "\u{1f518}", name: "radio button", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["radio_button"] },
+    EmojiInfo { emoji: "\u{1f533}", name: "white square button", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["white_square_button"] },
+    EmojiInfo { emoji: "\u{1f532}", name: "black square button", group: "Symbols", subgroup: "geometric", version: 0.6, shortcodes: &["black_square_button"] },
+    EmojiInfo { emoji: "\u{1f3c1}", name: "chequered flag", group: "Flags", subgroup: "flag", version: 0.6, shortcodes: &["checkered_flag"] },
+    EmojiInfo { emoji: "\u{1f6a9}", name: "triangular flag", group: "Flags", subgroup: "flag", version: 0.6, shortcodes: &["triangular_flag_on_post"] },
+    EmojiInfo { emoji: "\u{1f38c}", name: "crossed flags", group: "Flags", subgroup: "flag", version: 0.6, shortcodes: &["crossed_flags"] },
+    EmojiInfo { emoji: "\u{1f3f4}", name: "black flag", group: "Flags", subgroup: "flag", version: 1.0, shortcodes: &["black_flag"] },
+    EmojiInfo { emoji: "\u{1f3f3}\u{fe0f}", name: "white flag", group: "Flags", subgroup: "flag", version: 0.7, shortcodes: &["white_flag"] },
+    EmojiInfo { emoji: "\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}", name: "rainbow flag", group: "Flags", subgroup: "flag", version: 4.0, shortcodes: &["rainbow_flag"] },
+    EmojiInfo { emoji: "\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}", name: "transgender flag", group: "Flags", subgroup: "flag", version: 13.0, shortcodes: &["transgender_flag"] },
+    EmojiInfo { emoji: "\u{1f3f4}\u{200d}\u{2620}\u{fe0f}", name: "pirate flag", group: "Flags", subgroup: "flag", version: 11.0, shortcodes: &["pirate_flag"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1e8}", name: "flag: Ascension Island", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["ascension_island"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1e9}", name: "flag: Andorra", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["andorra"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1ea}", name: "flag: United Arab Emirates", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["united_arab_emirates"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1eb}", name: "flag: Afghanistan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["afghanistan"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1ec}", name: "flag: Antigua & Barbuda", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["antigua_barbuda"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1ee}", name: "flag: Anguilla", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["anguilla"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1f1}", name: "flag: Albania", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["albania"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1f2}", name: "flag: Armenia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["armenia"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1f4}", name: "flag: Angola", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["angola"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1f6}", name: "flag: Antarctica", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["antarctica"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1f7}", name: "flag: Argentina", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["argentina"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1f8}", name: "flag: American Samoa", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["american_samoa"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1f9}", name: "flag: Austria", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["austria"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1fa}", name: "flag: Australia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["australia"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1fc}", name: "flag: Aruba", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["aruba"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1fd}", name: "flag: Åland Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["aland_islands"] },
+    EmojiInfo { emoji: "\u{1f1e6}\u{1f1ff}", name: "flag: Azerbaijan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["azerbaijan"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1e6}", name: "flag: Bosnia & Herzegovina", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bosnia_herzegovina"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1e7}", name: "flag: Barbados", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["barbados"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1e9}", name: "flag: Bangladesh", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bangladesh"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1ea}", name: "flag: Belgium", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["belgium"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1eb}", name: "flag: Burkina Faso", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["burkina_faso"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1ec}", name: "flag: Bulgaria", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bulgaria"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1ed}", name: "flag: Bahrain", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bahrain"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1ee}", name: "flag: Burundi", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["burundi"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1ef}", name: "flag: Benin", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["benin"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1f1}", name: "flag: St. Barthélemy", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["st_barthelemy"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1f2}", name: "flag: Bermuda", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bermuda"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1f3}", name: "flag: Brunei", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["brunei"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1f4}", name: "flag: Bolivia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bolivia"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1f6}", name: "flag: Caribbean Netherlands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["caribbean_netherlands"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1f7}", name: "flag: Brazil", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["brazil"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1f8}", name: "flag: Bahamas", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bahamas"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1f9}", name: "flag: Bhutan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bhutan"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1fb}", name: "flag: Bouvet Island", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["bouvet_island"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1fc}", name: "flag: Botswana", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["botswana"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1fe}", name: "flag: Belarus", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["belarus"] },
+    EmojiInfo { emoji: "\u{1f1e7}\u{1f1ff}", name: "flag: Belize", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["belize"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1e6}", name: "flag: Canada", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["canada"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1e8}", name: "flag: Cocos (Keeling) Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cocos_islands"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1e9}", name: "flag: Congo - Kinshasa", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["congo_kinshasa"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1eb}", name: "flag: Central African Republic", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["central_african_republic"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1ec}", name: "flag: Congo - Brazzaville", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["congo_brazzaville"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1ed}", name: "flag: Switzerland", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["switzerland"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1ee}", name: "flag: Côte d’Ivoire", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cote_divoire"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1f0}", name: "flag: Cook Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cook_islands"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1f1}", name: "flag: Chile", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["chile"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1f2}", name: "flag: Cameroon", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cameroon"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1f3}", name: "flag: China", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["cn"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1f4}", name: "flag: Colombia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["colombia"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1f5}", name: "flag: Clipperton Island", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["clipperton_island"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1f7}", name: "flag: Costa Rica", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["costa_rica"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1fa}", name: "flag: Cuba", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cuba"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1fb}", name: "flag: Cape Verde", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cape_verde"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1fc}", name: "flag: Curaçao", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["curacao"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1fd}", name: "flag: Christmas Island", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["christmas_island"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1fe}", name: "flag: Cyprus", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cyprus"] },
+    EmojiInfo { emoji: "\u{1f1e8}\u{1f1ff}", name: "flag: Czechia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["czech_republic"] },
+    EmojiInfo { emoji: "\u{1f1e9}\u{1f1ea}", name: "flag: Germany", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["de"] },
+    EmojiInfo { emoji: "\u{1f1e9}\u{1f1ec}", name: "flag: Diego Garcia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["diego_garcia"] },
+    EmojiInfo { emoji: "\u{1f1e9}\u{1f1ef}", name: "flag: Djibouti", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["djibouti"] },
+    EmojiInfo { emoji: "\u{1f1e9}\u{1f1f0}", name: "flag: Denmark", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["denmark"] },
+    EmojiInfo { emoji: "\u{1f1e9}\u{1f1f2}", name: "flag: Dominica", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["dominica"] },
+    EmojiInfo { emoji: "\u{1f1e9}\u{1f1f4}", name: "flag: Dominican Republic", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["dominican_republic"] },
+    EmojiInfo { emoji: "\u{1f1e9}\u{1f1ff}", name: "flag: Algeria", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["algeria"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1e6}", name: "flag: Ceuta & Melilla", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["ceuta_melilla"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1e8}", name: "flag: Ecuador", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["ecuador"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1ea}", name: "flag: Estonia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["estonia"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1ec}", name: "flag: Egypt", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["egypt"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1ed}", name: "flag: Western Sahara", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["western_sahara"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1f7}", name: "flag: Eritrea", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["eritrea"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1f8}", name: "flag: Spain", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["es"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1f9}", name: "flag: Ethiopia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["ethiopia"] },
+    EmojiInfo { emoji: "\u{1f1ea}\u{1f1fa}", name: "flag: European Union", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["eu", "european_union"] },
+    EmojiInfo { emoji: "\u{1f1eb}\u{1f1ee}", name: "flag: Finland", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["finland"] },
+    EmojiInfo { emoji: "\u{1f1eb}\u{1f1ef}", name: "flag: Fiji", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["fiji"] },
+    EmojiInfo { emoji: "\u{1f1eb}\u{1f1f0}", name: "flag: Falkland Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["falkland_islands"] },
+    EmojiInfo { emoji: "\u{1f1eb}\u{1f1f2}", name: "flag: Micronesia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["micronesia"] },
+    EmojiInfo { emoji: "\u{1f1eb}\u{1f1f4}", name: "flag: Faroe Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["faroe_islands"] },
+    EmojiInfo { emoji: "\u{1f1eb}\u{1f1f7}", name: "flag: France", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["fr"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1e6}", name: "flag: Gabon", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["gabon"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1e7}", name: "flag: United Kingdom", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["gb", "uk"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1e9}", name: "flag: Grenada", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["grenada"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1ea}", name: "flag: Georgia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["georgia"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1eb}", name: "flag: French Guiana", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["french_guiana"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1ec}", name: "flag: Guernsey", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["guernsey"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1ed}", name: "flag: Ghana", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["ghana"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1ee}", name: "flag: Gibraltar", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["gibraltar"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1f1}", name: "flag: Greenland", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["greenland"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1f2}", name: "flag: Gambia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["gambia"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1f3}", name: "flag: Guinea", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["guinea"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1f5}", name: "flag: Guadeloupe", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["guadeloupe"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1f6}", name: "flag: Equatorial Guinea", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["equatorial_guinea"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1f7}", name: "flag: Greece", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["greece"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1f8}", name: "flag: South Georgia & South Sandwich Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["south_georgia_south_sandwich_islands"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1f9}", name: "flag: Guatemala", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["guatemala"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1fa}", name: "flag: Guam", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["guam"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1fc}", name: "flag: Guinea-Bissau", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["guinea_bissau"] },
+    EmojiInfo { emoji: "\u{1f1ec}\u{1f1fe}", name: "flag: Guyana", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["guyana"] },
+    EmojiInfo { emoji: "\u{1f1ed}\u{1f1f0}", name: "flag: Hong Kong SAR China", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["hong_kong"] },
+    EmojiInfo { emoji: "\u{1f1ed}\u{1f1f2}", name: "flag: Heard & McDonald Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["heard_mcdonald_islands"] },
+    EmojiInfo { emoji: "\u{1f1ed}\u{1f1f3}", name: "flag: Honduras", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["honduras"] },
+    EmojiInfo { emoji: "\u{1f1ed}\u{1f1f7}", name: "flag: Croatia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["croatia"] },
+    EmojiInfo { emoji: "\u{1f1ed}\u{1f1f9}", name: "flag: Haiti", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["haiti"] },
+    EmojiInfo { emoji: "\u{1f1ed}\u{1f1fa}", name: "flag: Hungary", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["hungary"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1e8}", name: "flag: Canary Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["canary_islands"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1e9}", name: "flag: Indonesia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["indonesia"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1ea}", name: "flag: Ireland", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["ireland"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1f1}", name: "flag: Israel", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["israel"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1f2}", name: "flag: Isle of Man", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["isle_of_man"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1f3}", name: "flag: India", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["india"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1f4}", name: "flag: British Indian Ocean Territory", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["british_indian_ocean_territory"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1f6}", name: "flag: Iraq", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["iraq"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1f7}", name: "flag: Iran", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["iran"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1f8}", name: "flag: Iceland", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["iceland"] },
+    EmojiInfo { emoji: "\u{1f1ee}\u{1f1f9}", name: "flag: Italy", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["it"] },
+    EmojiInfo { emoji: "\u{1f1ef}\u{1f1ea}", name: "flag: Jersey", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["jersey"] },
+    EmojiInfo { emoji: "\u{1f1ef}\u{1f1f2}", name: "flag: Jamaica", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["jamaica"] },
+    EmojiInfo { emoji: "\u{1f1ef}\u{1f1f4}", name: "flag: Jordan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["jordan"] },
+    EmojiInfo { emoji: "\u{1f1ef}\u{1f1f5}", name: "flag: Japan", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["jp"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1ea}", name: "flag: Kenya", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["kenya"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1ec}", name: "flag: Kyrgyzstan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["kyrgyzstan"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1ed}", name: "flag: Cambodia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cambodia"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1ee}", name: "flag: Kiribati", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["kiribati"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1f2}", name: "flag: Comoros", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["comoros"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1f3}", name: "flag: St. Kitts & Nevis", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["st_kitts_nevis"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1f5}", name: "flag: North Korea", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["north_korea"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1f7}", name: "flag: South Korea", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["kr"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1fc}", name: "flag: Kuwait", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["kuwait"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1fe}", name: "flag: Cayman Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["cayman_islands"] },
+    EmojiInfo { emoji: "\u{1f1f0}\u{1f1ff}", name: "flag: Kazakhstan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["kazakhstan"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1e6}", name: "flag: Laos", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["laos"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1e7}", name: "flag: Lebanon", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["lebanon"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1e8}", name: "flag: St. Lucia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["st_lucia"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1ee}", name: "flag: Liechtenstein", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["liechtenstein"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1f0}", name: "flag: Sri Lanka", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["sri_lanka"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1f7}", name: "flag: Liberia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["liberia"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1f8}", name: "flag: Lesotho", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["lesotho"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1f9}", name: "flag: Lithuania", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["lithuania"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1fa}", name: "flag: Luxembourg", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["luxembourg"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1fb}", name: "flag: Latvia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["latvia"] },
+    EmojiInfo { emoji: "\u{1f1f1}\u{1f1fe}", name: "flag: Libya", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["libya"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1e6}", name: "flag: Morocco", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["morocco"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1e8}", name: "flag: Monaco", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["monaco"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1e9}", name: "flag: Moldova", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["moldova"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1ea}", name: "flag: Montenegro", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["montenegro"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1eb}", name: "flag: St. Martin", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["st_martin"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1ec}", name: "flag: Madagascar", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["madagascar"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1ed}", name: "flag: Marshall Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["marshall_islands"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f0}", name: "flag: North Macedonia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["macedonia"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f1}", name: "flag: Mali", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["mali"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f2}", name: "flag: Myanmar (Burma)", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["myanmar"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f3}", name: "flag: Mongolia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["mongolia"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f4}", name: "flag: Macao SAR China", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["macau"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f5}", name: "flag: Northern Mariana Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["northern_mariana_islands"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f6}", name: "flag: Martinique", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["martinique"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f7}", name: "flag: Mauritania", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["mauritania"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f8}", name: "flag: Montserrat", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["montserrat"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1f9}", name: "flag: Malta", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["malta"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1fa}", name: "flag: Mauritius", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["mauritius"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1fb}", name: "flag: Maldives", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["maldives"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1fc}", name: "flag: Malawi", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["malawi"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1fd}", name: "flag: Mexico", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["mexico"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1fe}", name: "flag: Malaysia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["malaysia"] },
+    EmojiInfo { emoji: "\u{1f1f2}\u{1f1ff}", name: "flag: Mozambique", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["mozambique"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1e6}", name: "flag: Namibia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["namibia"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1e8}", name: "flag: New Caledonia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["new_caledonia"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1ea}", name: "flag: Niger", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["niger"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1eb}", name: "flag: Norfolk Island", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["norfolk_island"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1ec}", name: "flag: Nigeria", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["nigeria"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1ee}", name: "flag: Nicaragua", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["nicaragua"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1f1}", name: "flag: Netherlands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["netherlands"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1f4}", name: "flag: Norway", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["norway"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1f5}", name: "flag: Nepal", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["nepal"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1f7}", name: "flag: Nauru", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["nauru"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1fa}", name: "flag: Niue", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["niue"] },
+    EmojiInfo { emoji: "\u{1f1f3}\u{1f1ff}", name: "flag: New Zealand", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["new_zealand"] },
+    EmojiInfo { emoji: "\u{1f1f4}\u{1f1f2}", name: "flag: Oman", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["oman"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1e6}", name: "flag: Panama", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["panama"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1ea}", name: "flag: Peru", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["peru"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1eb}", name: "flag: French Polynesia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["french_polynesia"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1ec}", name: "flag: Papua New Guinea", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["papua_new_guinea"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1ed}", name: "flag: Philippines", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["philippines"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1f0}", name: "flag: Pakistan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["pakistan"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1f1}", name: "flag: Poland", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["poland"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1f2}", name: "flag: St. Pierre & Miquelon", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["st_pierre_miquelon"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1f3}", name: "flag: Pitcairn Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["pitcairn_islands"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1f7}", name: "flag: Puerto Rico", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["puerto_rico"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1f8}", name: "flag: Palestinian Territories", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["palestinian_territories"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1f9}", name: "flag: Portugal", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["portugal"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1fc}", name: "flag: Palau", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["palau"] },
+    EmojiInfo { emoji: "\u{1f1f5}\u{1f1fe}", name: "flag: Paraguay", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["paraguay"] },
+    EmojiInfo { emoji: "\u{1f1f6}\u{1f1e6}", name: "flag: Qatar", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["qatar"] },
+    EmojiInfo { emoji: "\u{1f1f7}\u{1f1ea}", name: "flag: Réunion", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["reunion"] },
+    EmojiInfo { emoji: "\u{1f1f7}\u{1f1f4}", name: "flag: Romania", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["romania"] },
+    EmojiInfo { emoji: "\u{1f1f7}\u{1f1f8}", name: "flag: Serbia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["serbia"] },
+    EmojiInfo { emoji: "\u{1f1f7}\u{1f1fa}", name: "flag: Russia", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["ru"] },
+    EmojiInfo { emoji: "\u{1f1f7}\u{1f1fc}", name: "flag: Rwanda", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["rwanda"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1e6}", name: "flag: Saudi Arabia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["saudi_arabia"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1e7}", name: "flag: Solomon Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["solomon_islands"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1e8}", name: "flag: Seychelles", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["seychelles"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1e9}", name: "flag: Sudan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["sudan"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1ea}", name: "flag: Sweden", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["sweden"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1ec}", name: "flag: Singapore", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["singapore"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1ed}", name: "flag: St. Helena", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["st_helena"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1ee}", name: "flag: Slovenia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["slovenia"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1ef}", name: "flag: Svalbard & Jan Mayen", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["svalbard_jan_mayen"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1f0}", name: "flag: Slovakia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["slovakia"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1f1}", name: "flag: Sierra Leone", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["sierra_leone"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1f2}", name: "flag: San Marino", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["san_marino"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1f3}", name: "flag: Senegal", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["senegal"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1f4}", name: "flag: Somalia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["somalia"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1f7}", name: "flag: Suriname", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["suriname"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1f8}", name: "flag: South Sudan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["south_sudan"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1f9}", name: "flag: São Tomé & Príncipe", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["sao_tome_principe"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1fb}", name: "flag: El Salvador", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["el_salvador"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1fd}", name: "flag: Sint Maarten", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["sint_maarten"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1fe}", name: "flag: Syria", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["syria"] },
+    EmojiInfo { emoji: "\u{1f1f8}\u{1f1ff}", name: "flag: Eswatini", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["swaziland"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1e6}", name: "flag: Tristan da Cunha", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["tristan_da_cunha"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1e8}", name: "flag: Turks & Caicos Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["turks_caicos_islands"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1e9}", name: "flag: Chad", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["chad"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1eb}", name: "flag: French Southern Territories", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["french_southern_territories"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1ec}", name: "flag: Togo", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["togo"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1ed}", name: "flag: Thailand", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["thailand"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1ef}", name: "flag: Tajikistan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["tajikistan"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1f0}", name: "flag: Tokelau", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["tokelau"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1f1}", name: "flag: Timor-Leste", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["timor_leste"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1f2}", name: "flag: Turkmenistan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["turkmenistan"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1f3}", name: "flag: Tunisia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["tunisia"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1f4}", name: "flag: Tonga", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["tonga"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1f7}", name: "flag: Turkey", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["tr"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1f9}", name: "flag: Trinidad & Tobago", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["trinidad_tobago"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1fb}", name: "flag: Tuvalu", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["tuvalu"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1fc}", name: "flag: Taiwan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["taiwan"] },
+    EmojiInfo { emoji: "\u{1f1f9}\u{1f1ff}", name: "flag: Tanzania", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["tanzania"] },
+    EmojiInfo { emoji: "\u{1f1fa}\u{1f1e6}", name: "flag: Ukraine", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["ukraine"] },
+    EmojiInfo { emoji: "\u{1f1fa}\u{1f1ec}", name: "flag: Uganda", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["uganda"] },
+    EmojiInfo { emoji: "\u{1f1fa}\u{1f1f2}", name: "flag: U.S. Outlying Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["us_outlying_islands"] },
+    EmojiInfo { emoji: "\u{1f1fa}\u{1f1f3}", name: "flag: United Nations", group: "Flags", subgroup: "country-flag", version: 4.0, shortcodes: &["united_nations"] },
+    EmojiInfo { emoji: "\u{1f1fa}\u{1f1f8}", name: "flag: United States", group: "Flags", subgroup: "country-flag", version: 0.6, shortcodes: &["us"] },
+    EmojiInfo { emoji: "\u{1f1fa}\u{1f1fe}", name: "flag: Uruguay", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["uruguay"] },
+    EmojiInfo { emoji: "\u{1f1fa}\u{1f1ff}", name: "flag: Uzbekistan", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["uzbekistan"] },
+    EmojiInfo { emoji: "\u{1f1fb}\u{1f1e6}", name: "flag: Vatican City", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["vatican_city"] },
+    EmojiInfo { emoji: "\u{1f1fb}\u{1f1e8}", name: "flag: St. Vincent & Grenadines", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["st_vincent_grenadines"] },
+    EmojiInfo { emoji: "\u{1f1fb}\u{1f1ea}", name: "flag: Venezuela", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["venezuela"] },
+    EmojiInfo { emoji: "\u{1f1fb}\u{1f1ec}", name: "flag: British Virgin Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["british_virgin_islands"] },
+    EmojiInfo { emoji: "\u{1f1fb}\u{1f1ee}", name: "flag: U.S. Virgin Islands", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["us_virgin_islands"] },
+    EmojiInfo { emoji: "\u{1f1fb}\u{1f1f3}", name: "flag: Vietnam", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["vietnam"] },
+    EmojiInfo { emoji: "\u{1f1fb}\u{1f1fa}", name: "flag: Vanuatu", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["vanuatu"] },
+    EmojiInfo { emoji: "\u{1f1fc}\u{1f1eb}", name: "flag: Wallis & Futuna", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["wallis_futuna"] },
+    EmojiInfo { emoji: "\u{1f1fc}\u{1f1f8}", name: "flag: Samoa", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["samoa"] },
+    EmojiInfo { emoji: "\u{1f1fd}\u{1f1f0}", name: "flag: Kosovo", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["kosovo"] },
+    EmojiInfo { emoji: "\u{1f1fe}\u{1f1ea}", name: "flag: Yemen", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["yemen"] },
+    EmojiInfo { emoji: "\u{1f1fe}\u{1f1f9}", name: "flag: Mayotte", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["mayotte"] },
+    EmojiInfo { emoji: "\u{1f1ff}\u{1f1e6}", name: "flag: South Africa", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["south_africa"] },
+    EmojiInfo { emoji: "\u{1f1ff}\u{1f1f2}", name: "flag: Zambia", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["zambia"] },
+    EmojiInfo { emoji: "\u{1f1ff}\u{1f1fc}", name: "flag: Zimbabwe", group: "Flags", subgroup: "country-flag", version: 2.0, shortcodes: &["zimbabwe"] },
+    EmojiInfo { emoji: "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}", name: "flag: England", group: "Flags", subgroup: "subdivision-flag", version: 5.0, shortcodes: &["england"] },
+    EmojiInfo { emoji: "\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", name: "flag: Scotland", group: "Flags", subgroup: "subdivision-flag", version: 5.0, shortcodes: &["scotland"] },
+    EmojiInfo { emoji: "\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}", name: "flag: Wales", group: "Flags", subgroup: "subdivision-flag", version: 5.0, shortcodes: &["wales"] },
+];
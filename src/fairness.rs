@@ -0,0 +1,61 @@
+use super::error::Error;
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use wasm_bindgen::JsValue;
+
+const SALT_LEN: usize = 16;
+
+// Commit-reveal scheme: the commitment is published when a season commences, and the
+// jackpot and salt are revealed when it ends. Anyone can then recompute the commitment to
+// check the jackpot wasn't changed in between. The salt stops people from hashing every
+// candidate emoji to find the jackpot early.
+pub fn commitment(jackpot: &str, salt: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.input(jackpot.as_bytes());
+    hasher.input(b":");
+    hasher.input(salt.as_bytes());
+    hex::encode(hasher.result())
+}
+
+pub fn verify(jackpot: &str, salt: &str, commitment: &str) -> bool {
+    self::commitment(jackpot, salt).eq_ignore_ascii_case(commitment.trim())
+}
+
+pub fn new_salt() -> Result<String, Error> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.try_fill_bytes(&mut salt).map_err(|e| {
+        Error::Jv(JsValue::from_str(&format!(
+            "Failed to generate salt, err: {}",
+            e
+        )))
+    })?;
+    Ok(hex::encode(salt))
+}
+
+// /verify?jackpot=🐙&salt=...&commitment=...
+pub fn verify_season(
+    jackpot: Option<String>,
+    salt: Option<String>,
+    commitment: Option<String>,
+) -> Result<JsValue, JsValue> {
+    match (jackpot, salt, commitment) {
+        (Some(jackpot), Some(salt), Some(commitment)) => {
+            if verify(&jackpot, &salt, &commitment) {
+                Ok(JsValue::from_str(&format!(
+                    "{} and salt {} match commitment {}",
+                    jackpot, salt, commitment
+                )))
+            } else {
+                Ok(JsValue::from_str(&format!(
+                    "{} and salt {} DO NOT match commitment {}",
+                    jackpot, salt, commitment
+                )))
+            }
+        }
+        _ => Ok(JsValue::from_str(
+            "Usage: /verify?jackpot=<emoji>&salt=<salt>&commitment=<commitment>",
+        )),
+    }
+}
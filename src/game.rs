@@ -0,0 +1,94 @@
+use super::cooldown::CooldownPolicy;
+use super::emoji::{EmojiLottery, Jackpot, LotteryConfig};
+use super::error::Error;
+use super::history::GuessHistory;
+use super::i18n::Messages;
+use super::puzzle::EmojiPuzzle;
+use super::raffle::{Entry, Raffle};
+
+// Which game a season runs, see Game
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum GameKind {
+    EmojiLottery,
+    // Wordle-style, the jackpot is a sequence of emoji, see puzzle::EmojiPuzzle
+    EmojiPuzzle,
+    // Players enter and winners are drawn at the end, see raffle::Raffle
+    Raffle,
+}
+
+impl Default for GameKind {
+    fn default() -> Self {
+        GameKind::EmojiLottery
+    }
+}
+
+// A game mode seasons can run. Games only decide, the handlers take care of storage,
+// cooldowns and notifications around them.
+pub trait Game {
+    // Returns the config of the next season, the caller points it at a new data namespace
+    fn start_season(&self, config: &LotteryConfig) -> Result<LotteryConfig, Error>;
+
+    // Judges a submission given the submitter's earlier guesses this season
+    fn judge(
+        &self,
+        config: &LotteryConfig,
+        submitter: &str,
+        submission: &str,
+        history: &GuessHistory,
+        messages: &Messages,
+    ) -> Verdict;
+
+    // Applies when the season doesn't set a cooldown
+    fn default_cooldown(&self, config: &LotteryConfig) -> CooldownPolicy;
+
+    fn end_season(&self, config: &LotteryConfig) -> LotteryConfig;
+
+    // Whether winners are drawn when the season ends, see draw_winners
+    fn draws_winners(&self) -> bool {
+        false
+    }
+
+    // Picks the winners among the season's entries when it ends, for games whose winners
+    // aren't found by guessing
+    fn draw_winners(
+        &self,
+        _config: &LotteryConfig,
+        _entries: &[Entry],
+    ) -> Result<Vec<Entry>, Error> {
+        Ok(Vec::new())
+    }
+
+    // Describes the season, what's revealed depends on whether it's still active
+    fn render_status(&self, config: &LotteryConfig, messages: &Messages) -> String;
+}
+
+pub enum Verdict {
+    // Not a valid submission, it's not recorded and doesn't count towards the cooldown.
+    // The reason is in the submitter's locale.
+    Rejected(String),
+    // value is the submission the way it's recorded
+    Win {
+        value: String,
+        jackpot: Jackpot,
+        feedback: Option<String>,
+    },
+    // Registers the submitter with that many entries, see raffle::enter
+    Enter {
+        entries: u32,
+    },
+    // eliminated are the emoji the miss rules out, see board::eliminate
+    Miss {
+        value: String,
+        feedback: Option<String>,
+        eliminated: Vec<String>,
+    },
+}
+
+pub fn for_season(config: &LotteryConfig) -> &'static dyn Game {
+    match config.game {
+        GameKind::EmojiLottery => &EmojiLottery,
+        GameKind::EmojiPuzzle => &EmojiPuzzle,
+        GameKind::Raffle => &Raffle,
+    }
+}
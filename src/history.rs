@@ -0,0 +1,141 @@
+use super::error::Error;
+use super::i18n::Messages;
+use super::kv::{Guess, KVClient};
+
+use std::collections::BTreeSet;
+
+// Only the latest guesses are kept, older ones still count towards the total
+const MAX_GUESSES: usize = 100;
+
+// Stored in the data namespace, so a history only lasts for a season
+fn history_key(submitter: &str) -> String {
+    format!("history:{}", submitter)
+}
+
+#[derive(Deserialize, Serialize, Default, Debug)]
+pub struct GuessHistory {
+    pub guesses: Vec<Guess>,
+    // Every guess made this season, including the ones that no longer fit in guesses
+    pub count: u64,
+    // Every wrong guess made this season, so repeats are caught however long ago they were
+    #[serde(default)]
+    pub misses: BTreeSet<String>,
+}
+
+impl GuessHistory {
+    pub fn last(&self) -> Option<&Guess> {
+        self.guesses.last()
+    }
+
+    pub fn timestamps(&self) -> Vec<i64> {
+        self.guesses.iter().map(|g| g.created_at).collect()
+    }
+
+    // Whether value was already guessed wrong this season, winning guesses don't count
+    pub fn missed(&self, value: &str) -> bool {
+        self.misses.contains(value)
+    }
+
+    fn push_miss(&mut self, guess: Guess) {
+        self.misses.insert(guess.value.clone());
+        self.push(guess);
+    }
+
+    fn push(&mut self, guess: Guess) {
+        self.count += 1;
+        self.guesses.push(guess);
+        if self.guesses.len() > MAX_GUESSES {
+            self.guesses.remove(0);
+        }
+    }
+
+    pub fn localize(&self, messages: &Messages) -> String {
+        let mut text = messages.count("guess_count", self.count, &[]);
+        if self.count > self.guesses.len() as u64 {
+            text.push_str(
+                &messages.get("latest_guesses", &[("n", &self.guesses.len().to_string())]),
+            );
+        }
+        for guess in self.guesses.iter() {
+            text.push_str(&format!("\n{}", guess.localize(messages)));
+        }
+        text
+    }
+}
+
+pub async fn read(client: &KVClient, submitter: &str) -> Result<GuessHistory, Error> {
+    if let Some(history) = client.read(&history_key(submitter)).await? {
+        return Ok(history);
+    }
+    // Seasons that commenced before histories were kept only have the last guess, keyed
+    // by submitter
+    let last: Option<Guess> = client.read(submitter).await?;
+    Ok(GuessHistory {
+        count: last.iter().count() as u64,
+        guesses: last.into_iter().collect(),
+        misses: BTreeSet::new(),
+    })
+}
+
+// Appends a guess to the submitter's history and returns the updated history
+pub async fn append(
+    client: &KVClient,
+    submitter: &str,
+    guess: Guess,
+) -> Result<GuessHistory, Error> {
+    let mut history = read(client, submitter).await?;
+    history.push(guess);
+    write(client, submitter, history).await
+}
+
+// Like append, for a wrong guess that shouldn't be repeated
+pub async fn append_miss(
+    client: &KVClient,
+    submitter: &str,
+    guess: Guess,
+) -> Result<GuessHistory, Error> {
+    let mut history = read(client, submitter).await?;
+    history.push_miss(guess);
+    write(client, submitter, history).await
+}
+
+async fn write(
+    client: &KVClient,
+    submitter: &str,
+    history: GuessHistory,
+) -> Result<GuessHistory, Error> {
+    client
+        .write::<&GuessHistory>(&history_key(submitter), &history)
+        .await?;
+    Ok(history)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(value: &str) -> Guess {
+        Guess {
+            value: value.to_string(),
+            created_at: 0,
+            client_ts: None,
+        }
+    }
+
+    #[test]
+    fn misses() {
+        let mut history = GuessHistory::default();
+        history.push_miss(guess("🦑"));
+        for _ in 0..MAX_GUESSES {
+            history.push_miss(guess("🦀"));
+        }
+        history.push(guess("🐙"));
+        // Older than the kept guesses, still a repeat
+        assert!(history.guesses.iter().all(|g| g.value != "🦑"));
+        assert!(history.missed("🦑"));
+        assert!(history.missed("🦀"));
+        // Wins aren't wrong guesses
+        assert!(!history.missed("🐙"));
+        assert_eq!(history.count, MAX_GUESSES as u64 + 2);
+    }
+}
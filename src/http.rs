@@ -1,73 +1,415 @@
 use super::error::Error;
+use super::trace::{self, Trace, TraceRecord};
 
-use serde::Serialize;
+use js_sys::{Function, Promise, Reflect};
+use serde::{de::DeserializeOwned, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::fmt;
+use url::{form_urlencoded, Url};
 use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{RequestInit, Response};
+use web_sys::{Blob, BlobPropertyBag, FormData, RequestInit};
 
-pub struct Request<T: ?Sized>
-where
-    T: Serialize,
-{
+// Number of bytes of the response body kept in a StatusError
+const MAX_ERROR_BODY_LEN: usize = 512;
+// Idempotent requests are sent up to this many times when they fail or the server errors
+const MAX_ATTEMPTS: u32 = 3;
+
+thread_local! {
+    static FETCH: RefCell<Option<Function>> = RefCell::new(None);
+}
+
+pub struct Request {
     pub url: String,
     pub method: Method,
     pub headers: HashMap<String, String>,
-    pub body: T,
+    pub query: Vec<(String, String)>,
+    pub body: Body,
+    // Where the call gets recorded, if anywhere
+    pub trace: Option<Trace>,
+}
+
+impl Request {
+    pub fn new(method: Method, url: String) -> Request {
+        Request {
+            url,
+            method,
+            headers: HashMap::new(),
+            query: Vec::new(),
+            body: Body::Empty,
+            trace: None,
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    // Query parameters are appended to the ones already present in url
+    pub fn query(mut self, name: &str, value: &str) -> Self {
+        self.query.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, body: Body) -> Self {
+        self.body = body;
+        self
+    }
+
+    pub fn trace(mut self, trace: &Trace) -> Self {
+        self.trace = Some(trace.clone());
+        self
+    }
+
+    fn full_url(&self) -> Result<String, Error> {
+        if self.query.is_empty() {
+            return Ok(self.url.clone());
+        }
+        let mut url = Url::parse(&self.url).map_err(|_| {
+            Error::Jv(JsValue::from_str(&format!(
+                "{:?} is not a valid url",
+                self.url
+            )))
+        })?;
+        url.query_pairs_mut().extend_pairs(self.query.iter());
+        Ok(url.to_string())
+    }
+
+    fn has_header(&self, name: &str) -> bool {
+        self.headers.keys().any(|k| k.eq_ignore_ascii_case(name))
+    }
 }
 
 pub enum Method {
     GET,
+    HEAD,
     POST,
     PUT,
+    PATCH,
     DELETE,
 }
 
 impl Method {
+    // Whether sending the request twice has the same effect as sending it once
+    fn is_idempotent(&self) -> bool {
+        match self {
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE => true,
+            Method::POST | Method::PATCH => false,
+        }
+    }
+
     pub fn as_str(&self) -> &str {
         match self {
             Method::GET => "GET",
+            Method::HEAD => "HEAD",
             Method::POST => "POST",
             Method::PUT => "PUT",
+            Method::PATCH => "PATCH",
             Method::DELETE => "DELETE",
         }
     }
 }
 
-pub async fn send<T>(req: Request<T>) -> Result<Response, Error>
-where
-    T: Serialize,
-{
+pub enum Body {
+    Empty,
+    // Already serialized JSON, see Body::json
+    Json(String),
+    // application/x-www-form-urlencoded, e.g. Slack's oauth.v2.access
+    Form(Vec<(String, String)>),
+    Multipart(Vec<Part>),
+    Raw {
+        content_type: String,
+        bytes: Vec<u8>,
+    },
+}
+
+pub enum Part {
+    Text {
+        name: String,
+        value: String,
+    },
+    File {
+        name: String,
+        filename: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    },
+}
+
+impl Body {
+    pub fn json<T: ?Sized>(val: &T) -> Result<Body, Error>
+    where
+        T: Serialize,
+    {
+        // Equivalent to JSON.stringify in JS
+        Ok(Body::Json(serde_json::to_string(val)?))
+    }
+
+    pub fn form(fields: &[(&str, &str)]) -> Body {
+        Body::Form(
+            fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    pub fn text(text: String) -> Body {
+        Body::Raw {
+            content_type: "text/plain; charset=utf-8".to_string(),
+            bytes: text.into_bytes(),
+        }
+    }
+
+    // Multipart bodies return None, fetch generates the content type with the boundary
+    fn content_type(&self) -> Option<&str> {
+        match self {
+            Body::Empty | Body::Multipart(_) => None,
+            Body::Json(_) => Some("application/json"),
+            Body::Form(_) => Some("application/x-www-form-urlencoded"),
+            Body::Raw { content_type, .. } => Some(content_type),
+        }
+    }
+
+    fn to_js(&self) -> Result<Option<JsValue>, Error> {
+        let body = match self {
+            Body::Empty => return Ok(None),
+            Body::Json(json) => JsValue::from_str(json),
+            Body::Form(fields) => {
+                let encoded = form_urlencoded::Serializer::new(String::new())
+                    .extend_pairs(fields.iter())
+                    .finish();
+                JsValue::from_str(&encoded)
+            }
+            Body::Multipart(parts) => {
+                let form = FormData::new()?;
+                for part in parts.iter() {
+                    match part {
+                        Part::Text { name, value } => form.append_with_str(name, value)?,
+                        Part::File {
+                            name,
+                            filename,
+                            content_type,
+                            bytes,
+                        } => {
+                            let blob = bytes_to_blob(bytes, content_type)?;
+                            form.append_with_blob_and_filename(name, &blob, filename)?
+                        }
+                    }
+                }
+                form.into()
+            }
+            Body::Raw { bytes, .. } => js_sys::Uint8Array::from(&bytes[..]).into(),
+        };
+        Ok(Some(body))
+    }
+}
+
+fn bytes_to_blob(bytes: &[u8], content_type: &str) -> Result<Blob, Error> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let mut opts = BlobPropertyBag::new();
+    opts.type_(content_type);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &opts)?;
+    Ok(blob)
+}
+
+// Sends the request, retrying idempotent ones on network errors, 429 and 5xx responses.
+// Every attempt is recorded in the request's trace.
+pub async fn send(req: Request) -> Result<Response, Error> {
+    let url = req.full_url()?;
+    let max_attempts = if req.method.is_idempotent() {
+        MAX_ATTEMPTS
+    } else {
+        1
+    };
+    let mut attempt = 1;
+    loop {
+        let started_at = js_sys::Date::now();
+        let result = fetch(&to_web_request(&req, &url)?).await;
+        if let Some(trace) = &req.trace {
+            trace.record(TraceRecord {
+                method: req.method.as_str().to_string(),
+                url: trace::redact_url(&url),
+                headers: trace::redact_headers(&req.headers),
+                status: result.as_ref().ok().map(|resp| resp.status()),
+                error: result.as_ref().err().map(|e| format!("{:?}", e)),
+                duration_ms: js_sys::Date::now() - started_at,
+                attempt,
+            });
+        }
+        let retry = match &result {
+            Ok(resp) => resp.status() == 429 || resp.status() >= 500,
+            Err(_) => true,
+        };
+        if !retry || attempt >= max_attempts {
+            return Ok(Response { inner: result? });
+        }
+        attempt += 1;
+    }
+}
+
+// A body can only be read once, so every attempt gets its own request
+fn to_web_request(req: &Request, url: &str) -> Result<web_sys::Request, Error> {
     let mut opts = RequestInit::new();
 
-    let method = req.method;
-    opts.method(method.as_str());
-    match method {
-        Method::GET | Method::DELETE => {}
-        Method::POST | Method::PUT => {
-            // Equivalent to JSON.stringify in JS
-            let body = JsValue::from_str(&serde_json::to_string(&req.body)?);
-            opts.body(Some(&body));
+    opts.method(req.method.as_str());
+    match req.method {
+        // fetch rejects GET and HEAD requests with a body
+        Method::GET | Method::HEAD => {}
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE => {
+            if let Some(body) = req.body.to_js()? {
+                opts.body(Some(&body));
+            }
         }
     };
 
-    let request = web_sys::Request::new_with_str_and_init(&req.url, &opts)?;
+    let request = web_sys::Request::new_with_str_and_init(url, &opts)?;
     for (k, v) in req.headers.iter() {
         request.headers().set(k, v)?;
     }
+    if let Some(content_type) = req.body.content_type() {
+        if !req.has_header("Content-type") {
+            request.headers().set("Content-type", content_type)?;
+        }
+    }
+
+    Ok(request)
+}
 
-    let window = worker_global_scope().ok_or(Error::NoWindow)?;
+async fn fetch(request: &web_sys::Request) -> Result<web_sys::Response, Error> {
+    let (this, fetch_fn) = fetch_function().ok_or(Error::NoWindow)?;
 
+    let promise: Promise = fetch_fn.call1(&this, request)?.dyn_into()?;
     // `resp_value` is a JS `Response` object.
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
+    let resp_value = JsFuture::from(promise).await?;
 
-    let resp: Response = resp_value.dyn_into()?;
+    let resp: web_sys::Response = resp_value.dyn_into()?;
     Ok(resp)
 }
 
-// Returns global execution context of a service worker
-fn worker_global_scope() -> Option<web_sys::ServiceWorkerGlobalScope> {
-    js_sys::global()
-        .dyn_into::<web_sys::ServiceWorkerGlobalScope>()
-        .ok()
+// Replaces the fetch implementation used by send, for runtimes where the global scope
+// doesn't expose fetch or when requests should be intercepted, e.g. in tests
+pub fn set_fetch(fetch_fn: Option<Function>) {
+    FETCH.with(|f| f.replace(fetch_fn));
+}
+
+pub struct Response {
+    inner: web_sys::Response,
+}
+
+impl Response {
+    pub fn status(&self) -> u16 {
+        self.inner.status()
+    }
+
+    pub fn ok(&self) -> bool {
+        self.inner.ok()
+    }
+
+    pub fn url(&self) -> String {
+        self.inner.url()
+    }
+
+    pub async fn text(self) -> Result<String, Error> {
+        // Convert this Promise into a rust Future.
+        let js_value = JsFuture::from(self.inner.text()?).await?;
+        js_value
+            .as_string()
+            .ok_or_else(|| Error::Jv(JsValue::from_str("Response body is not a string")))
+    }
+
+    pub async fn bytes(self) -> Result<Vec<u8>, Error> {
+        // Convert this Promise into a rust Future.
+        let js_value = JsFuture::from(self.inner.array_buffer()?).await?;
+        Ok(js_sys::Uint8Array::new(&js_value).to_vec())
+    }
+
+    // Deserializes the body as JSON. APIs such as Cloudflare's return their error envelope
+    // with a non 2xx status, so the status is only reported if the body can't be decoded.
+    pub async fn json<T>(self) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let status = self.status();
+        let ok = self.ok();
+        let url = self.url();
+        let body = self.text().await?;
+        match serde_json::from_str(&body) {
+            Ok(val) => Ok(val),
+            Err(_) if !ok => Err(StatusError::new(status, url, &body).into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // Turns a non 2xx response into a StatusError that includes the beginning of the body
+    pub async fn error_for_status(self) -> Result<Response, Error> {
+        if self.ok() {
+            return Ok(self);
+        }
+        let status = self.status();
+        let url = self.url();
+        let body = self.text().await?;
+        Err(StatusError::new(status, url, &body).into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StatusError {
+    pub status: u16,
+    pub url: String,
+    pub body: String,
+}
+
+impl StatusError {
+    fn new(status: u16, url: String, body: &str) -> StatusError {
+        StatusError {
+            status,
+            url,
+            body: truncate(body, MAX_ERROR_BODY_LEN),
+        }
+    }
+}
+
+impl fmt::Display for StatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "http error, status: {}, url: {}, body: {}",
+            self.status, self.url, self.body
+        )
+    }
+}
+
+impl From<StatusError> for Error {
+    fn from(e: StatusError) -> Error {
+        Error::Jv(JsValue::from_str(&e.to_string()))
+    }
+}
+
+fn truncate(s: &str, max_len: usize) -> String {
+    if s.len() <= max_len {
+        return s.to_string();
+    }
+    let mut end = max_len;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &s[..end])
+}
+
+// Returns the fetch function to call and the `this` it should be called with. An injected
+// fetch takes precedence over the one of the global execution context, which works for
+// service workers, module workers, Node and Deno alike.
+fn fetch_function() -> Option<(JsValue, Function)> {
+    if let Some(fetch_fn) = FETCH.with(|f| f.borrow().clone()) {
+        return Some((JsValue::UNDEFINED, fetch_fn));
+    }
+    let global = js_sys::global();
+    let fetch_fn = Reflect::get(&global, &JsValue::from_str("fetch"))
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?;
+    Some((global.into(), fetch_fn))
 }
@@ -0,0 +1,306 @@
+// Message catalogs for user-facing replies and announcements. Plural messages have one
+// entry per form, e.g. clock_skew.one and clock_skew.other, and get the count as {n}.
+// Messages missing from a catalog fall back to English.
+
+struct Catalog {
+    language: &'static str,
+    // Whether a count takes the .one form
+    is_one: fn(u64) -> bool,
+    messages: &'static [(&'static str, &'static str)],
+}
+
+static EN: Catalog = Catalog {
+    language: "en",
+    is_one: |n| n == 1,
+    messages: &[
+        ("no_active_season", "No active lottery yet, wait for the next announcement!"),
+        ("clock_skew.one", "Your clock is {n} second off, please sync it and try again"),
+        ("clock_skew.other", "Your clock is {n} seconds off, please sync it and try again"),
+        ("cooldown", "please submit after {time}"),
+        ("miss", "Not quite what I had in mind, try again"),
+        ("miss_retry_after", "Not quite what I had in mind, try again after {time}"),
+        ("already_won", "Bingo! You already won {tier} with this jackpot"),
+        ("jackpot_claimed.one", "Bingo! But this {tier} jackpot has already been claimed {n} time"),
+        ("jackpot_claimed.other", "Bingo! But this {tier} jackpot has already been claimed {n} times"),
+        ("win", "Bingo! You hit a {tier} jackpot"),
+        ("win_announcement_continue", "🎉 {submitter} hit a {tier} jackpot of season {season}! The season goes on, keep guessing"),
+        ("win_announcement", "🎉 {submitter} hit the {tier} jackpot {jackpot} and won season {season}!"),
+        ("no_guesses", "You haven't submit anything yet!"),
+        ("season_start", "{calendar} season {season} commence, {status}"),
+        ("season_end", "{calendar} season {season} ends, {status}"),
+        ("raffle_winners", ". 🎉 Congratulations to {winners}"),
+        ("status_active", "Season {season} is on, {status}"),
+        ("status_over", "Season {season} is over, {status}"),
+        ("unknown_emoji", "I don't know the emoji {emoji}, send an emoji, its :shortcode: or its name"),
+        ("not_in_pool", "{emoji} isn't part of this season, the jackpot is one of: {pool}"),
+        ("repeat_guess", "You already tried {emoji} this season, pick another one"),
+        ("hint_hot", "🔥 Hot! A jackpot is also in {subgroup}"),
+        ("hint_warm", "🌤️ Warm, a jackpot is also in {group}"),
+        ("hint_cold", "🥶 Cold, no jackpot is in that group"),
+        ("lottery_active", "jackpot commitment (sha256 of jackpot:salt) is {commitment}"),
+        ("season_theme", ". This season's theme is {pool}"),
+        ("lottery_over", "jackpot was {jackpots} with salt {salt}, verify it against commitment {commitment}"),
+        ("nobody_found", ". Nobody found it this time"),
+        ("puzzle_length.one", "Send a sequence of {n} emoji, e.g. 🐙 🦑 🦀"),
+        ("puzzle_length.other", "Send a sequence of {n} emoji, e.g. 🐙 🦑 🦀"),
+        ("puzzle_grid.one", "Emoji puzzle season {season}, {n} try"),
+        ("puzzle_grid.other", "Emoji puzzle season {season}, {n} tries"),
+        ("puzzle_active.one", "guess the sequence of {n} emoji, commitment (sha256 of sequence:salt) is {commitment}"),
+        ("puzzle_active.other", "guess the sequence of {n} emoji, commitment (sha256 of sequence:salt) is {commitment}"),
+        ("puzzle_over", "the sequence was {sequence} with salt {salt}, verify it against commitment {commitment}"),
+        ("nobody_solved", ". Nobody solved it this time"),
+        ("raffle_entered.one", "🎟️ You're in the raffle with {n} entry, winners are drawn when the season ends"),
+        ("raffle_entered.other", "🎟️ You're in the raffle with {n} entries, winners are drawn when the season ends"),
+        ("raffle_already_entered", "You're already in the raffle, good luck!"),
+        ("raffle_active.one", "enter the raffle, the winner is drawn when the season ends"),
+        ("raffle_active.other", "enter the raffle to be one of the {n} winners drawn when the season ends"),
+        ("raffle_drawn", "the raffle is drawn"),
+        ("raffle_closed", "the raffle is closed, nobody entered this time"),
+        ("guess", "{guess} submitted at {time}"),
+        ("guess_count.one", "{n} guess this season"),
+        ("guess_count.other", "{n} guesses this season"),
+        ("latest_guesses", ", the latest {n} are"),
+        ("winner.one", "{submitter} won {tier} with {emoji} after {n} guess on {platform} at {time}"),
+        ("winner.other", "{submitter} won {tier} with {emoji} after {n} guesses on {platform} at {time}"),
+        ("no_seasons", "No season has ended yet"),
+        ("past_seasons", "Past seasons: {seasons}. Ask for one of them to see its jackpot and winners"),
+        ("season_not_archived", "Season {season} isn't in the archive"),
+        ("season_ran", "Season {season} ran from {started_at} to {ended_at}"),
+        ("season_jackpots", "Jackpot: {jackpots}"),
+        ("season_fairness", "Salt {salt}, commitment {commitment}"),
+        ("season_guesses.one", "{guesses} guesses from {n} participant"),
+        ("season_guesses.other", "{guesses} guesses from {n} participants"),
+        ("season_no_winner", "Nobody found it"),
+        ("no_hall_of_fame", "Nobody has won a season yet"),
+        ("hall_of_fame", "🏆 Hall of fame"),
+        ("hall_of_fame_entry", "Season {season}: {winner}"),
+        ("board_hidden", "This is a hard mode season, the elimination board is hidden"),
+        ("board_empty", "Nothing has been ruled out yet"),
+        ("board.one", "🚫 {n} emoji ruled out"),
+        ("board.other", "🚫 {n} emoji ruled out"),
+    ],
+};
+
+static FR: Catalog = Catalog {
+    language: "fr",
+    is_one: |n| n < 2,
+    messages: &[
+        ("no_active_season", "Pas encore de loterie en cours, attendez la prochaine annonce !"),
+        ("clock_skew.one", "Votre horloge a {n} seconde d'écart, synchronisez-la et réessayez"),
+        ("clock_skew.other", "Votre horloge a {n} secondes d'écart, synchronisez-la et réessayez"),
+        ("cooldown", "merci de réessayer après {time}"),
+        ("miss", "Ce n'est pas tout à fait ça, réessayez"),
+        ("miss_retry_after", "Ce n'est pas tout à fait ça, réessayez après {time}"),
+        ("already_won", "Bingo ! Vous avez déjà gagné {tier} avec ce jackpot"),
+        ("jackpot_claimed.one", "Bingo ! Mais ce jackpot {tier} a déjà été remporté {n} fois"),
+        ("jackpot_claimed.other", "Bingo ! Mais ce jackpot {tier} a déjà été remporté {n} fois"),
+        ("win", "Bingo ! Vous avez décroché un jackpot {tier}"),
+        ("win_announcement_continue", "🎉 {submitter} a décroché un jackpot {tier} de la saison {season} ! La saison continue, continuez à deviner"),
+        ("win_announcement", "🎉 {submitter} a décroché le jackpot {tier} {jackpot} et remporte la saison {season} !"),
+        ("no_guesses", "Vous n'avez encore rien proposé !"),
+        ("season_start", "{calendar} : la saison {season} commence, {status}"),
+        ("season_end", "{calendar} : la saison {season} se termine, {status}"),
+        ("raffle_winners", ". 🎉 Félicitations à {winners}"),
+        ("status_active", "La saison {season} est en cours, {status}"),
+        ("status_over", "La saison {season} est terminée, {status}"),
+        ("unknown_emoji", "Je ne connais pas l'emoji {emoji}, envoyez un emoji, son :shortcode: ou son nom"),
+        ("not_in_pool", "{emoji} ne fait pas partie de cette saison, le jackpot est parmi : {pool}"),
+        ("repeat_guess", "Vous avez déjà essayé {emoji} cette saison, choisissez-en un autre"),
+        ("hint_hot", "🔥 Chaud ! Un jackpot est aussi dans {subgroup}"),
+        ("hint_warm", "🌤️ Tiède, un jackpot est aussi dans {group}"),
+        ("hint_cold", "🥶 Froid, aucun jackpot n'est dans ce groupe"),
+        ("lottery_active", "l'engagement du jackpot (sha256 de jackpot:sel) est {commitment}"),
+        ("season_theme", ". Le thème de cette saison est {pool}"),
+        ("lottery_over", "le jackpot était {jackpots} avec le sel {salt}, vérifiez-le avec l'engagement {commitment}"),
+        ("nobody_found", ". Personne ne l'a trouvé cette fois"),
+        ("puzzle_length.one", "Envoyez une séquence de {n} emoji, par exemple 🐙 🦑 🦀"),
+        ("puzzle_length.other", "Envoyez une séquence de {n} emoji, par exemple 🐙 🦑 🦀"),
+        ("puzzle_grid.one", "Puzzle emoji saison {season}, {n} essai"),
+        ("puzzle_grid.other", "Puzzle emoji saison {season}, {n} essais"),
+        ("puzzle_active.one", "devinez la séquence de {n} emoji, l'engagement (sha256 de séquence:sel) est {commitment}"),
+        ("puzzle_active.other", "devinez la séquence de {n} emoji, l'engagement (sha256 de séquence:sel) est {commitment}"),
+        ("puzzle_over", "la séquence était {sequence} avec le sel {salt}, vérifiez-la avec l'engagement {commitment}"),
+        ("nobody_solved", ". Personne ne l'a résolu cette fois"),
+        ("raffle_entered.one", "🎟️ Vous participez au tirage avec {n} ticket, les gagnants sont tirés à la fin de la saison"),
+        ("raffle_entered.other", "🎟️ Vous participez au tirage avec {n} tickets, les gagnants sont tirés à la fin de la saison"),
+        ("raffle_already_entered", "Vous participez déjà au tirage, bonne chance !"),
+        ("raffle_active.one", "participez au tirage, le gagnant est tiré à la fin de la saison"),
+        ("raffle_active.other", "participez au tirage pour être l'un des {n} gagnants tirés à la fin de la saison"),
+        ("raffle_drawn", "le tirage a eu lieu"),
+        ("raffle_closed", "le tirage est clos, personne n'a participé cette fois"),
+        ("guess", "{guess} proposé le {time}"),
+        ("guess_count.one", "{n} essai cette saison"),
+        ("guess_count.other", "{n} essais cette saison"),
+        ("latest_guesses", ", voici les {n} derniers"),
+        ("winner.one", "{submitter} a gagné {tier} avec {emoji} après {n} essai sur {platform} le {time}"),
+        ("winner.other", "{submitter} a gagné {tier} avec {emoji} après {n} essais sur {platform} le {time}"),
+        ("no_seasons", "Aucune saison n'est encore terminée"),
+        ("past_seasons", "Saisons passées : {seasons}. Demandez-en une pour voir son jackpot et ses gagnants"),
+        ("season_not_archived", "La saison {season} n'est pas dans les archives"),
+        ("season_ran", "La saison {season} a duré du {started_at} au {ended_at}"),
+        ("season_jackpots", "Jackpot : {jackpots}"),
+        ("season_fairness", "Sel {salt}, engagement {commitment}"),
+        ("season_guesses.one", "{guesses} essais de {n} participant"),
+        ("season_guesses.other", "{guesses} essais de {n} participants"),
+        ("season_no_winner", "Personne ne l'a trouvé"),
+        ("no_hall_of_fame", "Personne n'a encore gagné de saison"),
+        ("hall_of_fame", "🏆 Palmarès"),
+        ("hall_of_fame_entry", "Saison {season} : {winner}"),
+        ("board_hidden", "Cette saison est en mode difficile, le tableau des éliminations est masqué"),
+        ("board_empty", "Rien n'a encore été éliminé"),
+        ("board.one", "🚫 {n} emoji éliminé"),
+        ("board.other", "🚫 {n} emoji éliminés"),
+    ],
+};
+
+static CATALOGS: &[&Catalog] = &[&EN, &FR];
+
+#[derive(Clone, Copy)]
+pub struct Messages {
+    catalog: &'static Catalog,
+}
+
+impl Messages {
+    // Picks the first locale there's a catalog for, e.g. the user's then the tenant's,
+    // English if there's none. Locales can be given with a region, e.g. fr-CA or en_US.
+    pub fn negotiate(locales: &[Option<&str>]) -> Messages {
+        let catalog = locales
+            .iter()
+            .filter_map(|locale| *locale)
+            .filter_map(|locale| {
+                let language = locale
+                    .split(['-', '_'])
+                    .next()
+                    .unwrap_or("")
+                    .to_ascii_lowercase();
+                CATALOGS.iter().find(|c| c.language == language).copied()
+            })
+            .next()
+            .unwrap_or(&EN);
+        Messages { catalog }
+    }
+
+    pub fn get(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let message = find(self.catalog, key)
+            .or_else(|| find(&EN, key))
+            .unwrap_or(key);
+        format(message, args)
+    }
+
+    // Picks the plural form for n, which is passed to the message as {n}
+    pub fn count(&self, key: &str, n: u64, args: &[(&str, &str)]) -> String {
+        let form = if (self.catalog.is_one)(n) {
+            "one"
+        } else {
+            "other"
+        };
+        let n = n.to_string();
+        let mut args = args.to_vec();
+        args.push(("n", &n));
+        self.get(&format!("{}.{}", key, form), &args)
+    }
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Messages { catalog: &EN }
+    }
+}
+
+fn find(catalog: &'static Catalog, key: &str) -> Option<&'static str> {
+    catalog
+        .messages
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, message)| *message)
+}
+
+// Fills in the placeholders of the message in a single pass, so values are never expanded
+// themselves, e.g. a submitter named {jackpot}. Unknown placeholders are left as they are.
+fn format(message: &str, args: &[(&str, &str)]) -> String {
+    let mut formatted = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        formatted.push_str(&rest[..start]);
+        let placeholder = &rest[start..];
+        let arg = placeholder.find('}').and_then(|end| {
+            let name = &placeholder[1..end];
+            args.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, value)| (end, *value))
+        });
+        match arg {
+            Some((end, value)) => {
+                formatted.push_str(value);
+                rest = &placeholder[end + 1..];
+            }
+            None => {
+                formatted.push('{');
+                rest = &placeholder[1..];
+            }
+        }
+    }
+    formatted.push_str(rest);
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate() {
+        let fr = Messages::negotiate(&[Some("fr-CA"), Some("en")]);
+        assert_eq!(fr.catalog.language, "fr");
+        // Unknown locales are skipped, then English is the fallback
+        let fr = Messages::negotiate(&[Some("tlh"), None, Some("fr_FR")]);
+        assert_eq!(fr.catalog.language, "fr");
+        assert_eq!(Messages::negotiate(&[Some("tlh")]).catalog.language, "en");
+        assert_eq!(Messages::negotiate(&[]).catalog.language, "en");
+    }
+
+    #[test]
+    fn missing_messages_fall_back_to_english() {
+        static EMPTY: Catalog = Catalog {
+            language: "xx",
+            is_one: |n| n == 1,
+            messages: &[],
+        };
+        let messages = Messages { catalog: &EMPTY };
+        assert_eq!(
+            messages.get("miss", &[]),
+            Messages::default().get("miss", &[])
+        );
+        // Unknown keys come back as they are
+        assert_eq!(messages.get("no_such_key", &[]), "no_such_key");
+    }
+
+    #[test]
+    fn count() {
+        let en = Messages::default();
+        assert_eq!(en.count("guess_count", 0, &[]), "0 guesses this season");
+        assert_eq!(en.count("guess_count", 1, &[]), "1 guess this season");
+        assert_eq!(en.count("guess_count", 2, &[]), "2 guesses this season");
+        // Zero is singular in French
+        let fr = Messages::negotiate(&[Some("fr")]);
+        assert_eq!(fr.count("guess_count", 0, &[]), "0 essai cette saison");
+        assert_eq!(fr.count("guess_count", 1, &[]), "1 essai cette saison");
+        assert_eq!(fr.count("guess_count", 2, &[]), "2 essais cette saison");
+    }
+
+    #[test]
+    fn placeholders_in_values_are_not_expanded() {
+        assert_eq!(
+            format(
+                "{submitter} found {jackpot}",
+                &[("submitter", "{jackpot}"), ("jackpot", "🐙")]
+            ),
+            "{jackpot} found 🐙"
+        );
+        // Unknown and unclosed placeholders are left as they are
+        assert_eq!(
+            format("{unknown} {jackpot", &[("jackpot", "🐙")]),
+            "{unknown} {jackpot"
+        );
+    }
+}
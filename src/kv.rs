@@ -1,77 +1,52 @@
+use super::cloudflare::{CloudflareClient, CloudflareConfig, NoResultResponse};
 use super::error;
-use super::http::{send, Method, Request};
+use super::http::{send, Body, Method};
+use super::i18n::Messages;
 
 use chrono::prelude::*;
-use js_sys::ArrayBuffer;
 use serde::{de::DeserializeOwned, Serialize};
-use serde_json;
-use std::collections::HashMap;
-use std::fmt;
 use wasm_bindgen::JsValue;
-use wasm_bindgen_futures::JsFuture;
-use web_sys::TextDecoder;
 
-#[derive(Deserialize)]
-pub struct KVConfig {
-    token: String,
-    account_id: String,
-}
+pub type KVConfig = CloudflareConfig;
 
 pub struct KVClient {
-    token: String,
-    account_id: String,
+    client: CloudflareClient,
     namespace_id: String,
 }
 
 impl KVClient {
     pub fn new(config: KVConfig, namespace_id: String) -> KVClient {
         KVClient {
-            token: config.token,
-            account_id: config.account_id,
+            client: CloudflareClient::new(config),
             namespace_id,
         }
     }
 
-    pub async fn read(&self, key: &str) -> Result<Option<Guess>, error::Error> {
-        let mut headers = HashMap::new();
-        headers.insert(
-            "Authorization".to_string(),
-            format!("Bearer {}", self.token),
-        );
-        headers.insert("Content-type".to_string(), "application/json".to_string());
-        let url = format!(
-            "https://api.cloudflare.com/client/v4/accounts/{}/storage/kv/namespaces/{}/values/{}",
-            self.account_id, self.namespace_id, key
+    pub async fn read<T>(&self, key: &str) -> Result<Option<T>, error::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let path = format!(
+            "/storage/kv/namespaces/{}/values/{}",
+            self.namespace_id, key
         );
-        let req = Request {
-            url: url,
-            method: Method::GET,
-            headers: headers,
-            body: (),
-        };
-        let js_resp = send(req).await?;
-
-        if js_resp.ok() {
-            // Convert this Promise into a rust Future.
-            let js_value = JsFuture::from(js_resp.array_buffer()?).await?;
-            let array_buffer = ArrayBuffer::from(js_value);
-            let decoder = TextDecoder::new_with_label("utf-8")?;
-            let content = decoder.decode_with_buffer_source(&array_buffer)?;
-            let last: Guess = serde_json::from_str(&content)?;
-            Ok(Some(last))
+        let req = self.client.account_request(Method::GET, &path);
+        let resp = send(req).await?;
+
+        if resp.ok() {
+            let val: T = resp.json().await?;
+            Ok(Some(val))
         } else {
-            // Convert this Promise into a rust Future.
-            let json = JsFuture::from(js_resp.json()?).await?;
-            let resp = json.into_serde::<NoResultResponse>()?;
-            let err_code = resp.errors[0].code;
+            let resp: NoResultResponse = resp.json().await?;
             // 10009 is key not found
-            if err_code == 10009 {
+            if resp.errors.iter().any(|e| e.code == 10009) {
                 return Ok(None);
             }
-            return Err(error::Error::Jv(JsValue::from_str(&format!(
-                "Failed to read key, err code {}, message {}",
-                err_code, resp.errors[0].message
-            ))));
+            resp.result()?;
+            Err(error::Error::Jv(JsValue::from_str(&format!(
+                "Failed to read key {}, it came back without a value",
+                key
+            ))))
         }
     }
 
@@ -79,146 +54,32 @@ impl KVClient {
     where
         T: Serialize,
     {
-        let mut headers = HashMap::new();
-        headers.insert(
-            "Authorization".to_string(),
-            format!("Bearer {}", self.token),
-        );
-        headers.insert("Content-type".to_string(), "application/json".to_string());
-        let url = format!(
-            "https://api.cloudflare.com/client/v4/accounts/{}/storage/kv/namespaces/{}/values/{}",
-            self.account_id, self.namespace_id, key
+        let path = format!(
+            "/storage/kv/namespaces/{}/values/{}",
+            self.namespace_id, key
         );
-        let req = Request {
-            url: url,
-            method: Method::PUT,
-            headers: headers,
-            body: val,
-        };
-        let js_resp = send(req).await?;
-
-        // Convert this Promise into a rust Future.
-        let json = JsFuture::from(js_resp.json()?).await?;
-
-        let resp = json.into_serde::<NoResultResponse>()?;
-        resp.result()
+        let req = self
+            .client
+            .account_request(Method::PUT, &path)
+            .body(Body::json(&val)?);
+        self.client.no_result(req).await
     }
 
     pub async fn create_namespace(
         &self,
         title: String,
     ) -> Result<CreateNamespaceResult, error::Error> {
-        let mut headers = HashMap::new();
-        headers.insert(
-            "Authorization".to_string(),
-            format!("Bearer {}", self.token),
-        );
-        headers.insert("Content-type".to_string(), "application/json".to_string());
-        let url = format!(
-            "https://api.cloudflare.com/client/v4/accounts/{}/storage/kv/namespaces",
-            self.account_id
-        );
-        let req = Request {
-            url: url,
-            method: Method::POST,
-            headers: headers,
-            body: CreateNamespaceBody { title: title },
-        };
-        let js_resp = send(req).await?;
-
-        // Convert this Promise into a rust Future.
-        let json = JsFuture::from(js_resp.json()?).await?;
-
-        let resp = json.into_serde::<ExpectResultResponse<CreateNamespaceResult>>()?;
-
-        resp.result()
+        let req = self
+            .client
+            .account_request(Method::POST, "/storage/kv/namespaces")
+            .body(Body::json(&CreateNamespaceBody { title: title })?);
+        self.client.expect_result(req).await
     }
 
     pub async fn delete_namespace(&self, namespace_id: String) -> Result<(), error::Error> {
-        let mut headers = HashMap::new();
-        headers.insert(
-            "Authorization".to_string(),
-            format!("Bearer {}", self.token),
-        );
-        headers.insert("Content-type".to_string(), "application/json".to_string());
-        let url = format!(
-            "https://api.cloudflare.com/client/v4/accounts/{}/storage/kv/namespaces/{}",
-            self.account_id, namespace_id
-        );
-        let req = Request {
-            url: url,
-            method: Method::DELETE,
-            headers: headers,
-            body: (),
-        };
-        let js_resp = send(req).await?;
-
-        // Convert this Promise into a rust Future.
-        let json = JsFuture::from(js_resp.json()?).await?;
-
-        let resp = json.into_serde::<NoResultResponse>()?;
-
-        resp.result()
-    }
-}
-
-#[derive(Deserialize, Debug)]
-pub struct ExpectResultResponse<T>
-where
-    T: Clone,
-{
-    pub result: Option<T>,
-    pub success: bool,
-    pub errors: Vec<Error>,
-    pub messages: Vec<String>,
-}
-
-impl<T> ExpectResultResponse<T>
-where
-    T: Clone,
-{
-    pub fn result(&self) -> Result<T, error::Error> {
-        if self.errors.len() > 0 {
-            Err(error::Error::from(self.errors[0].clone()))
-        } else {
-            match &self.result {
-                Some(r) => Ok(r.clone()),
-                None => Err(error::Error::KvNoResult),
-            }
-        }
-    }
-}
-
-#[derive(Deserialize, Debug)]
-pub struct NoResultResponse {
-    pub success: bool,
-    pub errors: Vec<Error>,
-    pub messages: Vec<String>,
-}
-
-impl NoResultResponse {
-    pub fn result(&self) -> Result<(), error::Error> {
-        if self.errors.len() > 0 {
-            Err(error::Error::from(self.errors[0].clone()))
-        } else {
-            Ok(())
-        }
-    }
-}
-
-#[derive(Deserialize, Clone, Debug)]
-pub struct Error {
-    pub code: u16,
-    pub message: String,
-}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "kv error, code: {}, message: {}",
-            self.code, self.message
-        )
+        let path = format!("/storage/kv/namespaces/{}", namespace_id);
+        let req = self.client.account_request(Method::DELETE, &path);
+        self.client.no_result(req).await
     }
 }
 
@@ -237,16 +98,61 @@ pub struct CreateNamespaceResult {
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Guess {
     pub value: String,
+    // Taken from the worker's clock
     pub created_at: i64,
+    // What the client claimed, kept for auditing
+    #[serde(default)]
+    pub client_ts: Option<i64>,
+}
+
+impl Guess {
+    pub fn localize(&self, messages: &Messages) -> String {
+        messages.get(
+            "guess",
+            &[
+                ("guess", &self.value),
+                ("time", &readable_time(self.created_at).to_string()),
+            ],
+        )
+    }
+}
+
+pub fn readable_time(ts: i64) -> DateTime<Utc> {
+    // Create a NaiveDateTime from the timestamp
+    let naive = NaiveDateTime::from_timestamp(ts, 0);
+
+    // Create a normal DateTime from the NaiveDateTime
+    DateTime::from_utc(naive, Utc)
 }
 
-impl fmt::Display for Guess {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Create a NaiveDateTime from the timestamp
-        let naive = NaiveDateTime::from_timestamp(self.created_at, 0);
+// Stored in the data namespace, next to the guesses keyed by submitter
+pub const WINNERS_KEY: &str = "lottery:winners";
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Winner {
+    pub submitter: String,
+    pub emoji: String,
+    #[serde(default)]
+    pub tier: String,
+    // How many guesses it took, counting the winning one
+    #[serde(default)]
+    pub guesses: u64,
+    pub won_at: i64,
+    pub platform: String,
+}
 
-        // Create a normal DateTime from the NaiveDateTime
-        let readable_time: DateTime<Utc> = DateTime::from_utc(naive, Utc);
-        write!(f, "{} submitted at {}", self.value, readable_time)
+impl Winner {
+    pub fn localize(&self, messages: &Messages) -> String {
+        messages.count(
+            "winner",
+            self.guesses,
+            &[
+                ("submitter", &self.submitter),
+                ("tier", &self.tier),
+                ("emoji", &self.emoji),
+                ("platform", &self.platform),
+                ("time", &readable_time(self.won_at).to_string()),
+            ],
+        )
     }
 }
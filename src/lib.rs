@@ -5,24 +5,39 @@ extern crate serde_derive;
 extern crate wasm_bindgen;
 extern crate wasm_bindgen_futures;
 
+mod archive;
+mod board;
 mod calendar;
+mod cloudflare;
+mod cooldown;
 mod discord;
 mod emoji;
+mod emoji_catalog;
 mod error;
+mod fairness;
+mod game;
+mod history;
 mod http;
+mod i18n;
 mod kv;
+mod normalize;
+mod puzzle;
+mod raffle;
 mod route;
 mod slack;
+mod tenant;
+mod trace;
 #[macro_use]
 mod utils;
 
 use calendar::{calendar_end, calendar_start, NotifyTo};
 use cfg_if::cfg_if;
-use discord::{checkLastSubmission, submit, DiscordConfig};
+use discord::{checkLastSubmission, my_guesses, submit, DiscordConfig};
 use emoji::LotteryConfig;
 use kv::KVConfig;
 use route::Route;
 use slack::SlackConfig;
+use trace::Trace;
 use url::Url;
 use uuid::Uuid;
 use wasm_bindgen::prelude::*;
@@ -79,51 +94,141 @@ pub struct MessageEvent {
 
 #[derive(Deserialize)]
 pub struct BotConfig {
-    discord: DiscordConfig,
+    // Tenants can leave out the platform they aren't on, see tenant::Tenant
+    discord: Option<DiscordConfig>,
     kv: KVConfig,
     emoji: LotteryConfig,
-    slack: SlackConfig,
+    slack: Option<SlackConfig>,
 }
 
 impl BotConfig {
     pub fn mention_maintainer(&self, notify_to: &calendar::NotifyTo) -> String {
         match notify_to {
-            NotifyTo::Discord => format!("<@{}>", self.discord.maintainer).to_string(),
-            NotifyTo::Slack => format!("@{}", self.slack.maintainer).to_string(),
+            NotifyTo::Discord => match &self.discord {
+                Some(discord) => format!("<@{}>", discord.maintainer),
+                None => String::new(),
+            },
+            NotifyTo::Slack => match &self.slack {
+                Some(slack) => format!("@{}", slack.maintainer),
+                None => String::new(),
+            },
+        }
+    }
+
+    // Platforms announcements go to, tenants are only on some of them
+    pub fn notify_targets(&self) -> Vec<NotifyTo> {
+        let mut targets = Vec::new();
+        if self.discord.is_some() {
+            targets.push(NotifyTo::Discord);
+        }
+        if self.slack.is_some() {
+            targets.push(NotifyTo::Slack);
+        }
+        targets
+    }
+
+    // Records the outbound calls of every client built from this config into trace
+    fn trace_to(&mut self, trace: &Trace) {
+        self.kv.trace = trace.clone();
+        if let Some(discord) = &mut self.discord {
+            discord.trace = trace.clone();
+        }
+        if let Some(slack) = &mut self.slack {
+            slack.trace = trace.clone();
         }
     }
 }
 
 #[wasm_bindgen]
 pub async fn interactive_bot(req: JsValue, bot_config: JsValue) -> Result<JsValue, JsValue> {
-    let bot_config: BotConfig = bot_config.into_serde().map_err(|e| e.to_string())?;
+    let trace = Trace::default();
+    handle(req, bot_config, &trace)
+        .await
+        .map_err(|e| trace::attach(e, &trace))
+}
+
+async fn handle(req: JsValue, bot_config: JsValue, trace: &Trace) -> Result<JsValue, JsValue> {
+    let mut bot_config: BotConfig = bot_config.into_serde().map_err(|e| e.to_string())?;
+    bot_config.trace_to(trace);
 
     let req = Request::from(req);
     let url_str = req.url();
     let url = Url::parse(&url_str).map_err(|_| format!("{:?} is not a valid url", url_str))?;
 
+    let bot_config = match route::tenant(&url) {
+        Some(tenant) => {
+            let mut bot_config = tenant::scope(bot_config, &tenant)
+                .await
+                .map_err(|e| format!("Failed to load tenant {}, err: {:?}", tenant, e))?;
+            bot_config.trace_to(trace);
+            bot_config
+        }
+        None => bot_config,
+    };
+
     match Route::from(&url) {
-        Route::CalendarStart => calendar_start(req, bot_config, NotifyTo::Discord).await,
-        Route::CalendarEnd => calendar_end(req, bot_config, NotifyTo::Discord).await,
-        Route::Events => events(req).await,
+        Route::CalendarStart => calendar_start(req, bot_config).await,
+        Route::CalendarEnd => calendar_end(req, bot_config).await,
+        Route::Events => events(req, bot_config).await,
+        Route::Status => calendar::status(&bot_config),
         Route::Submit { submitter } => submit(req, submitter, bot_config).await,
-        Route::CheckLastSubmission { submitter } => {
-            checkLastSubmission(submitter, bot_config).await
+        Route::CheckLastSubmission { submitter, locale } => {
+            checkLastSubmission(submitter, locale, bot_config).await
+        }
+        Route::GuessHistory { submitter, locale } => {
+            my_guesses(submitter, locale, bot_config).await
         }
+        Route::Seasons => archive::list_seasons(&bot_config).await,
+        Route::Season { season } => archive::show_season(&bot_config, season).await,
+        Route::HallOfFame => archive::hall_of_fame(&bot_config).await,
+        Route::Board => board::show_board(&bot_config).await,
+        Route::Verify {
+            jackpot,
+            salt,
+            commitment,
+        } => fairness::verify_season(jackpot, salt, commitment),
         Route::Unhandled => Err(unhandled(&url)),
     }
 }
 
-async fn events(req: Request) -> Result<JsValue, JsValue> {
+async fn events(req: Request, bot_config: BotConfig) -> Result<JsValue, JsValue> {
     let body = JsFuture::from(req.json()?).await?;
     // Using into_serde API requires activating the serde-serialize feature of the wasm-bindgen crate.
     let event: MessageEvent = body.into_serde().map_err(|e| e.to_string())?;
-    Ok(JsValue::TRUE)
+    // Commands answered in the announcement channel, the discord gateway maps the same
+    // commands to /status, /seasons, /seasons/:season, /winners and /board
+    let mut words = event.text.split_whitespace();
+    let reply = match (words.next(), words.next().map(|s| s.parse::<u64>())) {
+        (Some("!status"), None) => calendar::status(&bot_config)?,
+        (Some("!seasons"), None) => archive::list_seasons(&bot_config).await?,
+        (Some("!season"), Some(Ok(season))) => archive::show_season(&bot_config, season).await?,
+        (Some("!winners"), None) => archive::hall_of_fame(&bot_config).await?,
+        (Some("!board"), None) => board::show_board(&bot_config).await?,
+        _ => return Ok(JsValue::TRUE),
+    };
+    match reply.as_string() {
+        Some(msg) => calendar::notify(&bot_config, &NotifyTo::Slack, msg).await,
+        None => Ok(JsValue::TRUE),
+    }
 }
 fn unhandled(url: &Url) -> JsValue {
     JsValue::from_str(&format!("No handler defined for route {:?}", url.path()))
 }
 
+// Expose a function to JS to inject the fetch used for outbound requests, for runtimes that
+// don't have a global fetch. Passing undefined restores the global one.
+#[wasm_bindgen]
+pub fn set_fetch(fetch: Option<js_sys::Function>) {
+    http::set_fetch(fetch);
+}
+
+// Expose a function to JS that checks a revealed jackpot and salt against the commitment
+// published when the season commenced
+#[wasm_bindgen]
+pub fn verify_commitment(jackpot: &str, salt: &str, commitment: &str) -> bool {
+    fairness::verify(jackpot, salt, commitment)
+}
+
 // Expose a function to JS that generates v4 UUID
 #[wasm_bindgen]
 pub fn uuid() -> String {
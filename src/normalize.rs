@@ -0,0 +1,140 @@
+use super::emoji_catalog::EMOJIS;
+
+use std::collections::HashMap;
+
+const TEXT_PRESENTATION_SELECTOR: char = '\u{FE0E}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+
+thread_local! {
+    // Catalog emoji keyed by their spelling without variation selectors, built on first use.
+    // The first entry wins, like a scan of the catalog would.
+    static BY_STRIPPED: HashMap<String, &'static str> = {
+        let mut index = HashMap::new();
+        for e in EMOJIS.iter() {
+            index.entry(strip_variation_selectors(e.emoji)).or_insert(e.emoji);
+        }
+        index
+    };
+}
+
+/*
+Sample NormalizeConfig, all rules are optional
+{
+    "trim_whitespace": true,
+    "ignore_variation_selectors": true,
+    "fold_skin_tones": false
+}
+*/
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct NormalizeConfig {
+    #[serde(default = "enabled")]
+    pub trim_whitespace: bool,
+    // Treats ❤ and ❤️ (U+2764 U+FE0F) as the same emoji
+    #[serde(default = "enabled")]
+    pub ignore_variation_selectors: bool,
+    // Treats 👋🏽 as 👋
+    #[serde(default)]
+    pub fold_skin_tones: bool,
+}
+
+impl Default for NormalizeConfig {
+    fn default() -> Self {
+        NormalizeConfig {
+            trim_whitespace: true,
+            ignore_variation_selectors: true,
+            fold_skin_tones: false,
+        }
+    }
+}
+
+fn enabled() -> bool {
+    true
+}
+
+// Returns the canonical form of an emoji according to the rules, so that two spellings
+// of the same emoji compare equal. Variation selectors are stripped and put back the way
+// the emoji catalog spells the fully-qualified sequence.
+pub fn normalize(emoji: &str, rules: &NormalizeConfig) -> String {
+    let emoji = if rules.trim_whitespace {
+        emoji.trim()
+    } else {
+        emoji
+    };
+    let mut normalized: String = emoji
+        .chars()
+        .filter(|c| !(rules.fold_skin_tones && is_skin_tone(*c)))
+        .collect();
+    if rules.ignore_variation_selectors {
+        let stripped = strip_variation_selectors(&normalized);
+        normalized = canonical(&stripped)
+            .map(|e| e.to_string())
+            .unwrap_or(stripped);
+    }
+    normalized
+}
+
+// The catalog's spelling of an emoji, whatever its variation selectors
+pub fn canonical(emoji: &str) -> Option<&'static str> {
+    BY_STRIPPED.with(|index| index.get(&strip_variation_selectors(emoji)).copied())
+}
+
+pub fn is_skin_tone(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+fn strip_variation_selectors(emoji: &str) -> String {
+    emoji
+        .chars()
+        .filter(|c| *c != TEXT_PRESENTATION_SELECTOR && *c != EMOJI_PRESENTATION_SELECTOR)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEART: &str = "\u{2764}";
+    const RED_HEART: &str = "\u{2764}\u{FE0F}";
+    const THUMBS_UP: &str = "\u{1F44D}";
+    const THUMBS_UP_MEDIUM: &str = "\u{1F44D}\u{1F3FD}";
+
+    #[test]
+    fn variation_selectors() {
+        let rules = NormalizeConfig::default();
+        assert_eq!(normalize(HEART, &rules), RED_HEART);
+        assert_eq!(normalize(RED_HEART, &rules), RED_HEART);
+        assert_eq!(normalize("\u{2764}\u{FE0E}", &rules), RED_HEART);
+        assert_eq!(canonical(HEART), Some(RED_HEART));
+
+        let rules = NormalizeConfig {
+            ignore_variation_selectors: false,
+            ..NormalizeConfig::default()
+        };
+        assert_eq!(normalize(HEART, &rules), HEART);
+    }
+
+    #[test]
+    fn skin_tones() {
+        let rules = NormalizeConfig::default();
+        assert_eq!(normalize(THUMBS_UP_MEDIUM, &rules), THUMBS_UP_MEDIUM);
+
+        let rules = NormalizeConfig {
+            fold_skin_tones: true,
+            ..NormalizeConfig::default()
+        };
+        assert_eq!(normalize(THUMBS_UP_MEDIUM, &rules), THUMBS_UP);
+        assert_eq!(normalize(THUMBS_UP, &rules), THUMBS_UP);
+    }
+
+    #[test]
+    fn whitespace() {
+        let rules = NormalizeConfig::default();
+        assert_eq!(normalize(" \u{1F419}\n", &rules), "\u{1F419}");
+
+        let rules = NormalizeConfig {
+            trim_whitespace: false,
+            ..NormalizeConfig::default()
+        };
+        assert_eq!(normalize(" \u{1F419}", &rules), " \u{1F419}");
+    }
+}
@@ -0,0 +1,324 @@
+use super::cooldown::{self, CooldownPolicy};
+use super::emoji::{lookup, Jackpot, LotteryConfig};
+use super::error::Error;
+use super::game::{Game, Verdict};
+use super::history::GuessHistory;
+use super::i18n::Messages;
+
+use std::collections::HashMap;
+
+// Longest emoji in the catalog in chars, the kiss and couple sequences with skin tones
+const MAX_EMOJI_LEN: usize = 10;
+
+/*
+Sample PuzzleConfig, guesses per day only apply if the season doesn't set a cooldown
+{
+    "length": 5,
+    "guesses_per_day": 6,
+    "timezone": "Europe/Paris"
+}
+*/
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PuzzleConfig {
+    #[serde(default = "default_length")]
+    pub length: usize,
+    #[serde(default = "default_guesses_per_day")]
+    pub guesses_per_day: u32,
+    // IANA timezone the days start at midnight in, see CooldownPolicy::DailyQuota
+    #[serde(default = "cooldown::default_timezone")]
+    pub timezone: String,
+}
+
+impl Default for PuzzleConfig {
+    fn default() -> Self {
+        PuzzleConfig {
+            length: default_length(),
+            guesses_per_day: default_guesses_per_day(),
+            timezone: cooldown::default_timezone(),
+        }
+    }
+}
+
+fn default_length() -> usize {
+    5
+}
+
+fn default_guesses_per_day() -> u32 {
+    6
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Mark {
+    Correct,
+    // In the sequence, but at another position
+    Present,
+    Absent,
+}
+
+impl Mark {
+    fn square(&self) -> &'static str {
+        match self {
+            Mark::Correct => "🟩",
+            Mark::Present => "🟨",
+            Mark::Absent => "⬛",
+        }
+    }
+}
+
+// Scores a guess position by position. Like in Wordle, an emoji guessed more often than
+// it's in the answer is only marked present as many times as it's left unmatched.
+pub fn score(guess: &[String], answer: &[String]) -> Vec<Mark> {
+    let mut marks = vec![Mark::Absent; guess.len()];
+    let mut unmatched: HashMap<&str, usize> = HashMap::new();
+    for (i, emoji) in answer.iter().enumerate() {
+        if guess.get(i) == Some(emoji) {
+            marks[i] = Mark::Correct;
+        } else {
+            *unmatched.entry(emoji.as_str()).or_insert(0) += 1;
+        }
+    }
+    for (i, emoji) in guess.iter().enumerate() {
+        if marks[i] == Mark::Correct {
+            continue;
+        }
+        if let Some(left) = unmatched.get_mut(emoji.as_str()) {
+            if *left > 0 {
+                *left -= 1;
+                marks[i] = Mark::Present;
+            }
+        }
+    }
+    marks
+}
+
+// Splits a submission into normalized emoji. They can be separated by whitespace or commas,
+// given as shortcodes or names, or sent back to back. Returns None if any part isn't an
+// emoji from the catalog.
+pub fn split(config: &LotteryConfig, submission: &str) -> Option<Vec<String>> {
+    let mut sequence = Vec::new();
+    let tokens = submission
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|t| !t.is_empty());
+    for token in tokens {
+        if let Some(emoji) = config.resolve(token) {
+            sequence.push(emoji);
+            continue;
+        }
+        // Back to back emoji, take the longest one at every position
+        let chars: Vec<char> = token.chars().collect();
+        let mut start = 0;
+        while start < chars.len() {
+            let longest = (start + 1..=chars.len().min(start + MAX_EMOJI_LEN))
+                .rev()
+                .map(|end| {
+                    let candidate: String = chars[start..end].iter().collect();
+                    (end, config.normalize(&candidate))
+                })
+                .find(|(_, emoji)| lookup(emoji).is_some());
+            let (end, emoji) = longest?;
+            sequence.push(emoji);
+            start = end;
+        }
+    }
+    Some(sequence)
+}
+
+// Stored guesses and the jackpot are already normalized and joined with spaces
+fn stored(value: &str) -> Vec<String> {
+    value.split(' ').map(|e| e.to_string()).collect()
+}
+
+fn answer(config: &LotteryConfig) -> Vec<String> {
+    stored(&config.jackpot)
+}
+
+// One row of squares per guess, ready to be shared without spoiling the emoji
+fn grid(
+    config: &LotteryConfig,
+    history: &GuessHistory,
+    latest: &[String],
+    messages: &Messages,
+) -> String {
+    let answer = answer(config);
+    let mut rows: Vec<String> = history
+        .guesses
+        .iter()
+        .map(|g| stored(&g.value))
+        .chain(Some(latest.to_vec()))
+        .map(|guess| score(&guess, &answer).iter().map(|m| m.square()).collect())
+        .collect();
+    let tries = history.count + 1;
+    rows.insert(
+        0,
+        messages.count(
+            "puzzle_grid",
+            tries,
+            &[("season", &config.season.to_string())],
+        ),
+    );
+    rows.join("\n")
+}
+
+// Guess a sequence of emoji, every guess tells which emoji are at the right position
+// (🟩), elsewhere in the sequence (🟨) or not in it at all (⬛)
+pub struct EmojiPuzzle;
+
+impl Game for EmojiPuzzle {
+    fn start_season(&self, config: &LotteryConfig) -> Result<LotteryConfig, Error> {
+        let sequence: Vec<&str> = config
+            .draw_emoji(config.puzzle.length)?
+            .iter()
+            .map(|e| e.emoji)
+            .collect();
+        let jackpot = Jackpot {
+            emoji: sequence.join(" "),
+            tier: config.tiers()[0].name.clone(),
+        };
+        config.commence_with(vec![jackpot])
+    }
+
+    fn judge(
+        &self,
+        config: &LotteryConfig,
+        _submitter: &str,
+        submission: &str,
+        history: &GuessHistory,
+        messages: &Messages,
+    ) -> Verdict {
+        let length = config.puzzle.length;
+        let guess = match split(config, submission) {
+            Some(guess) if guess.len() == length => guess,
+            _ => return Verdict::Rejected(messages.count("puzzle_length", length as u64, &[])),
+        };
+        let value = guess.join(" ");
+        if !config.allow_repeat_guesses && history.missed(&value) {
+            return Verdict::Rejected(messages.get("repeat_guess", &[("emoji", &value)]));
+        }
+        let answer = answer(config);
+        let feedback = Some(grid(config, history, &guess, messages));
+        if guess == answer {
+            return Verdict::Win {
+                value,
+                jackpot: config.all_jackpots()[0].clone(),
+                feedback,
+            };
+        }
+        let eliminated = guess
+            .iter()
+            .filter(|emoji| !answer.contains(emoji))
+            .cloned()
+            .collect();
+        Verdict::Miss {
+            value,
+            feedback,
+            eliminated,
+        }
+    }
+
+    fn default_cooldown(&self, config: &LotteryConfig) -> CooldownPolicy {
+        CooldownPolicy::DailyQuota {
+            guesses: config.puzzle.guesses_per_day,
+            timezone: config.puzzle.timezone.clone(),
+        }
+    }
+
+    fn end_season(&self, config: &LotteryConfig) -> LotteryConfig {
+        config.end()
+    }
+
+    fn render_status(&self, config: &LotteryConfig, messages: &Messages) -> String {
+        if config.active {
+            messages.count(
+                "puzzle_active",
+                config.puzzle.length as u64,
+                &[("commitment", &config.commitment)],
+            )
+        } else {
+            let mut status = messages.get(
+                "puzzle_over",
+                &[
+                    ("sequence", &config.revealed_jackpots()),
+                    ("salt", &config.salt),
+                    ("commitment", &config.commitment),
+                ],
+            );
+            if !config.has_winner {
+                status.push_str(&messages.get("nobody_solved", &[]));
+            }
+            status
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_marks_positions() {
+        let answer = stored("🐙 🦑 🦀 🐟 🐠");
+        assert_eq!(score(&answer, &answer), vec![Mark::Correct; 5]);
+        assert_eq!(
+            score(&stored("🦑 🐙 🦀 🐳 🐬"), &answer),
+            vec![
+                Mark::Present,
+                Mark::Present,
+                Mark::Correct,
+                Mark::Absent,
+                Mark::Absent
+            ]
+        );
+    }
+
+    #[test]
+    fn default_cooldown_follows_timezone() {
+        let config: LotteryConfig = serde_json::from_str(
+            r#"{
+                "has_winner": false,
+                "active": true,
+                "jackpot": "🐙 🦑 🦀 🐟 🐠",
+                "season": 1,
+                "game": "emoji_puzzle",
+                "puzzle": { "guesses_per_day": 1, "timezone": "Europe/Paris" },
+                "retry_in_hrs": 1,
+                "config_kv_namespace": "config",
+                "data_kv_namespace": "data"
+            }"#,
+        )
+        .unwrap();
+        // 2021-03-28T12:00:00+02:00, the day Paris switched to summer time
+        let now = 1616925600;
+        // Midnight of the 29th in Paris is 22:00Z on the 28th
+        assert_eq!(
+            config.cooldown().next_allowed(&[now], now),
+            Ok(Some(1616968800))
+        );
+    }
+
+    #[test]
+    fn score_duplicates() {
+        let answer = stored("🐙 🦑 🦀 🐙 🐠");
+        // A correct 🐙 uses up one of the two, the other extra ones are present once
+        assert_eq!(
+            score(&stored("🐙 🐙 🐙 🦀 🦀"), &answer),
+            vec![
+                Mark::Correct,
+                Mark::Present,
+                Mark::Absent,
+                Mark::Present,
+                Mark::Absent
+            ]
+        );
+        // Correct positions are matched first, even after a present one
+        assert_eq!(
+            score(&stored("🦀 🦑 🐟 🐟 🦀"), &stored("🐟 🦑 🐟 🐠 🦀")),
+            vec![
+                Mark::Absent,
+                Mark::Correct,
+                Mark::Correct,
+                Mark::Present,
+                Mark::Correct
+            ]
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use super::archive;
+use super::cooldown::CooldownPolicy;
+use super::emoji::{random_below, LotteryConfig};
+use super::error::Error;
+use super::game::{Game, Verdict};
+use super::history::{self, GuessHistory};
+use super::i18n::Messages;
+use super::kv::{Guess, KVClient, Winner, WINNERS_KEY};
+use super::utils;
+use super::BotConfig;
+
+use std::collections::HashMap;
+use wasm_bindgen::JsValue;
+
+// Stored in the data namespace
+const ENTRIES_KEY: &str = "lottery:raffle_entries";
+const DRAW_KEY: &str = "lottery:raffle_draw";
+const TICKET: &str = "🎟️";
+// Most bonus entries a submitter can be given
+const MAX_BONUS_ENTRIES: u32 = 1000;
+
+/*
+Sample RaffleConfig, everyone gets one entry plus their bonus entries
+{
+    "winners": 2,
+    "bonus_entries": { "maintainer-id": 2 }
+}
+*/
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RaffleConfig {
+    #[serde(default = "one")]
+    pub winners: u32,
+    // Extra entries keyed by submitter
+    #[serde(default)]
+    pub bonus_entries: HashMap<String, u32>,
+}
+
+impl Default for RaffleConfig {
+    fn default() -> Self {
+        RaffleConfig {
+            winners: one(),
+            bonus_entries: HashMap::new(),
+        }
+    }
+}
+
+fn one() -> u32 {
+    1
+}
+
+impl RaffleConfig {
+    fn validate(&self) -> Result<(), String> {
+        match self
+            .bonus_entries
+            .iter()
+            .find(|(_, bonus)| **bonus > MAX_BONUS_ENTRIES)
+        {
+            Some((submitter, bonus)) => Err(format!(
+                "{} has {} bonus entries, at most {} are allowed",
+                submitter, bonus, MAX_BONUS_ENTRIES
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct Entry {
+    pub submitter: String,
+    pub entries: u32,
+    pub entered_at: i64,
+    pub platform: String,
+}
+
+// Recorded so the draw can be looked into after the fact
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Draw {
+    pub drawn_at: i64,
+    pub tickets: u64,
+    pub winners: Vec<String>,
+}
+
+// Registers a submitter once judge let them in
+pub async fn enter(
+    data_client: &KVClient,
+    submitter: String,
+    entries: u32,
+    guess: Guess,
+    platform: &str,
+    messages: &Messages,
+) -> Result<JsValue, JsValue> {
+    let entered_at = guess.created_at;
+    history::append(data_client, &submitter, guess)
+        .await
+        .map_err(|e| format!("Failed to submit, err: {:?}", e))?;
+    archive::count_guess(data_client, &submitter)
+        .await
+        .map_err(|e| format!("Failed to count guess, err: {:?}", e))?;
+    let mut all_entries: Vec<Entry> = data_client
+        .read(ENTRIES_KEY)
+        .await
+        .map_err(|e| format!("Failed to read raffle entries, err: {:?}", e))?
+        .unwrap_or_default();
+    all_entries.push(Entry {
+        submitter,
+        entries,
+        entered_at,
+        platform: platform.to_string(),
+    });
+    data_client
+        .write::<&Vec<Entry>>(ENTRIES_KEY, &all_entries)
+        .await
+        .map_err(|e| format!("Failed to record raffle entry, err: {:?}", e))?;
+    Ok(JsValue::from_str(&messages.count(
+        "raffle_entered",
+        entries as u64,
+        &[],
+    )))
+}
+
+// Draws the season's winners if its game has any to draw, and records them with the draw.
+// The season can only be drawn once, ending it again returns the recorded winners.
+pub async fn draw(
+    bot_config: &BotConfig,
+    config: &LotteryConfig,
+    game: &dyn Game,
+) -> Result<Vec<Winner>, Error> {
+    let data_client = KVClient::new(bot_config.kv.clone(), config.data_kv_namespace.clone());
+    if let Some(draw) = data_client.read::<Draw>(DRAW_KEY).await? {
+        let winners: Vec<Winner> = data_client.read(WINNERS_KEY).await?.unwrap_or_default();
+        return Ok(draw
+            .winners
+            .iter()
+            .filter_map(|submitter| {
+                winners
+                    .iter()
+                    .find(|w| w.submitter == *submitter && w.emoji == TICKET)
+                    .cloned()
+            })
+            .collect());
+    }
+    let entries: Vec<Entry> = data_client.read(ENTRIES_KEY).await?.unwrap_or_default();
+    if entries.is_empty() {
+        return Ok(Vec::new());
+    }
+    let drawn = game.draw_winners(config, &entries)?;
+    let drawn_at = utils::now();
+    let tier = config.tiers()[0].name.clone();
+    let drawn: Vec<Winner> = drawn
+        .into_iter()
+        .map(|e| Winner {
+            submitter: e.submitter,
+            emoji: TICKET.to_string(),
+            tier: tier.clone(),
+            guesses: e.entries as u64,
+            won_at: drawn_at,
+            platform: e.platform,
+        })
+        .collect();
+
+    let draw = Draw {
+        drawn_at,
+        tickets: entries.iter().map(|e| e.entries as u64).sum(),
+        winners: drawn.iter().map(|w| w.submitter.clone()).collect(),
+    };
+    data_client.write::<&Draw>(DRAW_KEY, &draw).await?;
+    let mut winners: Vec<Winner> = data_client.read(WINNERS_KEY).await?.unwrap_or_default();
+    winners.extend(drawn.iter().cloned());
+    data_client
+        .write::<&Vec<Winner>>(WINNERS_KEY, &winners)
+        .await?;
+    Ok(drawn)
+}
+
+// Players only enter, winners are drawn from the entries when the season ends. Every
+// entry is a ticket, so bonus entries raise the odds of being drawn.
+pub struct Raffle;
+
+impl Game for Raffle {
+    fn start_season(&self, config: &LotteryConfig) -> Result<LotteryConfig, Error> {
+        config
+            .raffle
+            .validate()
+            .map_err(|e| Error::Jv(JsValue::from_str(&e)))?;
+        config.commence_with(Vec::new())
+    }
+
+    fn judge(
+        &self,
+        config: &LotteryConfig,
+        submitter: &str,
+        _submission: &str,
+        history: &GuessHistory,
+        messages: &Messages,
+    ) -> Verdict {
+        if history.count > 0 {
+            return Verdict::Rejected(messages.get("raffle_already_entered", &[]));
+        }
+        let bonus = config
+            .raffle
+            .bonus_entries
+            .get(submitter)
+            .cloned()
+            .unwrap_or(0);
+        Verdict::Enter {
+            entries: bonus.saturating_add(1),
+        }
+    }
+
+    // Entering is once per season anyway
+    fn default_cooldown(&self, _config: &LotteryConfig) -> CooldownPolicy {
+        CooldownPolicy::Interval { minutes: 0 }
+    }
+
+    fn end_season(&self, config: &LotteryConfig) -> LotteryConfig {
+        config.end()
+    }
+
+    fn draws_winners(&self) -> bool {
+        true
+    }
+
+    fn draw_winners(&self, config: &LotteryConfig, entries: &[Entry]) -> Result<Vec<Entry>, Error> {
+        let mut remaining: Vec<&Entry> = entries.iter().filter(|e| e.entries > 0).collect();
+        let mut drawn: Vec<Entry> = Vec::new();
+        while drawn.len() < config.raffle.winners as usize && !remaining.is_empty() {
+            // Weights add up in u64, so they can't overflow whatever the entries
+            let weights: Vec<u64> = remaining.iter().map(|e| e.entries as u64).collect();
+            let total = weights.iter().sum();
+            let winner = remaining[pick(&weights, random_below(total)?)];
+            // Nobody wins twice, whatever the number of entries
+            remaining.retain(|e| e.submitter != winner.submitter);
+            drawn.push(winner.clone());
+        }
+        Ok(drawn)
+    }
+
+    fn render_status(&self, config: &LotteryConfig, messages: &Messages) -> String {
+        if config.active {
+            messages.count("raffle_active", config.raffle.winners as u64, &[])
+        } else if config.has_winner {
+            messages.get("raffle_drawn", &[])
+        } else {
+            messages.get("raffle_closed", &[])
+        }
+    }
+}
+
+// Returns the index whose cumulative weight range holds r, r is below the sum of weights
+fn pick(weights: &[u64], r: u64) -> usize {
+    let mut cumulative = 0;
+    weights
+        .iter()
+        .position(|w| {
+            cumulative += w;
+            r < cumulative
+        })
+        .unwrap_or(weights.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(raffle: &str) -> LotteryConfig {
+        serde_json::from_str(&format!(
+            r#"{{
+                "has_winner": false,
+                "active": true,
+                "jackpot": "",
+                "season": 1,
+                "game": "raffle",
+                "raffle": {},
+                "retry_in_hrs": 1,
+                "config_kv_namespace": "config",
+                "data_kv_namespace": "data"
+            }}"#,
+            raffle
+        ))
+        .unwrap()
+    }
+
+    fn entry(submitter: &str, entries: u32) -> Entry {
+        Entry {
+            submitter: submitter.to_string(),
+            entries,
+            entered_at: 0,
+            platform: "discord".to_string(),
+        }
+    }
+
+    #[test]
+    fn pick_walks_cumulative_weights() {
+        let weights = [1, 0, 3];
+        assert_eq!(pick(&weights, 0), 0);
+        assert_eq!(pick(&weights, 1), 2);
+        assert_eq!(pick(&weights, 3), 2);
+    }
+
+    #[test]
+    fn draw_winners() {
+        let config = config(r#"{ "winners": 2 }"#);
+        // More tickets than a u32 can count
+        let entries = [entry("a", u32::MAX), entry("b", u32::MAX), entry("c", 0)];
+        let mut winners: Vec<String> = Raffle
+            .draw_winners(&config, &entries)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.submitter)
+            .collect();
+        winners.sort();
+        // Nobody without entries, nobody twice
+        assert_eq!(winners, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn bonus_entries() {
+        let generous = config(r#"{ "bonus_entries": { "a": 4294967295 } }"#);
+        assert!(generous.raffle.validate().is_err());
+        match Raffle.judge(
+            &generous,
+            "a",
+            "",
+            &GuessHistory::default(),
+            &Messages::default(),
+        ) {
+            Verdict::Enter { entries } => assert_eq!(entries, u32::MAX),
+            _ => panic!("a should enter"),
+        }
+        assert!(config(r#"{ "bonus_entries": { "a": 1000 } }"#)
+            .raffle
+            .validate()
+            .is_ok());
+    }
+}
@@ -4,14 +4,49 @@ pub enum Route {
     CalendarStart,
     CalendarEnd,
     Events,
-    Submit { submitter: String },
-    CheckLastSubmission { submitter: String },
+    Status,
+    Submit {
+        submitter: String,
+    },
+    // locale is the user's, e.g. the locale of the Discord interaction
+    CheckLastSubmission {
+        submitter: String,
+        locale: Option<String>,
+    },
+    GuessHistory {
+        submitter: String,
+        locale: Option<String>,
+    },
+    Seasons,
+    Season {
+        season: u64,
+    },
+    HallOfFame,
+    Board,
+    Verify {
+        jackpot: Option<String>,
+        salt: Option<String>,
+        commitment: Option<String>,
+    },
     Unhandled,
 }
 
+// Routes can be scoped to a tenant by prefixing them with /tenants/:tenant,
+// e.g. /tenants/:tenant/submit/discord/:submitter
+pub fn tenant(url: &Url) -> Option<String> {
+    let mut path_segments = url.path_segments()?;
+    match (path_segments.next(), path_segments.next()) {
+        (Some("tenants"), Some(tenant)) if !tenant.is_empty() => Some(tenant.to_string()),
+        _ => None,
+    }
+}
+
 impl From<&Url> for Route {
     fn from(url: &Url) -> Route {
         if let Some(mut path_segments) = url.path_segments() {
+            if tenant(url).is_some() {
+                path_segments.nth(1);
+            }
             return match path_segments.next() {
                 // /calendar_start
                 Some("calendar_start") => Route::CalendarStart,
@@ -19,13 +54,46 @@ impl From<&Url> for Route {
                 Some("calendar_end") => Route::CalendarEnd,
                 // /events
                 Some("events") => Route::Events,
+                // /status
+                Some("status") => Route::Status,
+                Some("seasons") => match path_segments.next() {
+                    // /seasons/:season
+                    Some(s) if !s.is_empty() => match s.parse() {
+                        Ok(season) => Route::Season { season },
+                        Err(_) => Route::Unhandled,
+                    },
+                    // /seasons
+                    _ => Route::Seasons,
+                },
+                // /winners
+                Some("winners") => Route::HallOfFame,
+                // /board
+                Some("board") => Route::Board,
+                // /verify?jackpot=:jackpot&salt=:salt&commitment=:commitment
+                Some("verify") => Route::Verify {
+                    jackpot: query_param(url, "jackpot"),
+                    salt: query_param(url, "salt"),
+                    commitment: query_param(url, "commitment"),
+                },
                 Some("submit") => match path_segments.next() {
                     Some("discord") => match path_segments.next() {
                         Some("last") => {
                             if let Some(submitter) = path_segments.next() {
-                                // /submit/discord/last/:submitter
+                                // /submit/discord/last/:submitter?locale=fr
                                 Route::CheckLastSubmission {
                                     submitter: submitter.to_string(),
+                                    locale: query_param(url, "locale"),
+                                }
+                            } else {
+                                Route::Unhandled
+                            }
+                        }
+                        Some("history") => {
+                            if let Some(submitter) = path_segments.next() {
+                                // /submit/discord/history/:submitter?locale=fr
+                                Route::GuessHistory {
+                                    submitter: submitter.to_string(),
+                                    locale: query_param(url, "locale"),
                                 }
                             } else {
                                 Route::Unhandled
@@ -45,3 +113,9 @@ impl From<&Url> for Route {
         Route::Unhandled
     }
 }
+
+fn query_param(url: &Url, name: &str) -> Option<String> {
+    url.query_pairs()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.into_owned())
+}
@@ -1,13 +1,14 @@
 use super::error::Error;
-use super::http::{send, Method, Request};
-use std::collections::HashMap;
-use wasm_bindgen_futures::JsFuture;
+use super::http::{send, Body, Method, Request};
+use super::trace::Trace;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 pub struct SlackConfig {
     token: String,
     announcement_channel: String,
     pub maintainer: String,
+    #[serde(skip)]
+    pub trace: Trace,
 }
 
 /*
@@ -82,27 +83,17 @@ pub fn new_slack_client(config: SlackConfig) -> SlackClient {
 
 impl SlackClient {
     pub async fn post_message(&self, message: String) -> Result<PostMessageResp, Error> {
-        let mut headers = HashMap::new();
-        headers.insert(
-            "Authorization".to_string(),
-            format!("Bearer {}", self.config.token),
-        );
-        headers.insert("Content-type".to_string(), "application/json".to_string());
-        let req = Request {
-            url: "https://slack.com/api/chat.postMessage".to_string(),
-            method: Method::POST,
-            headers: headers,
-            body: PostMessageBody {
-                channel: self.config.announcement_channel.clone(),
-                text: message,
-            },
-        };
-        let js_resp = send(req).await?;
-
-        // Convert this Promise into a rust Future.
-        let json = JsFuture::from(js_resp.json()?).await?;
-
-        let resp: PostMessageResp = json.into_serde()?;
+        let req = Request::new(
+            Method::POST,
+            "https://slack.com/api/chat.postMessage".to_string(),
+        )
+        .header("Authorization", &format!("Bearer {}", self.config.token))
+        .trace(&self.config.trace)
+        .body(Body::json(&PostMessageBody {
+            channel: self.config.announcement_channel.clone(),
+            text: message,
+        })?);
+        let resp: PostMessageResp = send(req).await?.json().await?;
 
         Ok(resp)
     }
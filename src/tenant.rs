@@ -0,0 +1,116 @@
+use super::discord::DiscordConfig;
+use super::emoji::{scoped_key, LotteryConfig, LOTTERY_CONFIG_KEY};
+use super::error::Error;
+use super::kv::KVClient;
+use super::slack::SlackConfig;
+use super::BotConfig;
+
+use wasm_bindgen::JsValue;
+
+// Longer than any guild or team ID, keeps tenant keys well under KV's key size limit
+const MAX_ID_LEN: usize = 64;
+
+/*
+Sample Tenant, stored in the config namespace under t/<id>/settings next to its lottery
+config under t/<id>/lotteryConfig. The id is the Discord guild ID or the Slack team ID, and
+either platform can be left out.
+{
+    "discord": {
+        "gateway_token": "...",
+        "webhook_url": "https://discord.com/api/webhooks/...",
+        "maintainer": "..."
+    },
+    "slack": {
+        "token": "...",
+        "announcement_channel": "C2147483705",
+        "maintainer": "..."
+    }
+}
+*/
+#[derive(Deserialize)]
+pub struct Tenant {
+    #[serde(default)]
+    discord: Option<DiscordConfig>,
+    #[serde(default)]
+    slack: Option<SlackConfig>,
+}
+
+fn tenant_key(tenant: &str) -> String {
+    scoped_key(Some(tenant), "settings")
+}
+
+// Guild and team IDs are alphanumeric. Anything else, a slash in particular, could reach
+// into the deployment's or another tenant's keys.
+fn is_valid(tenant: &str) -> bool {
+    !tenant.is_empty()
+        && tenant.len() <= MAX_ID_LEN
+        && tenant
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn unknown_tenant(tenant: &str) -> Error {
+    Error::Jv(JsValue::from_str(&format!("Unknown tenant {}", tenant)))
+}
+
+// Swaps the deployment's lottery and platforms for the tenant's. The KV credentials and the
+// config namespace stay the deployment's.
+pub async fn scope(bot_config: BotConfig, tenant: &str) -> Result<BotConfig, Error> {
+    if !is_valid(tenant) {
+        return Err(unknown_tenant(tenant));
+    }
+    let client = KVClient::new(
+        bot_config.kv.clone(),
+        bot_config.emoji.config_kv_namespace.clone(),
+    );
+    let settings: Tenant = client
+        .read(&tenant_key(tenant))
+        .await?
+        .ok_or_else(|| unknown_tenant(tenant))?;
+    let mut emoji: LotteryConfig = client
+        .read(&scoped_key(Some(tenant), LOTTERY_CONFIG_KEY))
+        .await?
+        .ok_or_else(|| unknown_tenant(tenant))?;
+    emoji.tenant = Some(tenant.to_string());
+    Ok(BotConfig {
+        discord: settings.discord,
+        kv: bot_config.kv,
+        emoji,
+        slack: settings.slack,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys() {
+        assert_eq!(
+            tenant_key("81384788765712384"),
+            "t/81384788765712384/settings"
+        );
+        assert_eq!(
+            scoped_key(Some("T0G9PQBBK"), LOTTERY_CONFIG_KEY),
+            "t/T0G9PQBBK/lotteryConfig"
+        );
+        assert_eq!(scoped_key(None, LOTTERY_CONFIG_KEY), LOTTERY_CONFIG_KEY);
+    }
+
+    #[test]
+    fn valid_ids() {
+        assert!(is_valid("81384788765712384"));
+        assert!(is_valid("T0G9PQBBK"));
+        for id in [
+            "",
+            "a/b",
+            "../lotteryConfig",
+            "a:b",
+            &"1".repeat(MAX_ID_LEN + 1),
+        ]
+        .iter()
+        {
+            assert!(!is_valid(id), "{:?}", id);
+        }
+    }
+}
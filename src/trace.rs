@@ -0,0 +1,120 @@
+use js_sys::{Object, Reflect};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use url::Url;
+use wasm_bindgen::JsValue;
+
+const REDACTED: &str = "REDACTED";
+
+// Header and query parameter names that are likely to carry credentials
+const SECRET_NAMES: [&str; 5] = ["auth", "token", "key", "secret", "password"];
+
+/*
+Sample TraceRecord
+{
+    "method": "PUT",
+    "url": "https://api.cloudflare.com/client/v4/accounts/123/storage/kv/namespaces/456/values/lotteryConfig",
+    "headers": { "Authorization": "REDACTED", "Content-type": "application/json" },
+    "status": 500,
+    "error": null,
+    "duration_ms": 83.0,
+    "attempt": 1
+}
+*/
+#[derive(Serialize, Debug, Clone)]
+pub struct TraceRecord {
+    pub method: String,
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+    pub duration_ms: f64,
+    // Starts at 1, retries of the same request count up, see http::send
+    pub attempt: u32,
+}
+
+// Collects the outbound calls made while handling one request. A worker interleaves
+// concurrent requests at every await, so each request gets its own, handed to the clients
+// through their configs, see BotConfig::trace_to.
+#[derive(Clone, Default)]
+pub struct Trace {
+    records: Rc<RefCell<Vec<TraceRecord>>>,
+}
+
+impl Trace {
+    pub fn record(&self, record: TraceRecord) {
+        self.records.borrow_mut().push(record);
+    }
+
+    pub fn take(&self) -> Vec<TraceRecord> {
+        self.records.replace(Vec::new())
+    }
+}
+
+// Errors reach JS as { "error": ..., "outbound_requests": [...] }, so the records can be
+// attached to the error report
+pub fn attach(err: JsValue, trace: &Trace) -> JsValue {
+    let report = Object::new();
+    let records = JsValue::from_serde(&trace.take()).unwrap_or(JsValue::NULL);
+    let _ = Reflect::set(&report, &JsValue::from_str("error"), &err);
+    let _ = Reflect::set(&report, &JsValue::from_str("outbound_requests"), &records);
+    report.into()
+}
+
+pub fn redact_headers(headers: &HashMap<String, String>) -> HashMap<String, String> {
+    headers
+        .iter()
+        .map(|(k, v)| {
+            if is_secret(k) {
+                (k.clone(), REDACTED.to_string())
+            } else {
+                (k.clone(), v.clone())
+            }
+        })
+        .collect()
+}
+
+// Removes credentials embedded in the url: userinfo, secret looking query parameters and
+// the token of Discord webhooks (https://discord.com/api/webhooks/:id/:token)
+pub fn redact_url(url: &str) -> String {
+    let mut url = match Url::parse(url) {
+        Ok(url) => url,
+        Err(_) => return REDACTED.to_string(),
+    };
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+
+    let query: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(k, v)| {
+            if is_secret(&k) {
+                (k.into_owned(), REDACTED.to_string())
+            } else {
+                (k.into_owned(), v.into_owned())
+            }
+        })
+        .collect();
+    if !query.is_empty() {
+        url.query_pairs_mut().clear().extend_pairs(query.iter());
+    }
+
+    let segments: Option<Vec<String>> = url
+        .path_segments()
+        .map(|segments| segments.map(|s| s.to_string()).collect());
+    if let Some(mut segments) = segments {
+        if let Some(i) = segments.iter().position(|s| s == "webhooks") {
+            // webhooks/:id/:token
+            if let Some(token) = segments.get_mut(i + 2) {
+                *token = REDACTED.to_string();
+            }
+            url.set_path(&segments.join("/"));
+        }
+    }
+    url.to_string()
+}
+
+fn is_secret(name: &str) -> bool {
+    let name = name.to_lowercase();
+    SECRET_NAMES.iter().any(|secret| name.contains(secret))
+}
@@ -15,3 +15,8 @@ cfg_if! {
         pub fn set_panic_hook() {}
     }
 }
+
+// Seconds since the epoch according to the worker clock
+pub fn now() -> i64 {
+    (js_sys::Date::now() / 1000.0) as i64
+}
@@ -10,3 +10,11 @@ wasm_bindgen_test_configure!(run_in_browser);
 fn pass() {
     assert_eq!(1 + 1, 2);
 }
+
+#[wasm_bindgen_test]
+fn verify_commitment() {
+    // sha256("🐙:00ff")
+    let commitment = "cf5f6d2203f00883861e7679d4354921be430c26e49ebb4e6d5bf7ac8f985c2a";
+    assert!(lottery_bot::verify_commitment("🐙", "00ff", commitment));
+    assert!(!lottery_bot::verify_commitment("🦑", "00ff", commitment));
+}
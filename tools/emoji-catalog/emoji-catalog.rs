@@ -0,0 +1,174 @@
+// Generates the emoji catalog from Unicode's emoji-test.txt
+// https://unicode.org/Public/emoji/latest/emoji-test.txt
+// and, optionally, shortcodes from a tab separated file of emoji and comma separated
+// aliases, e.g. extracted from GitHub's gemoji db/emoji.json (see run.sh)
+//
+// Only fully-qualified sequences are kept, they are the ones keyboards produce.
+// Each line looks like
+// 1F44B 1F3FB ; fully-qualified # 👋🏻 E1.0 waving hand: light skin tone
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::process;
+
+struct Entry {
+    codepoints: Vec<u32>,
+    name: String,
+    group: String,
+    subgroup: String,
+    version: String,
+    shortcodes: Vec<String>,
+}
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: emoji-catalog <emoji-test.txt> [aliases.tsv]");
+            process::exit(1);
+        }
+    };
+    let content = read(&path);
+    let aliases = match env::args().nth(2) {
+        Some(path) => parse_aliases(&read(&path)),
+        None => HashMap::new(),
+    };
+
+    let mut version = String::new();
+    let mut group = String::new();
+    let mut subgroup = String::new();
+    let mut entries = Vec::new();
+    for line in content.lines() {
+        if let Some(v) = line.strip_prefix("# Version:") {
+            version = v.trim().to_string();
+        } else if let Some(g) = line.strip_prefix("# group:") {
+            group = g.trim().to_string();
+        } else if let Some(s) = line.strip_prefix("# subgroup:") {
+            subgroup = s.trim().to_string();
+        } else if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        } else if let Some(mut entry) = parse_entry(line, &group, &subgroup) {
+            if let Some(shortcodes) = aliases.get(&without_selectors(&entry.codepoints)) {
+                entry.shortcodes = shortcodes.clone();
+            }
+            entries.push(entry);
+        }
+    }
+
+    println!("// Code generated by tools/emoji-catalog from emoji-test.txt. DO NOT EDIT.");
+    println!();
+    println!("pub struct EmojiInfo {{");
+    println!("    pub emoji: &'static str,");
+    println!("    // CLDR short name");
+    println!("    pub name: &'static str,");
+    println!("    pub group: &'static str,");
+    println!("    pub subgroup: &'static str,");
+    println!("    // Emoji version the sequence was introduced in");
+    println!("    pub version: f32,");
+    println!("    // Without the surrounding colons, e.g. tada for :tada:");
+    println!("    pub shortcodes: &'static [&'static str],");
+    println!("}}");
+    println!();
+    println!("pub const VERSION: f32 = {};", float_literal(&version));
+    println!();
+    println!("#[rustfmt::skip]");
+    println!("pub static EMOJIS: &[EmojiInfo] = &[");
+    for e in entries.iter() {
+        let emoji: String = e
+            .codepoints
+            .iter()
+            .map(|c| format!("\\u{{{:x}}}", c))
+            .collect();
+        let shortcodes: Vec<String> = e
+            .shortcodes
+            .iter()
+            .map(|s| format!("\"{}\"", escape(s)))
+            .collect();
+        println!(
+            "    EmojiInfo {{ emoji: \"{}\", name: \"{}\", group: \"{}\", subgroup: \"{}\", version: {}, shortcodes: &[{}] }},",
+            emoji,
+            escape(&e.name),
+            escape(&e.group),
+            escape(&e.subgroup),
+            float_literal(&e.version),
+            shortcodes.join(", "),
+        );
+    }
+    println!("];");
+}
+
+fn parse_entry(line: &str, group: &str, subgroup: &str) -> Option<Entry> {
+    let mut parts = line.splitn(2, ';');
+    let codepoints = parts.next()?;
+    let rest = parts.next()?;
+    let mut parts = rest.splitn(2, '#');
+    if parts.next()?.trim() != "fully-qualified" {
+        return None;
+    }
+    let comment = parts.next()?.trim();
+    // 👋🏻 E1.0 waving hand: light skin tone
+    let mut words = comment.splitn(3, ' ');
+    let _glyph = words.next()?;
+    let version = words.next()?.strip_prefix('E')?.to_string();
+    let name = words.next()?.to_string();
+    let codepoints = codepoints
+        .split_whitespace()
+        .map(|c| u32::from_str_radix(c, 16).ok())
+        .collect::<Option<Vec<u32>>>()?;
+    Some(Entry {
+        codepoints,
+        name,
+        group: group.to_string(),
+        subgroup: subgroup.to_string(),
+        version,
+        shortcodes: Vec::new(),
+    })
+}
+
+// Keyed by codepoints without variation selectors, gemoji and emoji-test.txt don't
+// always agree on them
+fn parse_aliases(content: &str) -> HashMap<Vec<u32>, Vec<String>> {
+    let mut aliases = HashMap::new();
+    for line in content.lines() {
+        let mut parts = line.splitn(2, '\t');
+        let (emoji, shortcodes) = match (parts.next(), parts.next()) {
+            (Some(emoji), Some(shortcodes)) => (emoji, shortcodes),
+            _ => continue,
+        };
+        let codepoints: Vec<u32> = emoji.chars().map(|c| c as u32).collect();
+        let shortcodes = shortcodes
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        aliases.insert(without_selectors(&codepoints), shortcodes);
+    }
+    aliases
+}
+
+fn without_selectors(codepoints: &[u32]) -> Vec<u32> {
+    codepoints
+        .iter()
+        .cloned()
+        .filter(|c| *c != 0xFE0E && *c != 0xFE0F)
+        .collect()
+}
+
+fn read(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("failed to read {}: {}", path, e);
+        process::exit(1);
+    })
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn float_literal(s: &str) -> String {
+    if s.contains('.') {
+        s.to_string()
+    } else {
+        format!("{}.0", s)
+    }
+}
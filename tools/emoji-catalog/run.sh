@@ -0,0 +1,7 @@
+# Regenerates src/emoji_catalog.rs from Unicode's emoji-test.txt and GitHub's gemoji shortcodes
+EMOJI_VERSION=${EMOJI_VERSION:-13.1}
+curl -sSfL -o emoji-test.txt https://unicode.org/Public/emoji/${EMOJI_VERSION}/emoji-test.txt
+curl -sSfL https://raw.githubusercontent.com/github/gemoji/master/db/emoji.json | jq -r '.[] | [.emoji, (.aliases | join(","))] | @tsv' > aliases.tsv
+rustc -O -o ../bin/emoji-catalog emoji-catalog.rs
+../bin/emoji-catalog emoji-test.txt aliases.tsv > ../../src/emoji_catalog.rs
+rm emoji-test.txt aliases.tsv
@@ -47,9 +47,13 @@ async function handleRequest(request) {
       })
     })
     .catch(async function (err) {
+      // Errors come with the outbound requests made while handling the request, secrets
+      // redacted. Anything else, e.g. a panic, is reported as is.
+      const report = err && typeof err === "object" ? err : { error: err };
+      const { error = err, outbound_requests = [] } = report;
       const sentryId = uuid();
-      await sentryLog(err, sentryId);
-      return new Response(err, {
+      await sentryLog(error, outbound_requests, sentryId);
+      return new Response(error, {
         "status": 500,
         "statusText": "Internal Server Error",
         "headers": { 'Content-Type': 'text/plain' }
@@ -59,9 +63,9 @@ async function handleRequest(request) {
   return result
 }
 
-async function sentryLog(err, id) {
+async function sentryLog(err, traces, id) {
   const currentTimestamp = Date.now() / 1000;
-  const body = sentryEventJson(err, currentTimestamp, id);
+  const body = sentryEventJson(err, traces, currentTimestamp, id);
   const sentryProectID = await SLACK_BRIDGE.get("sentryProjectID");
   const sentryKey = await SLACK_BRIDGE.get("sentryKey");
   return await fetch(`https://sentry.io/api/${sentryProectID}/store/`, {
@@ -79,10 +83,13 @@ async function sentryLog(err, id) {
   });
 }
 
-function sentryEventJson(err, currentTimestamp, id) {
+function sentryEventJson(err, traces, currentTimestamp, id) {
   return JSON.stringify({
     event_id: id,
     message: JSON.stringify(err),
+    extra: {
+      outbound_requests: traces,
+    },
     timestamp: currentTimestamp,
     logger: "slack-bridge-logger",
     platform: "javascript",